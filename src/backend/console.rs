@@ -0,0 +1,247 @@
+use super::{EscBehavior, Outcome, OutcomeAction};
+use crate::input::merge_responses;
+use crate::keymap::{KeyAction, KeyPattern, Keymap};
+use crate::line_editor::EditorRequest;
+use crate::{Input, InputRequest, StateChanged};
+use console::Key;
+
+/// Converts console key into input requests.
+///
+/// Control keys that the console crate doesn't recognize arrive as control
+/// characters, and Alt keys arrive as unknown escape sequences.
+pub fn to_input_request(key: &Key) -> Option<InputRequest> {
+    use InputRequest::*;
+    match key {
+        Key::Backspace => Some(DeletePrevChar),
+        Key::Del => Some(DeleteNextChar),
+        Key::ArrowLeft | Key::Char('\x02') => Some(GoToPrevChar),
+        Key::ArrowRight | Key::Char('\x06') => Some(GoToNextChar),
+        Key::ArrowUp => Some(GoToPrevLine),
+        Key::ArrowDown => Some(GoToNextLine),
+        Key::Char('\x1a') => Some(Undo),
+        Key::Char('\x15') => Some(DeleteLine),
+        Key::Char('\x12') => Some(ToggleMask),
+        Key::Char('\x19') => Some(PasteOverSelection),
+        Key::Char('\x17') => Some(DeletePrevWord),
+        Key::Char('\x0b') => Some(DeleteTillEnd),
+        Key::Home => Some(GoToStart),
+        Key::End => Some(GoToEnd),
+        Key::UnknownEscSeq(seq) => match seq.as_slice() {
+            ['b'] => Some(GoToPrevWord),
+            ['f'] => Some(GoToNextWord),
+            ['d'] | ['\x7f'] => Some(DeletePrevWord),
+            ['\r'] => Some(InsertNewline),
+            _ => None,
+        },
+        Key::Char(c) if !c.is_control() => Some(InsertChar(*c)),
+        _ => None,
+    }
+}
+
+/// Converts console key into a key action, looking it up in the keymap before
+/// falling back to the default mappings.
+pub fn to_key_action<C: Clone>(
+    key: &Key,
+    keymap: &Keymap<Key, C>,
+) -> Option<KeyAction<C>> {
+    keymap
+        .lookup(key)
+        .cloned()
+        .or_else(|| to_input_request(key).map(KeyAction::Request))
+}
+
+/// Console keys carry no modifiers to tell apart.
+impl KeyPattern for Key {
+    fn normalized(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The default mappings of [`to_input_request`] as a keymap, except for the
+/// chars inserted as is. Useful for listing them in a help popup, see
+/// [`Keymap::help`] and [`key_name`].
+pub fn default_keymap<C>() -> Keymap<Key, C> {
+    use InputRequest::*;
+    use KeyAction::Request;
+    let alt = |c| Key::UnknownEscSeq(vec![c]);
+    Keymap::default()
+        .bind(Key::Backspace, Request(DeletePrevChar))
+        .bind(Key::Del, Request(DeleteNextChar))
+        .bind(Key::ArrowLeft, Request(GoToPrevChar))
+        .bind(Key::Char('\x02'), Request(GoToPrevChar))
+        .bind(Key::ArrowRight, Request(GoToNextChar))
+        .bind(Key::Char('\x06'), Request(GoToNextChar))
+        .bind(Key::ArrowUp, Request(GoToPrevLine))
+        .bind(Key::ArrowDown, Request(GoToNextLine))
+        .bind(Key::Char('\x1a'), Request(Undo))
+        .bind(Key::Char('\x15'), Request(DeleteLine))
+        .bind(Key::Char('\x12'), Request(ToggleMask))
+        .bind(Key::Char('\x19'), Request(PasteOverSelection))
+        .bind(Key::Char('\x17'), Request(DeletePrevWord))
+        .bind(Key::Char('\x0b'), Request(DeleteTillEnd))
+        .bind(Key::Home, Request(GoToStart))
+        .bind(Key::End, Request(GoToEnd))
+        .bind(alt('b'), Request(GoToPrevWord))
+        .bind(alt('f'), Request(GoToNextWord))
+        .bind(alt('d'), Request(DeletePrevWord))
+        .bind(alt('\x7f'), Request(DeletePrevWord))
+        .bind(alt('\r'), Request(InsertNewline))
+}
+
+/// Get a human readable name of the key, e.g. `"Ctrl+W"` or `"Alt+B"`.
+///
+/// ```
+/// use console::Key;
+/// use tui_input::backend::console::key_name;
+///
+/// assert_eq!(key_name(&Key::Char('\x17')), "Ctrl+W");
+/// assert_eq!(key_name(&Key::UnknownEscSeq(vec!['b'])), "Alt+B");
+/// ```
+pub fn key_name(key: &Key) -> String {
+    fn char_name(c: char) -> String {
+        match c {
+            ' ' => "Space".into(),
+            '\r' | '\n' => "Enter".into(),
+            '\x7f' => "Backspace".into(),
+            '\x01'..='\x1a' => format!("Ctrl+{}", (c as u8 - 1 + b'A') as char),
+            c => c.to_uppercase().collect(),
+        }
+    }
+    match key {
+        Key::Char(c) if c.is_control() || *c == ' ' => char_name(*c),
+        Key::Char(c) => c.to_string(),
+        Key::UnknownEscSeq(seq) if seq.len() == 1 => {
+            format!("Alt+{}", char_name(seq[0]))
+        }
+        Key::ArrowLeft => "Left".into(),
+        Key::ArrowRight => "Right".into(),
+        Key::ArrowUp => "Up".into(),
+        Key::ArrowDown => "Down".into(),
+        Key::Del => "Delete".into(),
+        Key::Escape => "Esc".into(),
+        Key::PageUp => "Page Up".into(),
+        Key::PageDown => "Page Down".into(),
+        Key::BackTab => "Shift+Tab".into(),
+        key => format!("{:?}", key),
+    }
+}
+
+/// Key bindings of a [`LineEditor`](crate::line_editor::LineEditor), to be used
+/// with [`to_key_action`]: Enter accepts the line, Esc and Ctrl+C discard it,
+/// Up/Down and Ctrl+P/N recall the history, and Tab completes.
+pub fn line_editor_keymap() -> Keymap<Key, EditorRequest> {
+    use EditorRequest::*;
+    use KeyAction::Custom;
+    Keymap::default()
+        .bind(Key::Enter, Custom(Accept))
+        .bind(Key::Escape, Custom(Cancel))
+        .bind(Key::Char('\x03'), Custom(Cancel))
+        .bind(Key::ArrowUp, Custom(PrevHistory))
+        .bind(Key::Char('\x10'), Custom(PrevHistory))
+        .bind(Key::ArrowDown, Custom(NextHistory))
+        .bind(Key::Char('\x0e'), Custom(NextHistory))
+        .bind(Key::Tab, Custom(Complete))
+}
+
+/// Keys finishing the input, to be used with
+/// [`EventHandler::handle_event_outcome_with_keymap`]: Enter submits and Esc
+/// cancels.
+pub fn outcome_keymap() -> Keymap<Key, OutcomeAction> {
+    outcome_keymap_with_esc(EscBehavior::Cancel)
+}
+
+/// Like [`outcome_keymap`], with Esc doing the given action instead.
+pub fn outcome_keymap_with_esc(esc: EscBehavior) -> Keymap<Key, OutcomeAction> {
+    let keymap =
+        Keymap::default().bind(Key::Enter, KeyAction::Custom(OutcomeAction::Submit));
+    match esc.action() {
+        Some(action) => keymap.bind(Key::Escape, action),
+        None => keymap,
+    }
+}
+
+/// Import this trait to implement `Input::handle_event()` for console.
+pub trait EventHandler {
+    /// Handle console key.
+    fn handle_event(&mut self, key: &Key) -> Option<StateChanged>;
+
+    /// Handle the console keys received since the last frame, coalescing the
+    /// bursts of identical requests, see [`coalesce`](super::coalesce).
+    fn handle_events(&mut self, keys: &[Key]) -> Option<StateChanged>;
+
+    /// Handle console key, submitting the value on Enter and cancelling on
+    /// Esc.
+    fn handle_event_outcome(&mut self, key: &Key) -> Outcome;
+
+    /// Handle console key, submitting or cancelling the value with the keys
+    /// bound in the given keymap, see [`outcome_keymap`].
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        key: &Key,
+        keymap: &Keymap<Key, OutcomeAction>,
+    ) -> Outcome;
+}
+
+impl EventHandler for Input {
+    /// Handle console key.
+    fn handle_event(&mut self, key: &Key) -> Option<StateChanged> {
+        to_input_request(key).and_then(|req| self.handle(req))
+    }
+
+    fn handle_events(&mut self, keys: &[Key]) -> Option<StateChanged> {
+        super::coalesce(keys.iter().filter_map(to_input_request))
+            .into_iter()
+            .fold(None, |resp, (req, count)| {
+                merge_responses(resp, self.handle_repeated(req, count))
+            })
+    }
+
+    fn handle_event_outcome(&mut self, key: &Key) -> Outcome {
+        self.handle_event_outcome_with_keymap(key, &outcome_keymap())
+    }
+
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        key: &Key,
+        keymap: &Keymap<Key, OutcomeAction>,
+    ) -> Outcome {
+        Outcome::of_key_action(self, to_key_action(key, keymap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_control_chars() {
+        assert_eq!(to_input_request(&Key::Tab), None);
+        assert_eq!(to_input_request(&Key::Char('\t')), None);
+        assert_eq!(
+            to_input_request(&Key::Char('\x17')),
+            Some(InputRequest::DeletePrevWord)
+        );
+        assert_eq!(
+            to_input_request(&Key::UnknownEscSeq(vec!['b'])),
+            Some(InputRequest::GoToPrevWord)
+        );
+    }
+
+    #[test]
+    fn default_keymap_matches_requests() {
+        let keymap = default_keymap::<()>();
+        for (key, action, _) in keymap.help() {
+            let req = to_input_request(key);
+            assert_eq!(req.map(KeyAction::Request).as_ref(), Some(action));
+        }
+        assert_eq!(key_name(&Key::UnknownEscSeq(vec!['\r'])), "Alt+Enter");
+    }
+
+    #[test]
+    fn handle_event() {
+        let mut input: Input = "ab".into();
+        input.handle_event(&Key::ArrowLeft);
+        input.handle_event(&Key::Char('x'));
+        assert_eq!(input.value(), "axb");
+    }
+}
@@ -1,3 +1,11 @@
+use super::{EscBehavior, Outcome, OutcomeAction, WriteOptions};
+use crate::completion::{CompletionRequest, CompletionState};
+use crate::input::merge_responses;
+use crate::keymap::{KeyAction, KeyPattern, Keymap};
+use crate::line_editor::EditorRequest;
+use crate::palette::PaletteRequest;
+use crate::slider::SliderRequest;
+use crate::spinner::SpinnerRequest;
 use crate::{Input, InputRequest, StateChanged};
 use ratatui::crossterm::event::{
     Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
@@ -9,7 +17,63 @@ use ratatui::crossterm::{
 };
 use std::io::{Result, Write};
 
+/// Converts crossterm event into input requests, including the paste events
+/// sent with bracketed paste enabled, inserting the pasted text.
+///
+/// Example:
+///
+/// ```
+/// use ratatui::crossterm::event::Event;
+/// use tui_input::backend::crossterm::to_input_requests;
+/// use tui_input::InputRequest::{InsertChar, InsertNewline};
+///
+/// let evt = Event::Paste("a\r\nb".into());
+/// assert_eq!(to_input_requests(&evt), vec![InsertChar('a'), InsertNewline, InsertChar('b')]);
+/// ```
+pub fn to_input_requests(evt: &CrosstermEvent) -> Vec<InputRequest> {
+    match evt {
+        CrosstermEvent::Paste(text) => text
+            .replace("\r\n", "\n")
+            .chars()
+            .map(|c| match c {
+                '\n' | '\r' => InputRequest::InsertNewline,
+                c => InputRequest::InsertChar(c),
+            })
+            .collect(),
+        evt => to_input_request(evt).into_iter().collect(),
+    }
+}
+
+/// Converts crossterm event into the requests handled by the input, making
+/// sure that pasting never submits, like [`InputRequest::PasteOverSelection`].
+fn event_requests(input: &Input, evt: &CrosstermEvent) -> Vec<InputRequest> {
+    let paste = matches!(evt, CrosstermEvent::Paste(_));
+    let mut requests = to_input_requests(evt);
+    for req in requests.iter_mut().filter(|_| paste) {
+        if input.submits(*req) {
+            *req = InputRequest::InsertChar(' ');
+        }
+    }
+    requests
+}
+
+/// Handle the requests of consecutive key events, repeated ones at once.
+fn handle_coalesced(
+    input: &mut Input,
+    requests: Vec<InputRequest>,
+) -> Option<StateChanged> {
+    super::coalesce(requests)
+        .into_iter()
+        .fold(None, |resp, (req, count)| {
+            merge_responses(resp, input.handle_repeated(req, count))
+        })
+}
+
 /// Converts crossterm event into input requests.
+///
+/// Keypad keys map like their counterparts on the main keyboard, and
+/// Shift+Left/Right/Home/End and Ctrl+Shift+Left/Right extend the selection
+/// like in GUI text fields.
 pub fn to_input_request(evt: &CrosstermEvent) -> Option<InputRequest> {
     use InputRequest::*;
     use KeyCode::*;
@@ -29,23 +93,37 @@ pub fn to_input_request(evt: &CrosstermEvent) -> Option<InputRequest> {
                 (Left, KeyModifiers::NONE) | (Char('b'), KeyModifiers::CONTROL) => {
                     Some(GoToPrevChar)
                 }
-                (Left, KeyModifiers::CONTROL) | (Char('b'), KeyModifiers::META) => {
-                    Some(GoToPrevWord)
-                }
+                (Left, KeyModifiers::CONTROL)
+                | (Left, KeyModifiers::ALT)
+                | (Char('b'), KeyModifiers::META)
+                | (Char('b'), KeyModifiers::ALT) => Some(GoToPrevWord),
                 (Right, KeyModifiers::NONE) | (Char('f'), KeyModifiers::CONTROL) => {
                     Some(GoToNextChar)
                 }
-                (Right, KeyModifiers::CONTROL) | (Char('f'), KeyModifiers::META) => {
-                    Some(GoToNextWord)
+                (Right, KeyModifiers::CONTROL)
+                | (Right, KeyModifiers::ALT)
+                | (Char('f'), KeyModifiers::META)
+                | (Char('f'), KeyModifiers::ALT) => Some(GoToNextWord),
+                (Up, KeyModifiers::NONE) => Some(GoToPrevLine),
+                (Down, KeyModifiers::NONE) => Some(GoToNextLine),
+                (Char('z'), KeyModifiers::CONTROL) => Some(Undo),
+                (Char('z') | Char('Z'), m)
+                    if m == KeyModifiers::CONTROL | KeyModifiers::SHIFT =>
+                {
+                    Some(Redo)
                 }
                 (Char('u'), KeyModifiers::CONTROL) => Some(DeleteLine),
+                (Char('r'), KeyModifiers::CONTROL) => Some(ToggleMask),
+                (Char('y'), KeyModifiers::CONTROL) => Some(PasteOverSelection),
 
                 (Char('w'), KeyModifiers::CONTROL)
                 | (Char('d'), KeyModifiers::META)
                 | (Backspace, KeyModifiers::META)
                 | (Backspace, KeyModifiers::ALT) => Some(DeletePrevWord),
 
-                (Delete, KeyModifiers::CONTROL) => Some(DeleteNextWord),
+                (Delete, KeyModifiers::CONTROL) | (Delete, KeyModifiers::ALT) => {
+                    Some(DeleteNextWord)
+                }
                 (Char('k'), KeyModifiers::CONTROL) => Some(DeleteTillEnd),
                 (Char('a'), KeyModifiers::CONTROL) | (Home, KeyModifiers::NONE) => {
                     Some(GoToStart)
@@ -53,6 +131,20 @@ pub fn to_input_request(evt: &CrosstermEvent) -> Option<InputRequest> {
                 (Char('e'), KeyModifiers::CONTROL) | (End, KeyModifiers::NONE) => {
                     Some(GoToEnd)
                 }
+                (Left, KeyModifiers::SHIFT) => Some(SelectPrevChar),
+                (Right, KeyModifiers::SHIFT) => Some(SelectNextChar),
+                (Left, m) if m == KeyModifiers::CONTROL | KeyModifiers::SHIFT => {
+                    Some(SelectPrevWord)
+                }
+                (Right, m) if m == KeyModifiers::CONTROL | KeyModifiers::SHIFT => {
+                    Some(SelectNextWord)
+                }
+                (Home, KeyModifiers::SHIFT) => Some(SelectToStart),
+                (End, KeyModifiers::SHIFT) => Some(SelectToEnd),
+                (Enter, KeyModifiers::ALT) | (Char('j'), KeyModifiers::CONTROL) => {
+                    Some(InsertNewline)
+                }
+                (Insert, KeyModifiers::NONE) => Some(ToggleOverwrite),
                 (Char(c), KeyModifiers::NONE) => Some(InsertChar(c)),
                 (Char(c), KeyModifiers::SHIFT) => Some(InsertChar(c)),
                 (_, _) => None,
@@ -62,6 +154,343 @@ pub fn to_input_request(evt: &CrosstermEvent) -> Option<InputRequest> {
     }
 }
 
+/// Converts crossterm event into a key action, looking it up in the keymap
+/// before falling back to the default mappings. Useful for binding function
+/// keys, media keys, or other special keys.
+///
+/// Example:
+///
+/// ```
+/// use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use tui_input::backend::crossterm::to_key_action;
+/// use tui_input::keymap::{KeyAction, Keymap};
+/// use tui_input::InputRequest;
+///
+/// let keymap: Keymap<_> = Keymap::default().bind(
+///     (KeyCode::F(2), KeyModifiers::NONE),
+///     KeyAction::Request(InputRequest::DeleteLine),
+/// );
+///
+/// let evt = Event::Key(KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE));
+/// assert_eq!(
+///     to_key_action(&evt, &keymap),
+///     Some(KeyAction::Request(InputRequest::DeleteLine))
+/// );
+/// ```
+pub fn to_key_action<C: Clone>(
+    evt: &CrosstermEvent,
+    keymap: &Keymap<(KeyCode, KeyModifiers), C>,
+) -> Option<KeyAction<C>> {
+    match evt {
+        CrosstermEvent::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+            state: _,
+        }) if *kind == KeyEventKind::Press || *kind == KeyEventKind::Repeat => keymap
+            .lookup(&(*code, *modifiers))
+            .cloned()
+            .or_else(|| to_input_request(evt).map(KeyAction::Request)),
+        _ => None,
+    }
+}
+
+/// Crossterm keys are equivalent regardless of the Shift modifier implied by
+/// the chars, e.g. `Z` is Shift+z, and Meta counts as Alt.
+impl KeyPattern for (KeyCode, KeyModifiers) {
+    fn normalized(&self) -> Self {
+        let (code, mut modifiers) = *self;
+        if modifiers.contains(KeyModifiers::META) {
+            modifiers.remove(KeyModifiers::META);
+            modifiers.insert(KeyModifiers::ALT);
+        }
+        let code = match code {
+            KeyCode::Char(c) if c.is_uppercase() => {
+                modifiers.insert(KeyModifiers::SHIFT);
+                KeyCode::Char(c.to_lowercase().next().unwrap_or(c))
+            }
+            KeyCode::Char(c) if !c.is_lowercase() => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                code
+            }
+            code => code,
+        };
+        (code, modifiers)
+    }
+
+    fn split_modifiers(&self) -> Option<(Self, u32)> {
+        Some(((self.0, KeyModifiers::NONE), self.1.bits().into()))
+    }
+}
+
+/// The default mappings of [`to_input_request`] as a keymap, except for the
+/// chars inserted as is. Useful for listing them in a help popup, see
+/// [`Keymap::help`] and [`key_name`].
+pub fn default_keymap<C>() -> Keymap<(KeyCode, KeyModifiers), C> {
+    use InputRequest::*;
+    use KeyAction::Request;
+    use KeyCode::*;
+    let (none, ctrl, alt, meta, shift) = (
+        KeyModifiers::NONE,
+        KeyModifiers::CONTROL,
+        KeyModifiers::ALT,
+        KeyModifiers::META,
+        KeyModifiers::SHIFT,
+    );
+    Keymap::default()
+        .bind((Backspace, none), Request(DeletePrevChar))
+        .bind((Char('h'), ctrl), Request(DeletePrevChar))
+        .bind((Delete, none), Request(DeleteNextChar))
+        .bind((Left, none), Request(GoToPrevChar))
+        .bind((Char('b'), ctrl), Request(GoToPrevChar))
+        .bind((Left, ctrl), Request(GoToPrevWord))
+        .bind((Left, alt), Request(GoToPrevWord))
+        .bind((Char('b'), meta), Request(GoToPrevWord))
+        .bind((Char('b'), alt), Request(GoToPrevWord))
+        .bind((Right, none), Request(GoToNextChar))
+        .bind((Char('f'), ctrl), Request(GoToNextChar))
+        .bind((Right, ctrl), Request(GoToNextWord))
+        .bind((Right, alt), Request(GoToNextWord))
+        .bind((Char('f'), meta), Request(GoToNextWord))
+        .bind((Char('f'), alt), Request(GoToNextWord))
+        .bind((Up, none), Request(GoToPrevLine))
+        .bind((Down, none), Request(GoToNextLine))
+        .bind((Char('z'), ctrl), Request(Undo))
+        .bind((Char('Z'), ctrl | shift), Request(Redo))
+        .bind((Char('u'), ctrl), Request(DeleteLine))
+        .bind((Char('r'), ctrl), Request(ToggleMask))
+        .bind((Char('y'), ctrl), Request(PasteOverSelection))
+        .bind((Char('w'), ctrl), Request(DeletePrevWord))
+        .bind((Char('d'), meta), Request(DeletePrevWord))
+        .bind((Backspace, meta), Request(DeletePrevWord))
+        .bind((Backspace, alt), Request(DeletePrevWord))
+        .bind((Delete, ctrl), Request(DeleteNextWord))
+        .bind((Delete, alt), Request(DeleteNextWord))
+        .bind((Char('k'), ctrl), Request(DeleteTillEnd))
+        .bind((Char('a'), ctrl), Request(GoToStart))
+        .bind((Home, none), Request(GoToStart))
+        .bind((Char('e'), ctrl), Request(GoToEnd))
+        .bind((End, none), Request(GoToEnd))
+        .bind((Left, shift), Request(SelectPrevChar))
+        .bind((Right, shift), Request(SelectNextChar))
+        .bind((Left, ctrl | shift), Request(SelectPrevWord))
+        .bind((Right, ctrl | shift), Request(SelectNextWord))
+        .bind((Home, shift), Request(SelectToStart))
+        .bind((End, shift), Request(SelectToEnd))
+        .bind((Enter, alt), Request(InsertNewline))
+        .bind((Char('j'), ctrl), Request(InsertNewline))
+        .bind((Insert, none), Request(ToggleOverwrite))
+}
+
+/// Get a human readable name of the key, e.g. `"Ctrl+W"` or `"Alt+Left"`.
+///
+/// ```
+/// use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+/// use tui_input::backend::crossterm::key_name;
+///
+/// assert_eq!(key_name(&(KeyCode::Char('w'), KeyModifiers::CONTROL)), "Ctrl+W");
+/// assert_eq!(key_name(&(KeyCode::Left, KeyModifiers::ALT)), "Alt+Left");
+/// ```
+pub fn key_name(key: &(KeyCode, KeyModifiers)) -> String {
+    let (code, modifiers) = *key;
+    let mut name = String::new();
+    for (modifier, prefix) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::META, "Meta+"),
+        (KeyModifiers::SUPER, "Super+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+    ] {
+        if modifiers.contains(modifier) {
+            name.push_str(prefix);
+        }
+    }
+    match code {
+        KeyCode::Char(' ') => name.push_str("Space"),
+        KeyCode::Char(c) if modifiers.is_empty() => name.push(c),
+        KeyCode::Char(c) => name.extend(c.to_uppercase()),
+        KeyCode::Backspace => name.push_str("Backspace"),
+        KeyCode::Delete => name.push_str("Delete"),
+        KeyCode::Enter => name.push_str("Enter"),
+        KeyCode::Esc => name.push_str("Esc"),
+        code => name.push_str(&code.to_string()),
+    }
+    name
+}
+
+/// Key bindings of GUI text fields, to be used with [`to_key_action`]:
+/// Ctrl+A selects all, Ctrl+C/X/V copy, cut and paste, Ctrl+Z undoes and
+/// Ctrl+Shift+Z redoes.
+pub fn gui_keymap<C>() -> Keymap<(KeyCode, KeyModifiers), C> {
+    use InputRequest::*;
+    use KeyAction::Request;
+    let ctrl = KeyModifiers::CONTROL;
+    Keymap::default()
+        .bind((KeyCode::Char('a'), ctrl), Request(SelectAll))
+        .bind((KeyCode::Char('c'), ctrl), Request(CopySelection))
+        .bind((KeyCode::Char('x'), ctrl), Request(CutSelection))
+        .bind((KeyCode::Char('v'), ctrl), Request(PasteOverSelection))
+        .bind((KeyCode::Char('z'), ctrl), Request(Undo))
+        .bind(
+            (KeyCode::Char('Z'), ctrl | KeyModifiers::SHIFT),
+            Request(Redo),
+        )
+}
+
+/// Key bindings of a [`LineEditor`](crate::line_editor::LineEditor), to be used
+/// with [`to_key_action`]: Enter accepts the line, Esc and Ctrl+C discard it,
+/// Up/Down and Ctrl+P/N recall the history, and Tab completes.
+pub fn line_editor_keymap() -> Keymap<(KeyCode, KeyModifiers), EditorRequest> {
+    use EditorRequest::*;
+    use KeyAction::Custom;
+    let (none, ctrl) = (KeyModifiers::NONE, KeyModifiers::CONTROL);
+    Keymap::default()
+        .bind((KeyCode::Enter, none), Custom(Accept))
+        .bind((KeyCode::Esc, none), Custom(Cancel))
+        .bind((KeyCode::Char('c'), ctrl), Custom(Cancel))
+        .bind((KeyCode::Up, none), Custom(PrevHistory))
+        .bind((KeyCode::Char('p'), ctrl), Custom(PrevHistory))
+        .bind((KeyCode::Down, none), Custom(NextHistory))
+        .bind((KeyCode::Char('n'), ctrl), Custom(NextHistory))
+        .bind((KeyCode::Tab, none), Custom(Complete))
+}
+
+/// Keys finishing the input, to be used with
+/// [`EventHandler::handle_event_outcome_with_keymap`]: Enter submits and Esc
+/// cancels.
+pub fn outcome_keymap() -> Keymap<(KeyCode, KeyModifiers), OutcomeAction> {
+    outcome_keymap_with_esc(EscBehavior::Cancel)
+}
+
+/// Like [`outcome_keymap`], with Esc doing the given action instead.
+///
+/// Example:
+///
+/// ```
+/// use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use tui_input::backend::crossterm::{outcome_keymap_with_esc, EventHandler};
+/// use tui_input::backend::{EscBehavior, Outcome};
+/// use tui_input::{Input, InputRequest};
+///
+/// let keymap = outcome_keymap_with_esc(EscBehavior::Revert);
+/// let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+///
+/// let mut input = Input::from("draft");
+/// input.handle(InputRequest::DeletePrevChar);
+/// input.handle_event_outcome_with_keymap(&esc, &keymap);
+/// assert_eq!(input.value(), "draft");
+/// ```
+pub fn outcome_keymap_with_esc(
+    esc: EscBehavior,
+) -> Keymap<(KeyCode, KeyModifiers), OutcomeAction> {
+    let keymap = Keymap::default().bind(
+        (KeyCode::Enter, KeyModifiers::NONE),
+        KeyAction::Custom(OutcomeAction::Submit),
+    );
+    match esc.action() {
+        Some(action) => keymap.bind((KeyCode::Esc, KeyModifiers::NONE), action),
+        None => keymap,
+    }
+}
+
+/// Converts crossterm event into completion menu requests.
+pub fn to_completion_request(evt: &CrosstermEvent) -> Option<CompletionRequest> {
+    use CompletionRequest::*;
+    use KeyCode::*;
+    match evt {
+        CrosstermEvent::Key(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind,
+            state: _,
+        }) if *kind == KeyEventKind::Press || *kind == KeyEventKind::Repeat => {
+            match code {
+                Up => Some(SelectPrev),
+                Down => Some(SelectNext),
+                Tab => Some(Accept),
+                Esc => Some(Dismiss),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Converts crossterm event into command palette requests.
+/// Up and Down select a command, Enter accepts it, other keys edit the query.
+pub fn to_palette_request(evt: &CrosstermEvent) -> Option<PaletteRequest> {
+    match evt {
+        CrosstermEvent::Key(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind,
+            state: _,
+        }) if *kind == KeyEventKind::Press || *kind == KeyEventKind::Repeat => {
+            match code {
+                KeyCode::Up => Some(PaletteRequest::SelectPrev),
+                KeyCode::Down => Some(PaletteRequest::SelectNext),
+                KeyCode::Enter => Some(PaletteRequest::Accept),
+                _ => to_input_request(evt).map(PaletteRequest::Input),
+            }
+        }
+        _ => to_input_request(evt).map(PaletteRequest::Input),
+    }
+}
+
+/// Converts crossterm event into spinner requests.
+/// Up and Down step the value, other keys edit it.
+pub fn to_spinner_request(evt: &CrosstermEvent) -> Option<SpinnerRequest> {
+    match evt {
+        CrosstermEvent::Key(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind,
+            state: _,
+        }) if *kind == KeyEventKind::Press || *kind == KeyEventKind::Repeat => {
+            match code {
+                KeyCode::Up => Some(SpinnerRequest::Increment),
+                KeyCode::Down => Some(SpinnerRequest::Decrement),
+                _ => to_input_request(evt).map(SpinnerRequest::Input),
+            }
+        }
+        _ => to_input_request(evt).map(SpinnerRequest::Input),
+    }
+}
+
+/// Converts crossterm event into slider requests.
+/// Shift accelerates the step.
+pub fn to_slider_request(evt: &CrosstermEvent) -> Option<SliderRequest> {
+    use KeyCode::*;
+    use SliderRequest::*;
+    match evt {
+        CrosstermEvent::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+            state: _,
+        }) if *kind == KeyEventKind::Press || *kind == KeyEventKind::Repeat => {
+            match (*code, *modifiers) {
+                (Left, KeyModifiers::NONE) | (Down, KeyModifiers::NONE) => {
+                    Some(Decrement)
+                }
+                (Right, KeyModifiers::NONE) | (Up, KeyModifiers::NONE) => {
+                    Some(Increment)
+                }
+                (Left, KeyModifiers::SHIFT) | (Down, KeyModifiers::SHIFT) => {
+                    Some(DecrementFast)
+                }
+                (Right, KeyModifiers::SHIFT) | (Up, KeyModifiers::SHIFT) => {
+                    Some(IncrementFast)
+                }
+                (Home, KeyModifiers::NONE) => Some(GoToMin),
+                (End, KeyModifiers::NONE) => Some(GoToMax),
+                (_, _) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Renders the input UI at the given position with the given width.
 pub fn write<W: Write>(
     stdout: &mut W,
@@ -70,36 +499,54 @@ pub fn write<W: Write>(
     (x, y): (u16, u16),
     width: u16,
 ) -> Result<()> {
-    queue!(stdout, MoveTo(x, y), SetAttribute(CAttribute::NoReverse))?;
+    write_with_options(stdout, value, cursor, (x, y), width, &Default::default())
+}
 
-    let val_width = width.max(1) as usize - 1;
+/// Renders the input UI at the given position with the given width and options.
+pub fn write_with_options<W: Write>(
+    stdout: &mut W,
+    value: &str,
+    cursor: usize,
+    (x, y): (u16, u16),
+    width: u16,
+    options: &WriteOptions,
+) -> Result<()> {
+    queue!(
+        stdout,
+        MoveTo(x, y),
+        SetAttribute(CAttribute::NoReverse),
+        Print(&options.prefix)
+    )?;
+
+    let width = options.value_width(width);
     let len = value.chars().count();
-    let start = (len.max(val_width) - val_width).min(cursor);
-    let mut chars = value.chars().skip(start);
-    let mut i = start;
+    let padding = options.alignment.padding(len, width as usize);
+    for _ in 0..padding {
+        queue!(stdout, Print(' '))?;
+    }
 
-    // Chars before cursor
-    while i < cursor {
-        i += 1;
+    let (_, visible, cursor) =
+        super::visible_window(value, cursor, width as usize - padding);
+    let mut chars = visible.chars();
+
+    for i in 0..(width as usize - padding).max(1) {
         let c = chars.next().unwrap_or(' ');
-        queue!(stdout, Print(c))?;
+        if i == cursor && !options.hide_cursor {
+            queue!(
+                stdout,
+                SetAttribute(CAttribute::Reverse),
+                Print(c),
+                SetAttribute(CAttribute::NoReverse)
+            )?;
+        } else {
+            queue!(stdout, Print(c))?;
+        }
     }
 
-    // Cursor
-    i += 1;
-    let c = chars.next().unwrap_or(' ');
-    queue!(
-        stdout,
-        SetAttribute(CAttribute::Reverse),
-        Print(c),
-        SetAttribute(CAttribute::NoReverse)
-    )?;
+    queue!(stdout, Print(&options.suffix))?;
 
-    // Chars after the cursor
-    while i <= start + val_width {
-        i += 1;
-        let c = chars.next().unwrap_or(' ');
-        queue!(stdout, Print(c))?;
+    if let Some(text) = &options.clipboard {
+        queue!(stdout, Print(super::osc52(text)))?;
     }
 
     Ok(())
@@ -107,14 +554,134 @@ pub fn write<W: Write>(
 
 /// Import this trait to implement `Input::handle_event()` for crossterm.
 pub trait EventHandler {
-    /// Handle crossterm event.
+    /// Handle crossterm event, applying the requests of a paste event as a
+    /// single edit, see [`to_input_requests`] and [`Input::handle_all`].
     fn handle_event(&mut self, evt: &CrosstermEvent) -> Option<StateChanged>;
+
+    /// Handle the crossterm events received since the last frame, coalescing the
+    /// bursts of identical requests, see [`coalesce`](super::coalesce).
+    fn handle_events(&mut self, evts: &[CrosstermEvent]) -> Option<StateChanged>;
+
+    /// Handle crossterm event, routing the completion menu keys to the given
+    /// state while the menu is open.
+    ///
+    /// Returns `true` if the menu state changed, along with the response of
+    /// the input.
+    fn handle_event_with_completions(
+        &mut self,
+        evt: &CrosstermEvent,
+        completions: &mut CompletionState,
+    ) -> (bool, Option<StateChanged>);
+
+    /// Handle crossterm event, submitting the value on Enter and cancelling
+    /// on Esc.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use tui_input::backend::crossterm::EventHandler;
+    /// use tui_input::backend::Outcome;
+    /// use tui_input::Input;
+    ///
+    /// let mut input: Input = "Hello".into();
+    /// let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    ///
+    /// assert_eq!(input.handle_event_outcome(&enter), Outcome::Submitted("Hello".into()));
+    /// assert_eq!(input.value(), "");
+    /// ```
+    fn handle_event_outcome(&mut self, evt: &CrosstermEvent) -> Outcome;
+
+    /// Handle crossterm event, submitting or cancelling the value with the
+    /// keys bound in the given keymap, see [`outcome_keymap`]. Pastes are
+    /// inserted as a single edit, like in [`EventHandler::handle_event`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use tui_input::backend::crossterm::{outcome_keymap, EventHandler};
+    /// use tui_input::backend::{Outcome, OutcomeAction};
+    /// use tui_input::keymap::KeyAction;
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// // Ctrl+Enter submits, Enter inserts a newline, and Esc is disabled.
+    /// let mut keymap = outcome_keymap()
+    ///     .bind((KeyCode::Enter, KeyModifiers::CONTROL), KeyAction::Custom(OutcomeAction::Submit))
+    ///     .bind((KeyCode::Enter, KeyModifiers::NONE), KeyAction::Request(InputRequest::InsertNewline));
+    /// keymap.remove(&(KeyCode::Esc, KeyModifiers::NONE));
+    ///
+    /// let mut input = Input::from("a").with_multiline(true);
+    /// let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+    ///
+    /// input.handle_event_outcome_with_keymap(&key(KeyCode::Enter, KeyModifiers::NONE), &keymap);
+    /// let outcome =
+    ///     input.handle_event_outcome_with_keymap(&key(KeyCode::Esc, KeyModifiers::NONE), &keymap);
+    /// assert_eq!(outcome, Outcome::Ignored);
+    ///
+    /// let outcome = input
+    ///     .handle_event_outcome_with_keymap(&key(KeyCode::Enter, KeyModifiers::CONTROL), &keymap);
+    /// assert_eq!(outcome, Outcome::Submitted("a\n".into()));
+    /// ```
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        evt: &CrosstermEvent,
+        keymap: &Keymap<(KeyCode, KeyModifiers), OutcomeAction>,
+    ) -> Outcome;
 }
 
 impl EventHandler for Input {
     /// Handle crossterm event.
     fn handle_event(&mut self, evt: &CrosstermEvent) -> Option<StateChanged> {
-        to_input_request(evt).and_then(|req| self.handle(req))
+        match event_requests(self, evt).as_slice() {
+            [req] => self.handle(*req),
+            requests => self.handle_all(requests.iter().copied()),
+        }
+    }
+
+    fn handle_events(&mut self, evts: &[CrosstermEvent]) -> Option<StateChanged> {
+        let mut resp = None;
+        let mut keys = Vec::new();
+        for evt in evts {
+            if let CrosstermEvent::Paste(_) = evt {
+                let keys = std::mem::take(&mut keys);
+                resp = merge_responses(resp, handle_coalesced(self, keys));
+                let requests = event_requests(self, evt);
+                resp = merge_responses(resp, self.handle_all(requests));
+            } else {
+                keys.extend(event_requests(self, evt));
+            }
+        }
+        merge_responses(resp, handle_coalesced(self, keys))
+    }
+
+    fn handle_event_with_completions(
+        &mut self,
+        evt: &CrosstermEvent,
+        completions: &mut CompletionState,
+    ) -> (bool, Option<StateChanged>) {
+        match to_completion_request(evt).filter(|_| completions.is_open()) {
+            Some(req) => completions.handle(req, self),
+            None => (false, self.handle_event(evt)),
+        }
+    }
+
+    fn handle_event_outcome(&mut self, evt: &CrosstermEvent) -> Outcome {
+        self.handle_event_outcome_with_keymap(evt, &outcome_keymap())
+    }
+
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        evt: &CrosstermEvent,
+        keymap: &Keymap<(KeyCode, KeyModifiers), OutcomeAction>,
+    ) -> Outcome {
+        match evt {
+            CrosstermEvent::Key(_) => {
+                Outcome::of_key_action(self, to_key_action(evt, keymap))
+            }
+            // Pastes are inserted, never submitted.
+            _ => self.handle_all(event_requests(self, evt)).into(),
+        }
     }
 }
 
@@ -151,4 +718,273 @@ mod tests {
 
         assert_eq!(req, Some(InputRequest::InsertChar('a')));
     }
+
+    #[test]
+    fn handle_shift_selection() {
+        let key = |code| CrosstermEvent::Key(KeyEvent::new(code, KeyModifiers::SHIFT));
+        let mut input = Input::from("Hello World");
+        input.handle_event(&key(KeyCode::Left));
+        input.handle_event(&key(KeyCode::Left));
+        assert_eq!(input.selection(), Some(9..11));
+        input.handle_event(&key(KeyCode::Home));
+        assert_eq!(input.selection(), Some(0..11));
+        input.handle_event(&key(KeyCode::Right));
+        assert_eq!(input.selection(), Some(1..11));
+
+        input.handle(InputRequest::GoToEnd);
+        assert_eq!(input.selection(), None);
+    }
+
+    #[test]
+    fn handle_paste_outcome() {
+        let mut input = Input::from("a");
+        let outcome = input.handle_event_outcome(&CrosstermEvent::Paste("b\nc".into()));
+        assert!(matches!(outcome, Outcome::Changed(_)));
+        assert_eq!(input.value(), "abc");
+        assert_eq!(
+            input.handle_event_outcome(&CrosstermEvent::FocusGained),
+            Outcome::Ignored
+        );
+    }
+
+    #[test]
+    fn handle_insert_key() {
+        let evt =
+            CrosstermEvent::Key(KeyEvent::new(KeyCode::Insert, KeyModifiers::NONE));
+        let mut input = Input::from("ab").with_cursor(0);
+        input.handle_event(&evt);
+        assert!(input.is_overwrite());
+        input.handle_event(&CrosstermEvent::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(input.value(), "cb");
+    }
+
+    #[test]
+    fn lookup_with_extra_modifiers() {
+        let keymap: Keymap<_, char> = Keymap::default()
+            .bind(
+                (KeyCode::Left, KeyModifiers::CONTROL),
+                KeyAction::Custom('c'),
+            )
+            .bind((KeyCode::Left, KeyModifiers::ALT), KeyAction::Custom('a'))
+            .bind(
+                (KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+                KeyAction::Custom('s'),
+            );
+        let lookup = |modifiers| keymap.lookup(&(KeyCode::Left, modifiers)).cloned();
+        let ctrl = KeyModifiers::CONTROL;
+        assert_eq!(
+            lookup(ctrl | KeyModifiers::SUPER),
+            Some(KeyAction::Custom('c'))
+        );
+        assert_eq!(
+            lookup(ctrl | KeyModifiers::ALT),
+            Some(KeyAction::Custom('c'))
+        );
+        assert_eq!(
+            lookup(ctrl | KeyModifiers::SHIFT | KeyModifiers::ALT),
+            Some(KeyAction::Custom('s'))
+        );
+        assert_eq!(lookup(KeyModifiers::SHIFT), None);
+    }
+
+    #[test]
+    fn lookup_equivalent_keys() {
+        let keymap: Keymap<_> = Keymap::default().bind(
+            (
+                KeyCode::Char('z'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            ),
+            KeyAction::Request(InputRequest::Redo),
+        );
+        let redo = Some(&KeyAction::Request(InputRequest::Redo));
+        assert_eq!(
+            keymap.lookup(&(KeyCode::Char('Z'), KeyModifiers::CONTROL)),
+            redo
+        );
+        assert_eq!(
+            keymap.lookup(&(
+                KeyCode::Char('Z'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            redo
+        );
+        assert_eq!(
+            keymap.lookup(&(KeyCode::Char('z'), KeyModifiers::CONTROL)),
+            None
+        );
+
+        let key = |code| {
+            CrosstermEvent::Key(KeyEvent::new(
+                code,
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            ))
+        };
+        let mut input = Input::from("foo bar baz");
+        input.handle_event(&key(KeyCode::Left));
+        input.handle_event(&key(KeyCode::Left));
+        assert_eq!(input.selection(), Some(4..11));
+    }
+
+    #[test]
+    fn handle_paste() {
+        let mut input =
+            Input::from("a").with_newline_policy(crate::NewlinePolicy::Submit);
+        let resp = input.handle_event(&CrosstermEvent::Paste("b\nc".into()));
+        assert!(resp.is_some_and(|r| r.value));
+        assert_eq!(input.value(), "ab c");
+
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "a");
+
+        let key =
+            |c| CrosstermEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        input.handle_event(&key('x'));
+        input.handle_event(&key('y'));
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "a");
+    }
+
+    #[test]
+    fn handle_events() {
+        let evt = |code| CrosstermEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let mut input = Input::from("Hello");
+        let mut events = vec![evt(KeyCode::Left); 2];
+        events.extend(vec![evt(KeyCode::Backspace); 10]);
+        events.push(evt(KeyCode::Char('J')));
+
+        let resp = input.handle_events(&events);
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true
+            })
+        );
+        assert_eq!(input.value(), "Jlo");
+        assert_eq!(input.cursor(), 1);
+
+        let paste = CrosstermEvent::Paste("xyz".into());
+        input.handle_events(&[evt(KeyCode::End), paste.clone(), paste]);
+        assert_eq!(input.value(), "Jloxyzxyz");
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "Jloxyz");
+    }
+
+    #[test]
+    fn handle_completion_keys() {
+        let evt = |code| CrosstermEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let mut input = Input::from("ls -");
+        let mut completions = CompletionState::default();
+
+        let resp =
+            input.handle_event_with_completions(&evt(KeyCode::Tab), &mut completions);
+        assert_eq!(resp, (false, None));
+
+        let completion = crate::completion::Completion {
+            range: input.word_before_cursor(),
+            text: "-a".into(),
+        };
+        completions.set_completions(vec![completion]);
+
+        let (changed, resp) =
+            input.handle_event_with_completions(&evt(KeyCode::Tab), &mut completions);
+        assert!(changed);
+        assert!(resp.is_some());
+        assert_eq!(input.value(), "ls -a");
+        assert!(!completions.is_open());
+    }
+
+    #[test]
+    fn handle_option_keys() {
+        let evt = |code| CrosstermEvent::Key(KeyEvent::new(code, KeyModifiers::ALT));
+        assert_eq!(
+            to_input_request(&evt(KeyCode::Left)),
+            Some(InputRequest::GoToPrevWord)
+        );
+        assert_eq!(
+            to_input_request(&evt(KeyCode::Char('f'))),
+            Some(InputRequest::GoToNextWord)
+        );
+        assert_eq!(
+            to_input_request(&evt(KeyCode::Delete)),
+            Some(InputRequest::DeleteNextWord)
+        );
+    }
+
+    #[test]
+    fn handle_keypad() {
+        let evt = |code| {
+            CrosstermEvent::Key(KeyEvent::new_with_kind_and_state(
+                code,
+                KeyModifiers::NONE,
+                KeyEventKind::Press,
+                KeyEventState::KEYPAD,
+            ))
+        };
+        assert_eq!(
+            to_input_request(&evt(KeyCode::Char('7'))),
+            Some(InputRequest::InsertChar('7'))
+        );
+        assert_eq!(
+            to_input_request(&evt(KeyCode::Home)),
+            Some(InputRequest::GoToStart)
+        );
+        assert_eq!(to_input_request(&evt(KeyCode::KeypadBegin)), None);
+    }
+
+    #[test]
+    fn default_keymap_matches_requests() {
+        let keymap = default_keymap::<()>();
+        for (key, action, description) in keymap.help() {
+            let evt = CrosstermEvent::Key(KeyEvent::new(key.0, key.1));
+            assert_eq!(
+                to_input_request(&evt).map(KeyAction::Request).as_ref(),
+                Some(action)
+            );
+            assert!(description.is_some());
+        }
+        assert_eq!(
+            key_name(&(
+                KeyCode::Char('Z'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            "Ctrl+Shift+Z"
+        );
+    }
+
+    #[test]
+    fn gui_keymap_select_all() {
+        let keymap = gui_keymap::<()>();
+        let evt = CrosstermEvent::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL,
+        ));
+        assert_eq!(
+            to_key_action(&evt, &keymap),
+            Some(KeyAction::Request(InputRequest::SelectAll))
+        );
+        assert_eq!(to_input_request(&evt), Some(InputRequest::GoToStart));
+    }
+
+    #[test]
+    fn handle_slider_keys() {
+        let evt =
+            CrosstermEvent::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT));
+        assert_eq!(to_slider_request(&evt), Some(SliderRequest::IncrementFast));
+    }
+
+    #[test]
+    fn write_with_clipboard() {
+        let mut out = Vec::new();
+        let options = WriteOptions::default().clipboard("hi".into());
+        write_with_options(&mut out, "hi", 2, (0, 0), 3, &options).unwrap();
+        assert!(out.ends_with(b"\x1b]52;c;aGk=\x07"));
+
+        let mut out = Vec::new();
+        write(&mut out, "hi", 2, (0, 0), 3).unwrap();
+        assert!(!out.ends_with(b"\x07"));
+    }
 }
@@ -1,5 +1,324 @@
+#[cfg(feature = "console")]
+pub mod console;
+
 #[cfg(feature = "crossterm")]
 pub mod crossterm;
 
 #[cfg(feature = "termion")]
 pub mod termion;
+
+use crate::{InputRequest, StateChanged};
+use std::io::{Result, Write};
+
+/// The outcome of an event handled by the `handle_event_outcome` method of
+/// the backends' `EventHandler`, interpreting Enter and Esc.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    /// The event changed the input state.
+    Changed(StateChanged),
+    /// The value was submitted, resetting the input.
+    Submitted(String),
+    /// The input was cancelled. The value is kept.
+    Cancelled,
+    /// The event was ignored or didn't change anything.
+    Ignored,
+}
+
+/// Actions finishing the input, bound to keys as
+/// [`KeyAction::Custom`](crate::keymap::KeyAction::Custom) actions in the
+/// keymaps given to `handle_event_outcome_with_keymap`.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutcomeAction {
+    Submit,
+    Cancel,
+    /// Clear the value, as a single undo step.
+    Clear,
+    /// Restore the value the input had before the first edit, see
+    /// [`Input::undo_to`](crate::Input::undo_to).
+    Revert,
+}
+
+/// What Esc does in the keymaps built by the backends'
+/// `outcome_keymap_with_esc`.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EscBehavior {
+    /// Cancel the input, keeping the value.
+    #[default]
+    Cancel,
+    /// Clear the value.
+    Clear,
+    /// Restore the value the input had before the first edit.
+    Revert,
+    /// Leave Esc unbound, so that it's ignored by the input and can be
+    /// handled by the application.
+    PassThrough,
+}
+
+impl EscBehavior {
+    /// Get the action bound to Esc, if any.
+    pub fn action(self) -> Option<crate::keymap::KeyAction<OutcomeAction>> {
+        use crate::keymap::KeyAction::Custom;
+        match self {
+            Self::Cancel => Some(Custom(OutcomeAction::Cancel)),
+            Self::Clear => Some(Custom(OutcomeAction::Clear)),
+            Self::Revert => Some(Custom(OutcomeAction::Revert)),
+            Self::PassThrough => None,
+        }
+    }
+}
+
+impl Outcome {
+    /// Apply the key action to the input, submitting or cancelling it on the
+    /// matching [`OutcomeAction`]. Requests submitting the value, see
+    /// [`Input::submits`](crate::Input::submits), are reported as submitted
+    /// too.
+    pub fn of_key_action(
+        input: &mut crate::Input,
+        action: Option<crate::keymap::KeyAction<OutcomeAction>>,
+    ) -> Self {
+        use crate::keymap::KeyAction;
+        match action {
+            Some(KeyAction::Custom(OutcomeAction::Submit)) => {
+                Self::Submitted(input.value_and_reset())
+            }
+            Some(KeyAction::Custom(OutcomeAction::Cancel)) => Self::Cancelled,
+            Some(KeyAction::Custom(OutcomeAction::Clear)) => input
+                .handle_all([InputRequest::SelectAll, InputRequest::DeletePrevChar])
+                .into(),
+            Some(KeyAction::Custom(OutcomeAction::Revert)) => input.undo_to(0).into(),
+            Some(KeyAction::Request(req)) if input.submits(req) => {
+                Self::Submitted(input.value_and_reset())
+            }
+            Some(KeyAction::Request(req)) => input.handle(req).into(),
+            None => Self::Ignored,
+        }
+    }
+}
+
+impl From<Option<StateChanged>> for Outcome {
+    fn from(resp: Option<StateChanged>) -> Self {
+        resp.map_or(Self::Ignored, Self::Changed)
+    }
+}
+
+/// Horizontal alignment of the value when it fits in the given width.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Options used by the backends to render the input.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct WriteOptions {
+    pub alignment: Alignment,
+    /// Static text rendered before the editable region.
+    pub prefix: String,
+    /// Static text rendered after the editable region.
+    pub suffix: String,
+    /// Render the cursor like the other chars, e.g. while blinking.
+    pub hide_cursor: bool,
+    /// Text copied to the terminal clipboard while rendering, see
+    /// [`WriteOptions::clipboard`].
+    pub clipboard: Option<String>,
+}
+
+impl WriteOptions {
+    /// Set the static text rendered before the editable region, e.g. `"> "`.
+    pub fn prefix(mut self, prefix: String) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Set the static text rendered after the editable region, e.g. `" MB"`.
+    pub fn suffix(mut self, suffix: String) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Get the width left for the editable region out of the given width, in
+    /// terminal columns.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::backend::WriteOptions;
+    ///
+    /// let options = WriteOptions::default().prefix("❯ ".into()).suffix(" 円".into());
+    /// assert_eq!(options.value_width(10), 5);
+    /// ```
+    pub fn value_width(&self, width: u16) -> u16 {
+        use unicode_width::UnicodeWidthStr;
+        let affixes = self.prefix.width() + self.suffix.width();
+        (width as usize).max(affixes + 1) as u16 - affixes as u16
+    }
+
+    /// Set whether the cursor is rendered, see
+    /// [`CursorBlink`](crate::blink::CursorBlink).
+    pub fn cursor_visible(mut self, visible: bool) -> Self {
+        self.hide_cursor = !visible;
+        self
+    }
+
+    /// Set the alignment of the value.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Copy the text to the terminal clipboard with the OSC 52 escape sequence
+    /// while rendering, e.g. [`Input::clipboard`](crate::Input::clipboard)
+    /// after a copy or a cut. See [`copy_osc52`].
+    pub fn clipboard(mut self, text: String) -> Self {
+        self.clipboard = Some(text);
+        self
+    }
+}
+
+impl Alignment {
+    /// Get the padding to insert before a value of the given length so that
+    /// it's aligned within the given width. One column is always kept for the
+    /// cursor.
+    pub fn padding(self, len: usize, width: usize) -> usize {
+        let free = width.max(len + 1) - (len + 1);
+        match self {
+            Self::Left => 0,
+            Self::Center => free / 2,
+            Self::Right => free,
+        }
+    }
+}
+
+/// Get the part of the value visible in the given width (in chars), keeping
+/// the cursor in view. One column is always kept for the cursor.
+///
+/// Returns the char index the window starts at, the visible text, and the
+/// cursor offset within the window.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::backend::visible_window;
+///
+/// assert_eq!(visible_window("Hello World", 11, 5), (7, "orld", 4));
+/// assert_eq!(visible_window("Hello World", 2, 5), (2, "llo W", 0));
+/// assert_eq!(visible_window("Hi", 1, 5), (0, "Hi", 1));
+/// ```
+pub fn visible_window(value: &str, cursor: usize, width: usize) -> (usize, &str, usize) {
+    let width = width.max(1);
+    let len = value.chars().count();
+    let cursor = cursor.min(len);
+    let start = (len.max(width - 1) - (width - 1)).min(cursor);
+
+    let mut indices = value.char_indices().map(|(i, _)| i).skip(start);
+    let from = indices.next().unwrap_or(value.len());
+    let to = indices.nth(width - 1).unwrap_or(value.len());
+    (start, &value[from..to], cursor - start)
+}
+
+/// Group the consecutive identical requests, e.g. of a held Backspace or arrow
+/// key delivered between two frames, to handle each group at once with
+/// [`Input::handle_repeated`](crate::Input::handle_repeated) and redraw once.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::backend::coalesce;
+/// use tui_input::InputRequest::{DeletePrevChar, GoToPrevChar};
+///
+/// let requests = [DeletePrevChar, DeletePrevChar, GoToPrevChar, DeletePrevChar];
+/// assert_eq!(
+///     coalesce(requests),
+///     vec![(DeletePrevChar, 2), (GoToPrevChar, 1), (DeletePrevChar, 1)]
+/// );
+/// ```
+pub fn coalesce<I>(requests: I) -> Vec<(InputRequest, usize)>
+where
+    I: IntoIterator<Item = InputRequest>,
+{
+    let mut groups: Vec<(InputRequest, usize)> = Vec::new();
+    for req in requests {
+        match groups.last_mut() {
+            Some((last, count)) if *last == req => *count += 1,
+            _ => groups.push((req, 1)),
+        }
+    }
+    groups
+}
+
+/// Copy the text to the terminal clipboard using the OSC 52 escape sequence.
+///
+/// Unlike native clipboard crates, this works over SSH, as long as the
+/// terminal supports it. Use it with [`Input::clipboard`](crate::Input::clipboard)
+/// after copying, cutting or deleting text, or let the backends write it with
+/// [`WriteOptions::clipboard`].
+///
+/// Example:
+///
+/// ```
+/// use tui_input::backend::copy_osc52;
+///
+/// let mut out = Vec::new();
+/// copy_osc52(&mut out, "hello").unwrap();
+///
+/// assert_eq!(out, b"\x1b]52;c;aGVsbG8=\x07");
+/// ```
+pub fn copy_osc52<W: Write>(out: &mut W, text: &str) -> Result<()> {
+    out.write_all(osc52(text).as_bytes())?;
+    out.flush()
+}
+
+/// Get the OSC 52 escape sequence setting the terminal clipboard.
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const CHARS: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(CHARS[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding() {
+        assert_eq!(Alignment::Left.padding(3, 10), 0);
+        assert_eq!(Alignment::Center.padding(3, 10), 3);
+        assert_eq!(Alignment::Right.padding(3, 10), 6);
+        assert_eq!(Alignment::Right.padding(12, 10), 0);
+    }
+
+    #[test]
+    fn encode_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64("¡☆".as_bytes()), "wqHimIY=");
+    }
+}
@@ -1,4 +1,11 @@
-use crate::input::InputRequest;
+use super::{EscBehavior, Outcome, OutcomeAction, WriteOptions};
+use crate::completion::{CompletionRequest, CompletionState};
+use crate::input::{merge_responses, InputRequest};
+use crate::keymap::{KeyAction, KeyPattern, Keymap};
+use crate::line_editor::EditorRequest;
+use crate::palette::PaletteRequest;
+use crate::slider::SliderRequest;
+use crate::spinner::SpinnerRequest;
 use crate::Input;
 use crate::StateChanged;
 use std::io::{Result, Write};
@@ -13,17 +20,256 @@ pub fn to_input_request(evt: &Event) -> Option<InputRequest> {
     match *evt {
         Event::Key(Key::Backspace) | Event::Key(Key::Ctrl('h')) => Some(DeletePrevChar),
         Event::Key(Key::Delete) => Some(DeleteNextChar),
+        Event::Key(Key::Insert) => Some(ToggleOverwrite),
         Event::Key(Key::Left) | Event::Key(Key::Ctrl('b')) => Some(GoToPrevChar),
         Event::Key(Key::Right) | Event::Key(Key::Ctrl('f')) => Some(GoToNextChar),
         // Event::Key(Key::Ctrl(Key::Left)) => Some(GoToPrevWord),
         // Event::Key(Key::Ctrl(Key::Right)) => Some(GoToNextWord),
+        Event::Key(Key::AltLeft) | Event::Key(Key::Alt('b')) => Some(GoToPrevWord),
+        Event::Key(Key::AltRight) | Event::Key(Key::Alt('f')) => Some(GoToNextWord),
+        Event::Key(Key::Up) => Some(GoToPrevLine),
+        Event::Key(Key::Down) => Some(GoToNextLine),
+        Event::Key(Key::Ctrl('z')) => Some(Undo),
         Event::Key(Key::Ctrl('u')) => Some(DeleteLine),
-        Event::Key(Key::Ctrl('w')) => Some(DeletePrevWord),
+        Event::Key(Key::Ctrl('r')) => Some(ToggleMask),
+        Event::Key(Key::Ctrl('y')) => Some(PasteOverSelection),
+        Event::Key(Key::Ctrl('w')) | Event::Key(Key::Alt('\x7f')) => {
+            Some(DeletePrevWord)
+        }
         // Event::Key(Key::Ctrl(Key::Delete)) => Some(DeleteNextWord),
         Event::Key(Key::Ctrl('a')) | Event::Key(Key::Home) => Some(GoToStart),
         Event::Key(Key::Ctrl('e')) | Event::Key(Key::End) => Some(GoToEnd),
+        Event::Key(Key::Alt('\r')) => Some(InsertNewline),
         Event::Key(Key::Char('\t')) => None,
         Event::Key(Key::Char(c)) => Some(InsertChar(c)),
+        Event::Unsupported(ref seq) => {
+            keypad_key(seq).and_then(|key| to_input_request(&Event::Key(key)))
+        }
+        _ => None,
+    }
+}
+
+/// Converts the keys sent in application keypad mode, which termion doesn't
+/// parse, into the keys they stand for.
+fn keypad_key(seq: &[u8]) -> Option<Key> {
+    let key = match *seq {
+        [0x1b, b'O', b'A'] => Key::Up,
+        [0x1b, b'O', b'B'] => Key::Down,
+        [0x1b, b'O', b'C'] => Key::Right,
+        [0x1b, b'O', b'D'] => Key::Left,
+        [0x1b, b'O', b'H'] => Key::Home,
+        [0x1b, b'O', b'F'] => Key::End,
+        [0x1b, b'O', b'M'] => Key::Char('\n'),
+        [0x1b, b'O', c @ b'p'..=b'y'] => Key::Char((c - b'p' + b'0') as char),
+        [0x1b, b'O', b'j'] => Key::Char('*'),
+        [0x1b, b'O', b'k'] => Key::Char('+'),
+        [0x1b, b'O', b'l'] => Key::Char(','),
+        [0x1b, b'O', b'm'] => Key::Char('-'),
+        [0x1b, b'O', b'n'] => Key::Char('.'),
+        [0x1b, b'O', b'o'] => Key::Char('/'),
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Converts termion event into a key action, looking it up in the keymap
+/// before falling back to the default mappings. Useful for binding function
+/// keys or other special keys.
+pub fn to_key_action<C: Clone>(
+    evt: &Event,
+    keymap: &Keymap<Key, C>,
+) -> Option<KeyAction<C>> {
+    match evt {
+        Event::Key(key) => keymap
+            .lookup(key)
+            .cloned()
+            .or_else(|| to_input_request(evt).map(KeyAction::Request)),
+        _ => to_input_request(evt).map(KeyAction::Request),
+    }
+}
+
+/// Termion reports Ctrl with the char as typed, but terminals send the same
+/// byte for Ctrl+z and Ctrl+Shift+z, so the case of the char doesn't matter.
+impl KeyPattern for Key {
+    fn normalized(&self) -> Self {
+        match *self {
+            Key::Ctrl(c) => Key::Ctrl(c.to_ascii_lowercase()),
+            key => key,
+        }
+    }
+}
+
+/// The default mappings of [`to_input_request`] as a keymap, except for the
+/// chars inserted as is. Useful for listing them in a help popup, see
+/// [`Keymap::help`] and [`key_name`].
+pub fn default_keymap<C>() -> Keymap<Key, C> {
+    use InputRequest::*;
+    use KeyAction::Request;
+    Keymap::default()
+        .bind(Key::Backspace, Request(DeletePrevChar))
+        .bind(Key::Ctrl('h'), Request(DeletePrevChar))
+        .bind(Key::Delete, Request(DeleteNextChar))
+        .bind(Key::Insert, Request(ToggleOverwrite))
+        .bind(Key::Left, Request(GoToPrevChar))
+        .bind(Key::Ctrl('b'), Request(GoToPrevChar))
+        .bind(Key::Right, Request(GoToNextChar))
+        .bind(Key::Ctrl('f'), Request(GoToNextChar))
+        .bind(Key::AltLeft, Request(GoToPrevWord))
+        .bind(Key::Alt('b'), Request(GoToPrevWord))
+        .bind(Key::AltRight, Request(GoToNextWord))
+        .bind(Key::Alt('f'), Request(GoToNextWord))
+        .bind(Key::Up, Request(GoToPrevLine))
+        .bind(Key::Down, Request(GoToNextLine))
+        .bind(Key::Ctrl('z'), Request(Undo))
+        .bind(Key::Ctrl('u'), Request(DeleteLine))
+        .bind(Key::Ctrl('r'), Request(ToggleMask))
+        .bind(Key::Ctrl('y'), Request(PasteOverSelection))
+        .bind(Key::Ctrl('w'), Request(DeletePrevWord))
+        .bind(Key::Alt('\x7f'), Request(DeletePrevWord))
+        .bind(Key::Ctrl('a'), Request(GoToStart))
+        .bind(Key::Home, Request(GoToStart))
+        .bind(Key::Ctrl('e'), Request(GoToEnd))
+        .bind(Key::End, Request(GoToEnd))
+        .bind(Key::Alt('\r'), Request(InsertNewline))
+}
+
+/// Get a human readable name of the key, e.g. `"Ctrl+W"` or `"Alt+Left"`.
+///
+/// ```
+/// use termion::event::Key;
+/// use tui_input::backend::termion::key_name;
+///
+/// assert_eq!(key_name(&Key::Ctrl('w')), "Ctrl+W");
+/// assert_eq!(key_name(&Key::AltLeft), "Alt+Left");
+/// ```
+pub fn key_name(key: &Key) -> String {
+    fn char_name(c: char) -> String {
+        match c {
+            ' ' => "Space".into(),
+            '\n' | '\r' => "Enter".into(),
+            '\t' => "Tab".into(),
+            '\x7f' => "Backspace".into(),
+            c => c.to_uppercase().collect(),
+        }
+    }
+    match *key {
+        Key::Char(c) if c.is_whitespace() => char_name(c),
+        Key::Char(c) => c.into(),
+        Key::Ctrl(c) => format!("Ctrl+{}", char_name(c)),
+        Key::Alt(c) => format!("Alt+{}", char_name(c)),
+        Key::F(n) => format!("F{}", n),
+        Key::AltLeft => "Alt+Left".into(),
+        Key::AltRight => "Alt+Right".into(),
+        Key::AltUp => "Alt+Up".into(),
+        Key::AltDown => "Alt+Down".into(),
+        Key::CtrlLeft => "Ctrl+Left".into(),
+        Key::CtrlRight => "Ctrl+Right".into(),
+        Key::CtrlUp => "Ctrl+Up".into(),
+        Key::CtrlDown => "Ctrl+Down".into(),
+        Key::CtrlHome => "Ctrl+Home".into(),
+        Key::CtrlEnd => "Ctrl+End".into(),
+        Key::ShiftLeft => "Shift+Left".into(),
+        Key::ShiftRight => "Shift+Right".into(),
+        Key::ShiftUp => "Shift+Up".into(),
+        Key::ShiftDown => "Shift+Down".into(),
+        Key::PageUp => "Page Up".into(),
+        Key::PageDown => "Page Down".into(),
+        Key::BackTab => "Shift+Tab".into(),
+        ref key => format!("{:?}", key),
+    }
+}
+
+/// Key bindings of GUI text fields, to be used with [`to_key_action`]:
+/// Ctrl+A selects all, Ctrl+C/X/V copy, cut and paste, and Ctrl+Z undoes.
+pub fn gui_keymap<C>() -> Keymap<Key, C> {
+    use InputRequest::*;
+    use KeyAction::Request;
+    Keymap::default()
+        .bind(Key::Ctrl('a'), Request(SelectAll))
+        .bind(Key::Ctrl('c'), Request(CopySelection))
+        .bind(Key::Ctrl('x'), Request(CutSelection))
+        .bind(Key::Ctrl('v'), Request(PasteOverSelection))
+        .bind(Key::Ctrl('z'), Request(Undo))
+}
+
+/// Key bindings of a [`LineEditor`](crate::line_editor::LineEditor), to be used
+/// with [`to_key_action`]: Enter accepts the line, Esc and Ctrl+C discard it,
+/// Up/Down and Ctrl+P/N recall the history, and Tab completes.
+pub fn line_editor_keymap() -> Keymap<Key, EditorRequest> {
+    use EditorRequest::*;
+    use KeyAction::Custom;
+    Keymap::default()
+        .bind(Key::Char('\n'), Custom(Accept))
+        .bind(Key::Esc, Custom(Cancel))
+        .bind(Key::Ctrl('c'), Custom(Cancel))
+        .bind(Key::Up, Custom(PrevHistory))
+        .bind(Key::Ctrl('p'), Custom(PrevHistory))
+        .bind(Key::Down, Custom(NextHistory))
+        .bind(Key::Ctrl('n'), Custom(NextHistory))
+        .bind(Key::Char('\t'), Custom(Complete))
+}
+
+/// Keys finishing the input, to be used with
+/// [`EventHandler::handle_event_outcome_with_keymap`]: Enter submits and Esc
+/// cancels.
+pub fn outcome_keymap() -> Keymap<Key, OutcomeAction> {
+    outcome_keymap_with_esc(EscBehavior::Cancel)
+}
+
+/// Like [`outcome_keymap`], with Esc doing the given action instead.
+pub fn outcome_keymap_with_esc(esc: EscBehavior) -> Keymap<Key, OutcomeAction> {
+    let keymap = Keymap::default()
+        .bind(Key::Char('\n'), KeyAction::Custom(OutcomeAction::Submit));
+    match esc.action() {
+        Some(action) => keymap.bind(Key::Esc, action),
+        None => keymap,
+    }
+}
+
+/// Converts termion event into completion menu requests.
+pub fn to_completion_request(evt: &Event) -> Option<CompletionRequest> {
+    use CompletionRequest::*;
+    match *evt {
+        Event::Key(Key::Up) => Some(SelectPrev),
+        Event::Key(Key::Down) => Some(SelectNext),
+        Event::Key(Key::Char('\t')) => Some(Accept),
+        Event::Key(Key::Esc) => Some(Dismiss),
+        _ => None,
+    }
+}
+
+/// Converts termion event into command palette requests.
+/// Up and Down select a command, Enter accepts it, other keys edit the query.
+pub fn to_palette_request(evt: &Event) -> Option<PaletteRequest> {
+    match *evt {
+        Event::Key(Key::Up) => Some(PaletteRequest::SelectPrev),
+        Event::Key(Key::Down) => Some(PaletteRequest::SelectNext),
+        Event::Key(Key::Char('\n')) => Some(PaletteRequest::Accept),
+        _ => to_input_request(evt).map(PaletteRequest::Input),
+    }
+}
+
+/// Converts termion event into spinner requests.
+/// Up and Down step the value, other keys edit it.
+pub fn to_spinner_request(evt: &Event) -> Option<SpinnerRequest> {
+    match *evt {
+        Event::Key(Key::Up) => Some(SpinnerRequest::Increment),
+        Event::Key(Key::Down) => Some(SpinnerRequest::Decrement),
+        _ => to_input_request(evt).map(SpinnerRequest::Input),
+    }
+}
+
+/// Converts termion event into slider requests.
+/// Shift accelerates the step.
+pub fn to_slider_request(evt: &Event) -> Option<SliderRequest> {
+    use SliderRequest::*;
+    match *evt {
+        Event::Key(Key::Left) | Event::Key(Key::Down) => Some(Decrement),
+        Event::Key(Key::Right) | Event::Key(Key::Up) => Some(Increment),
+        Event::Key(Key::ShiftLeft) | Event::Key(Key::ShiftDown) => Some(DecrementFast),
+        Event::Key(Key::ShiftRight) | Event::Key(Key::ShiftUp) => Some(IncrementFast),
+        Event::Key(Key::Home) => Some(GoToMin),
+        Event::Key(Key::End) => Some(GoToMax),
         _ => None,
     }
 }
@@ -36,31 +282,48 @@ pub fn write<W: Write>(
     (x, y): (u16, u16),
     width: u16,
 ) -> Result<()> {
-    write!(stdout, "{}{}", Goto(x + 1, y + 1), NoInvert)?;
+    write_with_options(stdout, value, cursor, (x, y), width, &Default::default())
+}
 
-    let val_width = width.max(1) as usize - 1;
+/// Renders the input UI at the given position with the given width and options.
+pub fn write_with_options<W: Write>(
+    stdout: &mut W,
+    value: &str,
+    cursor: usize,
+    (x, y): (u16, u16),
+    width: u16,
+    options: &WriteOptions,
+) -> Result<()> {
+    write!(
+        stdout,
+        "{}{}{}",
+        Goto(x + 1, y + 1),
+        NoInvert,
+        options.prefix
+    )?;
+
+    let width = options.value_width(width);
     let len = value.chars().count();
-    let start = (len.max(val_width) - val_width).min(cursor);
-    let mut chars = value.chars().skip(start);
-    let mut i = start;
+    let padding = options.alignment.padding(len, width as usize);
+    write!(stdout, "{:padding$}", "")?;
+
+    let (_, visible, cursor) =
+        super::visible_window(value, cursor, width as usize - padding);
+    let mut chars = visible.chars();
 
-    // Chars before cursor
-    while i < cursor {
-        i += 1;
+    for i in 0..(width as usize - padding).max(1) {
         let c = chars.next().unwrap_or(' ');
-        write!(stdout, "{}", c)?;
+        if i == cursor && !options.hide_cursor {
+            write!(stdout, "{}{}{}", Invert, c, NoInvert)?;
+        } else {
+            write!(stdout, "{}", c)?;
+        }
     }
 
-    // Cursor
-    i += 1;
-    let c = chars.next().unwrap_or(' ');
-    write!(stdout, "{}{}{}", Invert, c, NoInvert,)?;
+    write!(stdout, "{}", options.suffix)?;
 
-    // Chars after the cursor
-    while i <= start + val_width {
-        i += 1;
-        let c = chars.next().unwrap_or(' ');
-        write!(stdout, "{}", c)?;
+    if let Some(text) = &options.clipboard {
+        write!(stdout, "{}", super::osc52(text))?;
     }
 
     Ok(())
@@ -70,6 +333,33 @@ pub fn write<W: Write>(
 pub trait EventHandler {
     /// Handle termion event.
     fn handle_event(&mut self, evt: &Event) -> Option<StateChanged>;
+
+    /// Handle the termion events received since the last frame, coalescing the
+    /// bursts of identical requests, see [`coalesce`](super::coalesce).
+    fn handle_events(&mut self, evts: &[Event]) -> Option<StateChanged>;
+
+    /// Handle termion event, routing the completion menu keys to the given
+    /// state while the menu is open.
+    ///
+    /// Returns `true` if the menu state changed, along with the response of
+    /// the input.
+    fn handle_event_with_completions(
+        &mut self,
+        evt: &Event,
+        completions: &mut CompletionState,
+    ) -> (bool, Option<StateChanged>);
+
+    /// Handle termion event, submitting the value on Enter and cancelling on
+    /// Esc.
+    fn handle_event_outcome(&mut self, evt: &Event) -> Outcome;
+
+    /// Handle termion event, submitting or cancelling the value with the keys
+    /// bound in the given keymap, see [`outcome_keymap`].
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        evt: &Event,
+        keymap: &Keymap<Key, OutcomeAction>,
+    ) -> Outcome;
 }
 
 impl EventHandler for Input {
@@ -77,11 +367,108 @@ impl EventHandler for Input {
     fn handle_event(&mut self, evt: &Event) -> Option<StateChanged> {
         to_input_request(evt).and_then(|req| self.handle(req))
     }
+
+    fn handle_events(&mut self, evts: &[Event]) -> Option<StateChanged> {
+        super::coalesce(evts.iter().filter_map(to_input_request))
+            .into_iter()
+            .fold(None, |resp, (req, count)| {
+                merge_responses(resp, self.handle_repeated(req, count))
+            })
+    }
+
+    fn handle_event_with_completions(
+        &mut self,
+        evt: &Event,
+        completions: &mut CompletionState,
+    ) -> (bool, Option<StateChanged>) {
+        match to_completion_request(evt).filter(|_| completions.is_open()) {
+            Some(req) => completions.handle(req, self),
+            None => (false, self.handle_event(evt)),
+        }
+    }
+
+    fn handle_event_outcome(&mut self, evt: &Event) -> Outcome {
+        self.handle_event_outcome_with_keymap(evt, &outcome_keymap())
+    }
+
+    fn handle_event_outcome_with_keymap(
+        &mut self,
+        evt: &Event,
+        keymap: &Keymap<Key, OutcomeAction>,
+    ) -> Outcome {
+        Outcome::of_key_action(self, to_key_action(evt, keymap))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backend::Alignment;
+
+    #[test]
+    fn default_keymap_matches_requests() {
+        let keymap = default_keymap::<()>();
+        for (key, action, _) in keymap.help() {
+            let req = to_input_request(&Event::Key(*key));
+            assert_eq!(req.map(KeyAction::Request).as_ref(), Some(action));
+        }
+        assert_eq!(key_name(&Key::Alt('\r')), "Alt+Enter");
+        assert_eq!(key_name(&Key::Backspace), "Backspace");
+    }
+
+    #[test]
+    fn handle_event_outcome() {
+        let mut input: Input = "a".into();
+        let key = |k| Event::Key(k);
+        assert_eq!(
+            input.handle_event_outcome(&key(Key::Esc)),
+            Outcome::Cancelled
+        );
+        assert_eq!(
+            input.handle_event_outcome(&key(Key::F(1))),
+            Outcome::Ignored
+        );
+        assert!(matches!(
+            input.handle_event_outcome(&key(Key::Char('b'))),
+            Outcome::Changed(_)
+        ));
+        assert_eq!(
+            input.handle_event_outcome(&key(Key::Char('\n'))),
+            Outcome::Submitted("ab".into())
+        );
+    }
+
+    #[test]
+    fn esc_behavior() {
+        let esc = Event::Key(Key::Esc);
+        let mut input: Input = "a".into();
+        input.handle_event(&Event::Key(Key::Char('b')));
+
+        let keymap = outcome_keymap_with_esc(EscBehavior::PassThrough);
+        assert_eq!(
+            input.handle_event_outcome_with_keymap(&esc, &keymap),
+            Outcome::Ignored
+        );
+
+        let keymap = outcome_keymap_with_esc(EscBehavior::Clear);
+        assert!(matches!(
+            input.handle_event_outcome_with_keymap(&esc, &keymap),
+            Outcome::Changed(_)
+        ));
+        assert_eq!(input.value(), "");
+        assert_eq!(
+            input.handle_event_outcome_with_keymap(&esc, &keymap),
+            Outcome::Ignored
+        );
+
+        let keymap = outcome_keymap_with_esc(EscBehavior::Revert);
+        input.handle_event_outcome_with_keymap(&esc, &keymap);
+        assert_eq!(input.value(), "a");
+        assert_eq!(
+            input.handle_event_outcome_with_keymap(&esc, &keymap),
+            Outcome::Ignored
+        );
+    }
 
     #[test]
     fn handle_tab() {
@@ -91,4 +478,79 @@ mod tests {
 
         assert!(req.is_none());
     }
+
+    #[test]
+    fn handle_option_keys() {
+        let req = to_input_request(&Event::Key(Key::AltLeft));
+        assert_eq!(req, Some(InputRequest::GoToPrevWord));
+
+        let req = to_input_request(&Event::Key(Key::Alt('f')));
+        assert_eq!(req, Some(InputRequest::GoToNextWord));
+    }
+
+    #[test]
+    fn handle_keypad() {
+        let req = to_input_request(&Event::Unsupported(vec![0x1b, b'O', b'r']));
+        assert_eq!(req, Some(InputRequest::InsertChar('2')));
+
+        let req = to_input_request(&Event::Unsupported(vec![0x1b, b'O', b'D']));
+        assert_eq!(req, Some(InputRequest::GoToPrevChar));
+
+        let req = to_input_request(&Event::Unsupported(vec![0x1b, b'[', 0]));
+        assert_eq!(req, None);
+    }
+
+    #[test]
+    fn handle_keymap() {
+        let keymap = Keymap::default().bind(Key::F(2), KeyAction::Custom("clear"));
+
+        let action = to_key_action(&Event::Key(Key::F(2)), &keymap);
+        assert_eq!(action, Some(KeyAction::Custom("clear")));
+
+        let action = to_key_action(&Event::Key(Key::Home), &keymap);
+        assert_eq!(action, Some(KeyAction::Request(InputRequest::GoToStart)));
+    }
+
+    #[test]
+    fn key_action_of_equivalent_keys() {
+        let keymap = Keymap::default().bind(Key::Ctrl('e'), KeyAction::Custom("edit"));
+        let action = to_key_action(&Event::Key(Key::Ctrl('E')), &keymap);
+        assert_eq!(action, Some(KeyAction::Custom("edit")));
+    }
+
+    #[test]
+    fn write_right_aligned() {
+        let mut out = Vec::new();
+        let options = WriteOptions::default().alignment(Alignment::Right);
+        write_with_options(&mut out, "12", 2, (0, 0), 6, &options).unwrap();
+
+        let expected = format!("{}{}   12{} {}", Goto(1, 1), NoInvert, Invert, NoInvert);
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn write_with_affixes() {
+        let mut out = Vec::new();
+        let options = WriteOptions::default()
+            .prefix("$ ".into())
+            .suffix(" MB".into());
+        write_with_options(&mut out, "1234", 4, (0, 0), 9, &options).unwrap();
+
+        let expected =
+            format!("{}{}$ 234{} {} MB", Goto(1, 1), NoInvert, Invert, NoInvert);
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn write_with_clipboard() {
+        let mut out = Vec::new();
+        let options = WriteOptions::default().clipboard("hi".into());
+        write_with_options(&mut out, "hi", 2, (0, 0), 3, &options).unwrap();
+
+        let expected = format!("{}{}hi{} {}", Goto(1, 1), NoInvert, Invert, NoInvert);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            expected + "\x1b]52;c;aGk=\x07"
+        );
+    }
 }
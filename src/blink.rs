@@ -0,0 +1,80 @@
+//! Blinking of a cursor drawn by the application, e.g. with the cursor style
+//! of the widgets instead of the terminal cursor.
+
+use std::time::Duration;
+
+/// A tick-driven cursor blink state.
+///
+/// Call [`CursorBlink::tick`] with the time elapsed since the last frame, and
+/// [`CursorBlink::reset`] on input so that the cursor stays visible while
+/// typing.
+///
+/// Example:
+///
+/// ```
+/// use std::time::Duration;
+/// use tui_input::blink::CursorBlink;
+///
+/// let mut blink = CursorBlink::new(Duration::from_millis(500));
+///
+/// assert!(blink.tick(Duration::from_millis(300)));
+/// assert!(!blink.tick(Duration::from_millis(300)));
+///
+/// blink.reset();
+/// assert!(blink.is_visible());
+/// ```
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorBlink {
+    interval: Duration,
+    elapsed: Duration,
+    visible: bool,
+}
+
+impl Default for CursorBlink {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(530))
+    }
+}
+
+impl CursorBlink {
+    /// Initialize a visible cursor, toggled after each given interval.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            elapsed: Duration::ZERO,
+            visible: true,
+        }
+    }
+
+    /// Advance the time by the given duration, returning whether the cursor
+    /// is visible.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        if self.interval.is_zero() {
+            return self.visible;
+        }
+        self.elapsed += delta;
+        while self.elapsed >= self.interval {
+            self.elapsed -= self.interval;
+            self.visible = !self.visible;
+        }
+        self.visible
+    }
+
+    /// Show the cursor and restart the interval, e.g. after a key press.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.visible = true;
+    }
+
+    /// Check if the cursor is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Get the time left until the cursor is toggled, e.g. to use as the
+    /// event poll timeout.
+    pub fn until_toggle(&self) -> Duration {
+        self.interval.saturating_sub(self.elapsed)
+    }
+}
@@ -0,0 +1,510 @@
+//! Abstract storage for the value, so ropes, gap buffers or app owned storage
+//! can mirror the edits made by [`Input`](crate::Input). Through
+//! [`Input::handle_with_buffer`](crate::Input::handle_with_buffer) they get
+//! the exact insertions and removals, so a keystroke costs the size of the edit
+//! rather than the size of the value.
+//!
+//! Besides `String`, it's implemented for `Cow<str>` to borrow read-mostly
+//! values, for `SmolStr` (with the `smol_str` feature), and for
+//! [`SmallString`], which the input uses by default to keep short values on the
+//! stack. Any of them can also be the [`Storage`] of the input itself.
+
+use std::borrow::{Borrow, Cow};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Range};
+
+/// A change of the value: `removed` chars at the char index `start` were
+/// replaced by `inserted`.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edit {
+    pub start: usize,
+    pub removed: usize,
+    pub inserted: String,
+}
+
+impl Edit {
+    /// Get the minimal edit turning `old` into `new`, if they differ.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::buffer::Edit;
+    ///
+    /// let edit = Edit::between("Hello World", "Hello, world").unwrap();
+    ///
+    /// assert_eq!(edit.start, 5);
+    /// assert_eq!(edit.removed, 2);
+    /// assert_eq!(edit.inserted, ", w");
+    /// assert_eq!(Edit::between("same", "same"), None);
+    /// ```
+    pub fn between(old: &str, new: &str) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        let prefix = old
+            .chars()
+            .zip(new.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let old_len = old.chars().count();
+        let new_len = new.chars().count();
+        let suffix = old
+            .chars()
+            .rev()
+            .zip(new.chars().rev())
+            .take(old_len.min(new_len) - prefix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        Some(Self {
+            start: prefix,
+            removed: old_len - prefix - suffix,
+            inserted: new
+                .chars()
+                .skip(prefix)
+                .take(new_len - prefix - suffix)
+                .collect(),
+        })
+    }
+
+    /// The char range replaced in the old value.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.removed
+    }
+}
+
+/// Storage the edits of an [`Input`](crate::Input) can be applied to.
+/// All indices are char indices.
+///
+/// See [`Input::handle_with_buffer`](crate::Input::handle_with_buffer).
+pub trait InputBuffer {
+    /// Number of chars in the buffer.
+    fn char_count(&self) -> usize;
+
+    /// Insert the text at the given char index.
+    fn insert_at(&mut self, index: usize, text: &str);
+
+    /// Remove the chars in the given range.
+    fn remove_range(&mut self, range: Range<usize>);
+
+    /// Get the text in the given char range.
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str>;
+
+    /// Apply the edit.
+    fn apply(&mut self, edit: &Edit) {
+        if edit.removed > 0 {
+            self.remove_range(edit.range());
+        }
+        if !edit.inserted.is_empty() {
+            self.insert_at(edit.start, &edit.inserted);
+        }
+    }
+}
+
+/// Storage the [`Input`](crate::Input) can keep its value in, e.g. `String`,
+/// [`SmallString`] (the default), `Cow<str>` or `SmolStr`.
+///
+/// See [`Input::from_storage`](crate::Input::from_storage).
+pub trait Storage:
+    InputBuffer
+    + Deref<Target = str>
+    + From<String>
+    + Into<String>
+    + Clone
+    + Default
+    + fmt::Debug
+{
+}
+
+impl<T> Storage for T where
+    T: InputBuffer
+        + Deref<Target = str>
+        + From<String>
+        + Into<String>
+        + Clone
+        + Default
+        + fmt::Debug
+{
+}
+
+impl InputBuffer for String {
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn insert_at(&mut self, index: usize, text: &str) {
+        let index = byte_index(self, index);
+        self.insert_str(index, text);
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        self.replace_range(range, "");
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        Cow::Borrowed(&self[range])
+    }
+}
+
+/// Borrowed until the first edit, for read-mostly values.
+impl InputBuffer for Cow<'_, str> {
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn insert_at(&mut self, index: usize, text: &str) {
+        if !text.is_empty() {
+            self.to_mut().insert_at(index, text);
+        }
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) {
+        if !range.is_empty() {
+            self.to_mut().remove_range(range);
+        }
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        Cow::Borrowed(&self[range])
+    }
+}
+
+/// Stored inline without allocating while the value is short.
+#[cfg(feature = "smol_str")]
+impl InputBuffer for smol_str::SmolStr {
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn insert_at(&mut self, index: usize, text: &str) {
+        let index = byte_index(self, index);
+        let mut builder = smol_str::SmolStrBuilder::new();
+        builder.push_str(&self[..index]);
+        builder.push_str(text);
+        builder.push_str(&self[index..]);
+        *self = builder.finish();
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        let mut builder = smol_str::SmolStrBuilder::new();
+        builder.push_str(&self[..range.start]);
+        builder.push_str(&self[range.end..]);
+        *self = builder.finish();
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        Cow::Borrowed(&self[range])
+    }
+}
+
+/// Max length in bytes of a [`SmallString`] stored inline.
+pub const INLINE_CAPACITY: usize = 23;
+
+/// A string stored inline, without allocating, while it's at most
+/// [`INLINE_CAPACITY`] bytes long. The value of an [`Input`](crate::Input) is
+/// stored this way, so that short prompts never allocate.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::buffer::SmallString;
+///
+/// let mut text = SmallString::from("Hello");
+/// text.push_str(" World");
+/// assert!(text.is_inline());
+///
+/// text.push_str(", and everyone else");
+/// assert!(!text.is_inline());
+/// assert_eq!(text, "Hello World, and everyone else");
+/// ```
+#[derive(Clone)]
+pub struct SmallString(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAPACITY] },
+    Heap(String),
+}
+
+impl SmallString {
+    /// Initialize an empty string.
+    pub const fn new() -> Self {
+        Self(Repr::Inline {
+            len: 0,
+            buf: [0; INLINE_CAPACITY],
+        })
+    }
+
+    /// Get the string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            // Safe, because only whole UTF-8 strings are copied at char
+            // boundaries into the buffer.
+            Repr::Inline { len, buf } => unsafe {
+                std::str::from_utf8_unchecked(&buf[..*len as usize])
+            },
+            Repr::Heap(text) => text,
+        }
+    }
+
+    /// Check if the string is stored inline, i.e. without allocating.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline { .. })
+    }
+
+    /// Append the char.
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Append the text.
+    pub fn push_str(&mut self, text: &str) {
+        let len = self.len();
+        self.replace_bytes(len..len, text);
+    }
+
+    /// Replace the given byte range with the text, moving the string to the
+    /// heap when it no longer fits inline.
+    ///
+    /// Panics if the range is out of bounds or not on char boundaries.
+    pub fn replace_bytes(&mut self, range: Range<usize>, text: &str) {
+        assert!(
+            range.start <= range.end
+                && self.is_char_boundary(range.start)
+                && self.is_char_boundary(range.end),
+            "invalid range {range:?}"
+        );
+        let new_len = self.len() - range.len() + text.len();
+        match &mut self.0 {
+            Repr::Heap(heap) => heap.replace_range(range, text),
+            Repr::Inline { len, buf } if new_len <= INLINE_CAPACITY => {
+                let end = range.start + text.len();
+                buf.copy_within(range.end..*len as usize, end);
+                buf[range.start..end].copy_from_slice(text.as_bytes());
+                *len = new_len as u8;
+            }
+            Repr::Inline { .. } => {
+                let mut heap = String::with_capacity(new_len);
+                heap.push_str(&self[..range.start]);
+                heap.push_str(text);
+                heap.push_str(&self[range.end..]);
+                self.0 = Repr::Heap(heap);
+            }
+        }
+    }
+}
+
+impl Default for SmallString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for SmallString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallString {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Borrow<str> for SmallString {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl From<&str> for SmallString {
+    fn from(text: &str) -> Self {
+        let mut small = Self::new();
+        small.push_str(text);
+        small
+    }
+}
+
+impl From<String> for SmallString {
+    fn from(text: String) -> Self {
+        if text.len() <= INLINE_CAPACITY {
+            text.as_str().into()
+        } else {
+            Self(Repr::Heap(text))
+        }
+    }
+}
+
+impl From<SmallString> for String {
+    fn from(text: SmallString) -> Self {
+        match text.0 {
+            Repr::Inline { .. } => text.as_str().into(),
+            Repr::Heap(heap) => heap,
+        }
+    }
+}
+
+impl FromIterator<char> for SmallString {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut small = Self::new();
+        for c in iter {
+            small.push(c);
+        }
+        small
+    }
+}
+
+impl PartialEq for SmallString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmallString {}
+
+impl PartialEq<str> for SmallString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for SmallString {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SmallString {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for SmallString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SmallString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SmallString {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl InputBuffer for SmallString {
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn insert_at(&mut self, index: usize, text: &str) {
+        let index = byte_index(self, index);
+        self.replace_bytes(index..index, text);
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        self.replace_bytes(range, "");
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let range = byte_index(self, range.start)..byte_index(self, range.end);
+        Cow::Borrowed(&self[range])
+    }
+}
+
+/// Get the byte index of the given char index, clamped to the text length.
+fn byte_index(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_edit() {
+        for (old, new) in [("", "ab"), ("ab", ""), ("héllo", "hallo"), ("aaa", "aa")] {
+            let edit = Edit::between(old, new).unwrap();
+            let mut buffer = String::from(old);
+            buffer.apply(&edit);
+            assert_eq!(buffer, new);
+        }
+    }
+
+    #[test]
+    fn cow_stays_borrowed() {
+        let mut buffer = Cow::Borrowed("abc");
+        buffer.apply(&Edit {
+            start: 1,
+            removed: 0,
+            inserted: "".into(),
+        });
+        assert!(matches!(buffer, Cow::Borrowed(_)));
+
+        buffer.apply(&Edit::between("abc", "aXc").unwrap());
+        assert_eq!(buffer, "aXc");
+    }
+
+    #[test]
+    fn small_string() {
+        let mut text = SmallString::from("héllo");
+        text.apply(&Edit::between("héllo", "hello!").unwrap());
+        assert_eq!(text, "hello!");
+
+        text.insert_at(0, &"a".repeat(INLINE_CAPACITY - 6));
+        assert!(text.is_inline());
+        text.insert_at(1, "b");
+        assert!(!text.is_inline());
+        assert_eq!(text.len(), INLINE_CAPACITY + 1);
+        assert!(SmallString::from(String::from(&text[..INLINE_CAPACITY])).is_inline());
+    }
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str() {
+        let mut buffer = smol_str::SmolStr::new("héllo");
+        buffer.apply(&Edit::between("héllo", "hello!").unwrap());
+        assert_eq!(buffer, "hello!");
+        assert!(!buffer.is_heap_allocated());
+    }
+}
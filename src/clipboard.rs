@@ -0,0 +1,95 @@
+//! Clipboard providers used by copy and paste requests, see
+//! [`Input::handle_with_clipboard`](crate::Input::handle_with_clipboard).
+
+use std::io::{Result, Write};
+
+/// A clipboard holding text.
+pub trait ClipboardProvider {
+    /// Get the text of the clipboard, if it can be read.
+    fn get_text(&mut self) -> Result<Option<String>>;
+
+    /// Set the text of the clipboard.
+    fn set_text(&mut self, text: &str) -> Result<()>;
+}
+
+/// A clipboard living in memory, e.g. for tests.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct MemoryClipboard(pub String);
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get_text(&mut self) -> Result<Option<String>> {
+        Ok(Some(self.0.clone()))
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        text.clone_into(&mut self.0);
+        Ok(())
+    }
+}
+
+/// The terminal clipboard, set with the OSC 52 escape sequence written to the
+/// given output. It can't be read, so pasting uses the last copied text.
+///
+/// See [`copy_osc52`](crate::backend::copy_osc52).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct Osc52Clipboard<W>(pub W);
+
+impl<W: Write> ClipboardProvider for Osc52Clipboard<W> {
+    fn get_text(&mut self) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        crate::backend::copy_osc52(&mut self.0, text)
+    }
+}
+
+/// The system clipboard, through the [arboard](https://docs.rs/arboard) crate.
+#[cfg(feature = "arboard")]
+pub struct ArboardClipboard(pub arboard::Clipboard);
+
+#[cfg(feature = "arboard")]
+impl ArboardClipboard {
+    /// Connect to the system clipboard.
+    pub fn new() -> Result<Self> {
+        arboard::Clipboard::new()
+            .map(Self)
+            .map_err(std::io::Error::other)
+    }
+}
+
+#[cfg(feature = "arboard")]
+impl ClipboardProvider for ArboardClipboard {
+    fn get_text(&mut self) -> Result<Option<String>> {
+        match self.0.get_text() {
+            Ok(text) => Ok(Some(text)),
+            Err(arboard::Error::ContentNotAvailable) => Ok(None),
+            Err(err) => Err(std::io::Error::other(err)),
+        }
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        self.0.set_text(text).map_err(std::io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Input, InputRequest};
+
+    #[test]
+    fn paste_last_copied_when_unreadable() {
+        let mut clipboard = Osc52Clipboard(Vec::new());
+        let mut input: Input = "ab".into();
+        input
+            .handle_with_clipboard(InputRequest::DeletePrevWord, &mut clipboard)
+            .unwrap();
+        assert_eq!(clipboard.0, b"\x1b]52;c;YWI=\x07");
+
+        input
+            .handle_with_clipboard(InputRequest::PasteOverSelection, &mut clipboard)
+            .unwrap();
+        assert_eq!(input.value(), "ab");
+    }
+}
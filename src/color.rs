@@ -0,0 +1,142 @@
+//! An input for hex colors, like `#ff8800` or `#f80`.
+
+use crate::{Input, InputRequest, InputResponse, Validator};
+
+/// Parse a `#RRGGBB` or `#RGB` color into its red, green and blue components.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::color::parse_hex_color;
+///
+/// assert_eq!(parse_hex_color("#ff8800"), Ok((255, 136, 0)));
+/// assert_eq!(parse_hex_color("#f80"), Ok((255, 136, 0)));
+/// assert!(parse_hex_color("ff8800").is_err());
+/// ```
+pub fn parse_hex_color(value: &str) -> Result<(u8, u8, u8), String> {
+    let err = || String::from("must be like #RRGGBB or #RGB");
+    let digits = value.strip_prefix('#').ok_or_else(err)?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(err());
+    }
+    let component = |hex: &str| u8::from_str_radix(hex, 16).map_err(|_| err());
+    match digits.len() {
+        6 => Ok((
+            component(&digits[0..2])?,
+            component(&digits[2..4])?,
+            component(&digits[4..6])?,
+        )),
+        3 => {
+            let short = |i: usize| component(&digits[i..i + 1]).map(|c| c * 17);
+            Ok((short(0)?, short(1)?, short(2)?))
+        }
+        _ => Err(err()),
+    }
+}
+
+impl Validator {
+    /// Accept `#RRGGBB` or `#RGB` colors, see [`parse_hex_color`].
+    pub fn hex_color() -> Self {
+        Self::new(|value| parse_hex_color(value).map(|_| ()))
+    }
+}
+
+/// An input for hex colors, following the same request/response pattern as
+/// [`Input`]. Only hex digits can be typed after the leading `#`, which is
+/// inserted automatically, and the value is validated with
+/// [`Validator::hex_color`] as it changes.
+///
+/// Render it with the wrapped [`ColorInput::input`], e.g. with the swatch of
+/// `InputWidget::color_swatch`.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::color::ColorInput;
+/// use tui_input::InputRequest;
+///
+/// let mut input = ColorInput::default();
+/// for c in "0f8g".chars() {
+///     input.handle(InputRequest::InsertChar(c));
+/// }
+///
+/// assert_eq!(input.input().value(), "#0f8");
+/// assert_eq!(input.rgb(), Some((0, 255, 136)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorInput {
+    input: Input,
+}
+
+impl Default for ColorInput {
+    fn default() -> Self {
+        Self::new(Input::default())
+    }
+}
+
+impl ColorInput {
+    /// Wrap the given input, e.g. to set a placeholder. Its validator and max
+    /// length are replaced.
+    pub fn new(input: Input) -> Self {
+        Self {
+            input: input.with_validator(Validator::hex_color()).with_max_len(7),
+        }
+    }
+
+    /// Get a reference to the input, e.g. to render it.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Get the parsed color, if the value is valid.
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        parse_hex_color(self.input.value()).ok()
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+        use InputRequest::*;
+        match req {
+            InsertNewline => None,
+            InsertChar('#') if self.input.cursor() == 0 => match self.input.value() {
+                value if value.starts_with('#') => None,
+                _ => self.input.handle(req),
+            },
+            InsertChar(c) if !c.is_ascii_hexdigit() => None,
+            InsertChar(_) if !self.input.value().starts_with('#') => {
+                let cursor = self.input.cursor();
+                self.input.handle(GoToStart);
+                self.input.handle(InsertChar('#'));
+                self.input.handle(SetCursor(cursor + 1));
+                self.input.handle(req)
+            }
+            InsertChar(_) if self.input.cursor() == 0 => None,
+            req => self.input.handle(req),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use InputRequest::*;
+
+    #[test]
+    fn edit() {
+        let mut input = ColorInput::default();
+        input.handle(InsertChar('#'));
+        assert_eq!(input.handle(InsertChar('#')), None);
+        for c in "1234567".chars() {
+            input.handle(InsertChar(c));
+        }
+        assert_eq!(input.input().value(), "#123456");
+        assert_eq!(input.rgb(), Some((0x12, 0x34, 0x56)));
+
+        input.handle(GoToStart);
+        assert_eq!(input.handle(InsertChar('a')), None);
+        input.handle(DeletePrevChar);
+        input.handle(DeleteNextChar);
+        assert_eq!(input.input().value(), "123456");
+        assert_eq!(input.rgb(), None);
+    }
+}
@@ -0,0 +1,260 @@
+//! Completion of the value using pluggable providers.
+
+#[cfg(feature = "path-completion")]
+mod path;
+
+#[cfg(feature = "path-completion")]
+pub use path::PathCompleter;
+
+use crate::{Input, InputResponse, StateChanged};
+use std::ops::Range;
+
+/// A completion candidate, replacing a char range of the value.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Completion {
+    /// The char range of the value to replace.
+    pub range: Range<usize>,
+    /// The text to replace the range with.
+    pub text: String,
+}
+
+/// Implement this trait to provide completions for an input.
+pub trait Completer {
+    /// Get the completion candidates for the current state of the input.
+    fn complete(&self, input: &Input) -> Vec<Completion>;
+}
+
+impl<F> Completer for F
+where
+    F: Fn(&Input) -> Vec<Completion>,
+{
+    fn complete(&self, input: &Input) -> Vec<Completion> {
+        self(input)
+    }
+}
+
+/// Requests used to navigate the completion menu.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompletionRequest {
+    SelectPrev,
+    SelectNext,
+    Accept,
+    Dismiss,
+}
+
+/// State of the completion menu: the candidates and the selected one.
+///
+/// The menu is open as long as there are candidates.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompletionState {
+    completions: Vec<Completion>,
+    selected: usize,
+}
+
+impl CompletionState {
+    /// Open the menu with the given candidates, selecting the first one.
+    pub fn set_completions(&mut self, completions: Vec<Completion>) {
+        self.completions = completions;
+        self.selected = 0;
+    }
+
+    /// Get the candidates.
+    pub fn completions(&self) -> &[Completion] {
+        &self.completions
+    }
+
+    /// Get the index of the selected candidate, if the menu is open.
+    pub fn selected(&self) -> Option<usize> {
+        self.is_open().then_some(self.selected)
+    }
+
+    /// Get the selected candidate, if the menu is open.
+    pub fn selected_completion(&self) -> Option<&Completion> {
+        self.completions.get(self.selected)
+    }
+
+    /// Check if the menu is open.
+    pub fn is_open(&self) -> bool {
+        !self.completions.is_empty()
+    }
+
+    /// Close the menu.
+    pub fn close(&mut self) {
+        self.set_completions(vec![]);
+    }
+
+    /// Handle request, applying the selected candidate to the input on
+    /// [`CompletionRequest::Accept`].
+    ///
+    /// Returns `true` if the menu state changed, along with the response of the
+    /// input.
+    pub fn handle(
+        &mut self,
+        req: CompletionRequest,
+        input: &mut Input,
+    ) -> (bool, InputResponse) {
+        use CompletionRequest::*;
+        let len = self.completions.len();
+        if len == 0 {
+            return (false, None);
+        }
+        match req {
+            SelectPrev => {
+                self.selected = (self.selected + len - 1) % len;
+                (len > 1, None)
+            }
+            SelectNext => {
+                self.selected = (self.selected + 1) % len;
+                (len > 1, None)
+            }
+            Accept => {
+                let resp = input.apply_completion(&self.completions[self.selected]);
+                self.close();
+                (true, resp)
+            }
+            Dismiss => {
+                self.close();
+                (true, None)
+            }
+        }
+    }
+}
+
+impl Input {
+    /// Get the char range of the whitespace delimited word before the cursor.
+    pub fn word_before_cursor(&self) -> Range<usize> {
+        let start = self
+            .value()
+            .chars()
+            .take(self.cursor())
+            .collect::<Vec<_>>()
+            .iter()
+            .rposition(|c| c.is_whitespace())
+            .map_or(0, |i| i + 1);
+        start..self.cursor()
+    }
+
+    /// Apply the completion, replacing its range and moving the cursor to the
+    /// end of the inserted text.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::completion::Completion;
+    /// use tui_input::Input;
+    ///
+    /// let mut input: Input = "cd sr".into();
+    /// let completion = Completion { range: 3..5, text: "src/".into() };
+    /// input.apply_completion(&completion);
+    ///
+    /// assert_eq!(input.value(), "cd src/");
+    /// assert_eq!(input.cursor(), 7);
+    /// ```
+    pub fn apply_completion(&mut self, completion: &Completion) -> InputResponse {
+        let len = self.char_len();
+        let start = completion.range.start.min(len);
+        let end = completion.range.end.clamp(start, len);
+        let value: String = self
+            .value()
+            .chars()
+            .take(start)
+            .chain(completion.text.chars())
+            .chain(self.value().chars().skip(end))
+            .collect();
+        let cursor = start + completion.text.chars().count();
+
+        if value == self.value() && cursor == self.cursor() || !self.fits_limits(&value)
+        {
+            return None;
+        }
+
+        let changed = StateChanged {
+            value: value != self.value(),
+            cursor: cursor != self.cursor(),
+        };
+        self.replace_value(value, cursor);
+        Some(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_before_cursor() {
+        let input = Input::from("git checkout ma");
+        assert_eq!(input.word_before_cursor(), 13..15);
+
+        let input = input.with_cursor(5);
+        assert_eq!(input.word_before_cursor(), 4..5);
+
+        let input = Input::from("git ");
+        assert_eq!(input.word_before_cursor(), 4..4);
+    }
+
+    #[test]
+    fn apply_completion() {
+        let mut input = Input::from("git ch --").with_cursor(6);
+        let completer = |input: &Input| {
+            vec![Completion {
+                range: input.word_before_cursor(),
+                text: "checkout".into(),
+            }]
+        };
+
+        let completions = completer.complete(&input);
+        let resp = input.apply_completion(&completions[0]);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+        assert_eq!(input.value(), "git checkout --");
+        assert_eq!(input.cursor(), 12);
+
+        let completions = completer.complete(&input);
+        assert_eq!(input.apply_completion(&completions[0]), None);
+
+        let mut input = Input::from("git ch").with_max_bytes(8);
+        let completions = completer.complete(&input);
+        assert_eq!(input.apply_completion(&completions[0]), None);
+        assert_eq!(input.value(), "git ch");
+    }
+
+    #[test]
+    fn completion_state() {
+        let mut input = Input::from("ls -");
+        let mut state = CompletionState::default();
+        assert_eq!(
+            state.handle(CompletionRequest::SelectNext, &mut input),
+            (false, None)
+        );
+
+        let completion = |text: &str| Completion {
+            range: input.word_before_cursor(),
+            text: text.into(),
+        };
+        state.set_completions(vec![completion("-a"), completion("-l")]);
+        assert_eq!(state.selected(), Some(0));
+
+        state.handle(CompletionRequest::SelectPrev, &mut input);
+        assert_eq!(state.selected(), Some(1));
+        state.handle(CompletionRequest::SelectNext, &mut input);
+        state.handle(CompletionRequest::SelectNext, &mut input);
+        assert_eq!(state.selected(), Some(1));
+
+        let (changed, resp) = state.handle(CompletionRequest::Accept, &mut input);
+        assert!(changed);
+        assert!(resp.is_some());
+        assert_eq!(input.value(), "ls -l");
+        assert!(!state.is_open());
+        assert_eq!(state.selected(), None);
+    }
+}
@@ -0,0 +1,119 @@
+use super::{Completer, Completion};
+use crate::Input;
+use std::fs;
+use std::path::PathBuf;
+
+/// Completes file system paths from the word before the cursor.
+///
+/// Relative paths are resolved from the base directory, which defaults to the
+/// current working directory. Directories are completed with a trailing `/`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct PathCompleter {
+    base_dir: Option<PathBuf>,
+    show_hidden: bool,
+}
+
+impl PathCompleter {
+    /// Initialize a new path completer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the directory used to resolve relative paths.
+    pub fn with_base_dir(mut self, base_dir: PathBuf) -> Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
+    /// Also complete hidden entries when the typed name doesn't start with `.`.
+    pub fn with_show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+}
+
+impl Completer for PathCompleter {
+    fn complete(&self, input: &Input) -> Vec<Completion> {
+        let range = input.word_before_cursor();
+        let word: String = input
+            .value()
+            .chars()
+            .skip(range.start)
+            .take(range.len())
+            .collect();
+
+        let (dir, name) = match word.rfind('/') {
+            Some(i) => word.split_at(i + 1),
+            None => ("", word.as_str()),
+        };
+
+        let mut path = self.base_dir.clone().unwrap_or_default();
+        path.push(if dir.is_empty() { "." } else { dir });
+
+        let Ok(entries) = fs::read_dir(path) else {
+            return vec![];
+        };
+
+        let mut completions: Vec<Completion> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                if !file_name.starts_with(name)
+                    || (file_name.starts_with('.')
+                        && !name.starts_with('.')
+                        && !self.show_hidden)
+                {
+                    return None;
+                }
+                let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+                let suffix = if is_dir { "/" } else { "" };
+                Some(Completion {
+                    range: range.clone(),
+                    text: format!("{dir}{file_name}{suffix}"),
+                })
+            })
+            .collect();
+
+        completions.sort_by(|a, b| a.text.cmp(&b.text));
+        completions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_paths() {
+        let base_dir = std::env::temp_dir()
+            .join(format!("tui-input-path-completer-{}", std::process::id()));
+        fs::create_dir_all(base_dir.join("src/backend")).unwrap();
+        fs::write(base_dir.join("src/lib.rs"), "").unwrap();
+        fs::write(base_dir.join("src/.hidden"), "").unwrap();
+
+        let completer = PathCompleter::new().with_base_dir(base_dir.clone());
+
+        let input = Input::from("cat src/");
+        let texts: Vec<String> = completer
+            .complete(&input)
+            .into_iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(texts, vec!["src/backend/", "src/lib.rs"]);
+
+        let input = Input::from("cat sr");
+        let completions = completer.complete(&input);
+        assert_eq!(
+            completions,
+            vec![Completion {
+                range: 4..6,
+                text: "src/".into()
+            }]
+        );
+
+        let input = Input::from("cat src/.");
+        assert_eq!(completer.complete(&input).len(), 1);
+
+        fs::remove_dir_all(base_dir).unwrap();
+    }
+}
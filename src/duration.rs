@@ -0,0 +1,200 @@
+//! An input for durations, like `1h30m`, `90s` or `02:15:00`.
+
+use crate::{Input, InputRequest, InputResponse, Validator};
+use std::time::Duration;
+
+/// Parse a duration written with units (`1h30m`, `90s`, `1.5d`, `250ms`) or
+/// as a clock (`02:15:00`, `15:00`).
+///
+/// Example:
+///
+/// ```
+/// use std::time::Duration;
+/// use tui_input::duration::parse_duration;
+///
+/// assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration("02:15:00"), Ok(Duration::from_secs(8100)));
+/// assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+/// assert!(parse_duration("90").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("required".into());
+    }
+    if value.contains(':') {
+        parse_clock(value)
+    } else {
+        parse_units(value)
+    }
+}
+
+fn parse_clock(value: &str) -> Result<Duration, String> {
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.len() > 3 || parts.iter().any(|p| p.is_empty()) {
+        return Err("must be like 02:15:00".into());
+    }
+    let mut secs = 0.0;
+    for (i, part) in parts.iter().enumerate() {
+        let last = i == parts.len() - 1;
+        let n = match last {
+            true => parse_number(part),
+            false => part.parse::<u64>().ok().map(|n| n as f64),
+        }
+        .ok_or_else(|| format!("invalid number: {part}"))?;
+        if i > 0 && n >= 60.0 {
+            return Err(format!("must be below 60: {part}"));
+        }
+        secs = secs * 60.0 + n;
+    }
+    to_duration(secs)
+}
+
+fn parse_units(value: &str) -> Result<Duration, String> {
+    let mut total = Duration::ZERO;
+    let mut seen = Vec::new();
+    let mut rest = value;
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or("missing unit")?;
+        let (number, tail) = rest.split_at(split);
+        let n =
+            parse_number(number).ok_or_else(|| format!("invalid number: {number}"))?;
+        let unit_len = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        let secs = match unit {
+            "d" => 86400.0,
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            "" => return Err("missing unit".into()),
+            unit => return Err(format!("unknown unit: {unit}")),
+        };
+        if seen.contains(&unit) {
+            return Err(format!("repeated unit: {unit}"));
+        }
+        seen.push(unit);
+        total = total.checked_add(to_duration(n * secs)?).ok_or(TOO_LONG)?;
+        rest = tail.trim_start();
+    }
+    Ok(total)
+}
+
+const TOO_LONG: &str = "too long";
+
+fn to_duration(secs: f64) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(secs).map_err(|_| TOO_LONG.into())
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    let valid = !value.is_empty()
+        && value.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && value.chars().any(|c| c.is_ascii_digit());
+    valid.then(|| value.parse().ok()).flatten()
+}
+
+impl Validator {
+    /// Accept durations, see [`parse_duration`].
+    pub fn duration() -> Self {
+        Self::new(|value| parse_duration(value).map(|_| ()))
+    }
+}
+
+/// An input for durations, following the same request/response pattern as
+/// [`Input`]. Only the chars of the supported forms can be typed, and the
+/// value is validated with [`Validator::duration`] as it changes.
+///
+/// Render it with the wrapped [`DurationInput::input`].
+///
+/// Example:
+///
+/// ```
+/// use std::time::Duration;
+/// use tui_input::duration::DurationInput;
+/// use tui_input::InputRequest;
+///
+/// let mut input = DurationInput::default();
+/// for c in "1h30!".chars() {
+///     input.handle(InputRequest::InsertChar(c));
+/// }
+/// assert_eq!(input.input().value(), "1h30");
+/// assert_eq!(input.error(), Some("missing unit".into()));
+///
+/// input.handle(InputRequest::InsertChar('m'));
+/// assert_eq!(input.duration(), Some(Duration::from_secs(5400)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DurationInput {
+    input: Input,
+}
+
+impl Default for DurationInput {
+    fn default() -> Self {
+        Self::new(Input::default())
+    }
+}
+
+impl DurationInput {
+    /// Wrap the given input, e.g. to set a placeholder. Its validator is
+    /// replaced.
+    pub fn new(input: Input) -> Self {
+        Self {
+            input: input.with_validator(Validator::duration()),
+        }
+    }
+
+    /// Get a reference to the input, e.g. to render it.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Get the parsed duration, if the value is valid.
+    pub fn duration(&self) -> Option<Duration> {
+        parse_duration(self.input.value()).ok()
+    }
+
+    /// Get the validation error of the value, if any.
+    pub fn error(&self) -> Option<String> {
+        self.input.validate().err()
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+        match req {
+            InputRequest::InsertNewline => None,
+            InputRequest::InsertChar(c)
+                if !(c.is_ascii_digit() || ".: dhms".contains(c)) =>
+            {
+                None
+            }
+            req => self.input.handle(req),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let secs = |s| parse_duration(s).map(|d| d.as_secs_f64());
+        assert_eq!(secs("1d 2h 3m 4s"), Ok(93784.0));
+        assert_eq!(secs("250ms"), Ok(0.25));
+        assert_eq!(secs("15:00"), Ok(900.0));
+        assert_eq!(secs("00:01.5"), Ok(1.5));
+        assert_eq!(secs("1:60"), Err("must be below 60: 60".into()));
+        assert_eq!(secs("1::2"), Err("must be like 02:15:00".into()));
+        assert_eq!(secs("1m1m"), Err("repeated unit: m".into()));
+        assert_eq!(secs("1y"), Err("unknown unit: y".into()));
+        assert_eq!(secs("h"), Err("invalid number: ".into()));
+        assert_eq!(secs(""), Err("required".into()));
+        assert_eq!(secs("99999999999999999999d"), Err("too long".into()));
+        assert_eq!(secs("18446744073709500000s 1d"), Err("too long".into()));
+        assert_eq!(secs("999999999999999999:00:00"), Err("too long".into()));
+    }
+}
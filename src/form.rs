@@ -0,0 +1,365 @@
+//! A group of named inputs with focus and per-field validation.
+
+use crate::{Input, InputRequest, InputResponse};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A named input of a [`Form`], with its last validation error.
+///
+/// The validator is the one set with [`Input::with_validator`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Field {
+    name: String,
+    input: Input,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    error: Option<String>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl Field {
+    /// Initialize a new field with the given name and input.
+    pub fn new(name: String, input: Input) -> Self {
+        Self {
+            name,
+            input,
+            error: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Get the app defined metadata of the field, e.g. whether it was touched.
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Set an app defined metadata entry of the field.
+    pub fn set_metadata(&mut self, key: String, value: String) {
+        self.metadata.insert(key, value);
+    }
+
+    /// Get the name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a reference to the input.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Get a mutable reference to the input. The error is kept until the next
+    /// validation.
+    pub fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
+    }
+
+    /// Get the error of the last validation, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Validate the value, remembering the error.
+    pub fn validate(&mut self) -> Result<(), String> {
+        let result = self.input.validate();
+        self.error = result.clone().err();
+        result
+    }
+}
+
+/// A group of named inputs, one of which has the focus.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::form::Form;
+/// use tui_input::{Input, InputRequest, Validator};
+///
+/// let required = Validator::new(|v| match v {
+///     "" => Err("required".into()),
+///     _ => Ok(()),
+/// });
+///
+/// let mut form = Form::default()
+///     .with_field("name".into(), Input::default().with_validator(required))
+///     .with_field("email".into(), Input::default());
+///
+/// assert!(!form.validate_all());
+/// assert_eq!(form.errors().collect::<Vec<_>>(), vec![("name", "required")]);
+///
+/// form.handle(InputRequest::InsertChar('a'));
+/// assert!(form.validate_all());
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Form {
+    fields: Vec<Field>,
+    focus: usize,
+}
+
+impl Form {
+    /// Add a field with the given name and input.
+    pub fn with_field(mut self, name: String, input: Input) -> Self {
+        self.fields.push(Field::new(name, input));
+        self
+    }
+
+    /// Get the fields, in order.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Get the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Get the field with the given name, mutably.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find(|f| f.name == name)
+    }
+
+    /// Get the value of the field with the given name.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.field(name).map(|f| f.input.value())
+    }
+
+    /// Get the index of the focused field.
+    pub fn focus(&self) -> usize {
+        self.focus
+    }
+
+    /// Focus the field at the given index, if it exists.
+    pub fn set_focus(&mut self, index: usize) {
+        if index < self.fields.len() {
+            self.focus = index;
+        }
+    }
+
+    /// Get the focused field, if any.
+    pub fn focused(&self) -> Option<&Field> {
+        self.fields.get(self.focus)
+    }
+
+    /// Get the focused field mutably, if any.
+    pub fn focused_mut(&mut self) -> Option<&mut Field> {
+        self.fields.get_mut(self.focus)
+    }
+
+    /// Focus the next field, wrapping around.
+    pub fn focus_next(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus = (self.focus + 1) % self.fields.len();
+        }
+    }
+
+    /// Focus the previous field, wrapping around.
+    pub fn focus_prev(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus = (self.focus + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    /// Handle the request with the focused field.
+    ///
+    /// A field with an error is validated again when its value changes, so
+    /// the error goes away as soon as it's fixed.
+    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+        let field = self.focused_mut()?;
+        let resp = field.input.handle(req);
+        if field.error.is_some() && resp.is_some_and(|r| r.value) {
+            let _ = field.validate();
+        }
+        resp
+    }
+
+    /// Validate all the fields, returning `true` if they're all valid.
+    pub fn validate_all(&mut self) -> bool {
+        let mut valid = true;
+        for field in &mut self.fields {
+            valid &= field.validate().is_ok();
+        }
+        valid
+    }
+
+    /// Iterate over the names and the errors of the invalid fields, as of the
+    /// last validation.
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .filter_map(|f| f.error().map(|e| (f.name(), e)))
+    }
+}
+
+/// The state of a [`Form`] saved as a draft, to be restored on next launch.
+///
+/// Unlike the form itself, it doesn't hold the validators and other settings
+/// of the inputs, so that they're defined by the app.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormDraft {
+    pub version: u32,
+    /// Name of the focused field, so that it keeps the focus when the fields
+    /// are reordered.
+    pub focus: Option<String>,
+    pub fields: Vec<FieldDraft>,
+}
+
+/// The state of a [`Field`] saved in a [`FormDraft`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldDraft {
+    pub name: String,
+    pub value: String,
+    pub cursor: usize,
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl FormDraft {
+    /// The version of the drafts saved by this version of the library.
+    pub const VERSION: u32 = 1;
+}
+
+/// Error restoring a [`FormDraft`].
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RestoreError {
+    /// The draft was saved by a newer version of the library.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(v) => write!(f, "unsupported draft version {v}"),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+impl Form {
+    /// Save the values, the focus and the metadata of the fields.
+    pub fn to_draft(&self) -> FormDraft {
+        FormDraft {
+            version: FormDraft::VERSION,
+            focus: self.focused().map(|f| f.name.clone()),
+            fields: self
+                .fields
+                .iter()
+                .map(|f| FieldDraft {
+                    name: f.name.clone(),
+                    value: f.input.value().into(),
+                    cursor: f.input.cursor(),
+                    metadata: f.metadata.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Restore the state saved in the draft into the fields with the same
+    /// names, and focus the field that had the focus. Fields missing from
+    /// either side are left alone.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::form::Form;
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let form = || Form::default().with_field("name".into(), Input::default());
+    ///
+    /// let mut old = form();
+    /// old.handle(InputRequest::InsertChar('a'));
+    /// let draft = old.to_draft();
+    ///
+    /// let mut new = form();
+    /// new.restore(&draft).unwrap();
+    /// assert_eq!(new.value("name"), Some("a"));
+    /// ```
+    pub fn restore(&mut self, draft: &FormDraft) -> Result<(), RestoreError> {
+        if draft.version > FormDraft::VERSION {
+            return Err(RestoreError::UnsupportedVersion(draft.version));
+        }
+        for saved in &draft.fields {
+            if let Some(field) = self.field_mut(&saved.name) {
+                field.input = std::mem::take(&mut field.input)
+                    .with_value(saved.value.clone())
+                    .with_cursor(saved.cursor);
+                field.metadata.clone_from(&saved.metadata);
+                field.error = None;
+            }
+        }
+        let focus = draft
+            .focus
+            .as_ref()
+            .and_then(|name| self.fields.iter().position(|f| &f.name == name));
+        if let Some(focus) = focus {
+            self.focus = focus;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Validator;
+
+    #[test]
+    fn revalidate_on_edit() {
+        let digits = Validator::new(|v| {
+            v.chars()
+                .all(|c| c.is_ascii_digit())
+                .then_some(())
+                .ok_or_else(|| "digits only".into())
+        });
+        let mut form = Form::default()
+            .with_field("a".into(), Input::from("1x").with_validator(digits))
+            .with_field("b".into(), Input::default());
+
+        form.handle(InputRequest::InsertChar('y'));
+        assert_eq!(form.errors().count(), 0);
+
+        assert!(!form.validate_all());
+        assert_eq!(form.focused().and_then(Field::error), Some("digits only"));
+
+        form.handle(InputRequest::DeleteLine);
+        assert_eq!(form.errors().count(), 0);
+
+        form.focus_prev();
+        assert_eq!(form.focused().map(Field::name), Some("b"));
+    }
+
+    #[test]
+    fn restore_draft() {
+        let mut form = Form::default()
+            .with_field("a".into(), Input::default())
+            .with_field("b".into(), Input::from("bb"));
+        form.set_focus(1);
+        form.handle(InputRequest::GoToStart);
+        if let Some(f) = form.focused_mut() {
+            f.set_metadata("touched".into(), "true".into());
+        }
+
+        let mut draft = form.to_draft();
+        let mut restored = Form::default()
+            .with_field("b".into(), Input::default())
+            .with_field("a".into(), Input::default());
+        assert_eq!(restored.restore(&draft), Ok(()));
+
+        let field = restored.field("b").unwrap();
+        assert_eq!(field.input().value(), "bb");
+        assert_eq!(field.input().cursor(), 0);
+        assert_eq!(
+            field.metadata().get("touched").map(String::as_str),
+            Some("true")
+        );
+        assert_eq!(restored.focused().map(Field::name), Some("b"));
+
+        draft.version = FormDraft::VERSION + 1;
+        assert_eq!(
+            restored.restore(&draft),
+            Err(RestoreError::UnsupportedVersion(2))
+        );
+    }
+}
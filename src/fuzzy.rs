@@ -0,0 +1,151 @@
+//! A small fuzzy matcher for filtering candidates and highlighting matches.
+
+/// The result of a successful fuzzy match.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FuzzyMatch {
+    /// Higher is better.
+    pub score: i64,
+    /// Char indices of the matched chars in the candidate.
+    pub indices: Vec<usize>,
+}
+
+const MATCH: i64 = 16;
+const CONSECUTIVE: i64 = 8;
+const BOUNDARY: i64 = 8;
+const GAP: i64 = 1;
+
+/// Match the pattern chars, in order, against the candidate.
+///
+/// Matching is case insensitive, unless the pattern contains an uppercase
+/// char. Matches on consecutive chars and word boundaries score higher.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::fuzzy::fuzzy_match;
+///
+/// let m = fuzzy_match("fb", "foo_bar").unwrap();
+/// assert_eq!(m.indices, vec![0, 4]);
+///
+/// assert!(fuzzy_match("fz", "foo_bar").is_none());
+/// ```
+pub fn fuzzy_match(pattern: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let case_sensitive = pattern.chars().any(char::is_uppercase);
+    let normalize = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+
+    let pattern: Vec<char> = pattern.chars().map(normalize).collect();
+    let chars: Vec<char> = candidate.chars().collect();
+    if pattern.is_empty() {
+        return Some(FuzzyMatch::default());
+    }
+
+    // Find the end of the first match going forward
+    let mut p = 0;
+    let mut end = None;
+    for (i, c) in chars.iter().enumerate() {
+        if normalize(*c) == pattern[p] {
+            p += 1;
+            if p == pattern.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // Then go backward to find the tightest match ending there
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut p = pattern.len();
+    for i in (0..=end).rev() {
+        if normalize(chars[i]) == pattern[p - 1] {
+            indices.push(i);
+            p -= 1;
+            if p == 0 {
+                break;
+            }
+        }
+    }
+    indices.reverse();
+
+    let mut score = 0;
+    for (n, &i) in indices.iter().enumerate() {
+        score += MATCH;
+        if n > 0 && indices[n - 1] + 1 == i {
+            score += CONSECUTIVE;
+        }
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        if prev.is_none_or(|p| !p.is_alphanumeric())
+            || (prev.is_some_and(char::is_lowercase) && chars[i].is_uppercase())
+        {
+            score += BOUNDARY;
+        }
+    }
+    score -= (indices[indices.len() - 1] - indices[0] + 1 - indices.len()) as i64 * GAP;
+    score -= indices[0] as i64 * GAP;
+
+    Some(FuzzyMatch { score, indices })
+}
+
+/// Filter the candidates matching the pattern, best matches first.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::fuzzy::fuzzy_filter;
+///
+/// let matches = fuzzy_filter("gc", ["git status", "cargo check", "git commit"]);
+/// let candidates: Vec<_> = matches.iter().map(|(c, _)| *c).collect();
+///
+/// assert_eq!(candidates, vec!["git commit", "cargo check"]);
+/// ```
+pub fn fuzzy_filter<'a, I>(pattern: &str, candidates: I) -> Vec<(&'a str, FuzzyMatch)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut matches: Vec<_> = candidates
+        .into_iter()
+        .filter_map(|c| fuzzy_match(pattern, c).map(|m| (c, m)))
+        .collect();
+    matches
+        .sort_by(|(a, ma), (b, mb)| mb.score.cmp(&ma.score).then(a.len().cmp(&b.len())));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tightest_match() {
+        let m = fuzzy_match("ab", "a_xab").unwrap();
+        assert_eq!(m.indices, vec![3, 4]);
+    }
+
+    #[test]
+    fn smart_case() {
+        assert!(fuzzy_match("fb", "FooBar").is_some());
+        assert!(fuzzy_match("FB", "foobar").is_none());
+        assert_eq!(fuzzy_match("FB", "FooBar").unwrap().indices, vec![0, 3]);
+    }
+
+    #[test]
+    fn scores() {
+        let consecutive = fuzzy_match("ob", "fobar").unwrap();
+        let gap = fuzzy_match("ob", "foxbar").unwrap();
+        assert!(consecutive.score > gap.score);
+
+        let boundary = fuzzy_match("b", "foo_bar").unwrap();
+        let camel = fuzzy_match("b", "fooBar").unwrap();
+        let inner = fuzzy_match("b", "foobar").unwrap();
+        assert!(boundary.score > inner.score);
+        assert!(camel.score > inner.score);
+        assert_eq!(fuzzy_match("", "foo"), Some(FuzzyMatch::default()));
+    }
+}
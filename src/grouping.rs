@@ -0,0 +1,124 @@
+//! Displaying the value in groups, e.g. `1234 5678 9012 3456`, while the input
+//! stores the raw chars.
+
+use crate::Input;
+
+/// Splits the raw value into groups of chars joined by a separator, and
+/// translates the positions between the raw and the displayed value.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::grouping::Grouping;
+/// use tui_input::Input;
+///
+/// let grouping = Grouping::new(vec![4], ' ');
+/// let input = Input::from("1234567890").with_cursor(5);
+///
+/// assert_eq!(grouping.display(&input), ("1234 5678 90".into(), 6));
+/// assert_eq!(grouping.raw_index(6), 5);
+/// ```
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grouping {
+    sizes: Vec<usize>,
+    separator: char,
+}
+
+impl Grouping {
+    /// Initialize a new grouping with the given group sizes, the last one
+    /// repeating, e.g. `vec![4, 6, 5]` for American Express card numbers.
+    /// Zero sizes are ignored.
+    pub fn new(mut sizes: Vec<usize>, separator: char) -> Self {
+        sizes.retain(|&size| size > 0);
+        Self { sizes, separator }
+    }
+
+    /// Groups of 4 chars separated by spaces, like most card numbers.
+    pub fn card_number() -> Self {
+        Self::new(vec![4], ' ')
+    }
+
+    /// Get the separator.
+    pub fn separator(&self) -> char {
+        self.separator
+    }
+
+    /// Get the raw char indices starting a group, up to the given index.
+    fn group_starts(&self, max: usize) -> impl Iterator<Item = usize> + '_ {
+        let last = self.sizes.last().copied();
+        self.sizes
+            .iter()
+            .copied()
+            .chain(std::iter::repeat_with(move || last.unwrap_or(usize::MAX)))
+            .scan(0_usize, |start, size| {
+                *start = start.saturating_add(size);
+                Some(*start)
+            })
+            .take_while(move |&start| start <= max && start != usize::MAX)
+    }
+
+    /// Format the raw value, e.g. `"12345"` as `"1234 5"`.
+    pub fn format(&self, raw: &str) -> String {
+        let len = raw.chars().count();
+        let mut starts = self.group_starts(len).peekable();
+        let mut formatted = String::with_capacity(raw.len() + raw.len() / 2);
+        for (i, c) in raw.chars().enumerate() {
+            if starts.next_if_eq(&i).is_some() {
+                formatted.push(self.separator);
+            }
+            formatted.push(c);
+        }
+        formatted
+    }
+
+    /// Get the index in the displayed value of the char at the given raw index.
+    /// Past the last char, the index may point after a trailing separator that
+    /// isn't displayed.
+    pub fn display_index(&self, raw_index: usize) -> usize {
+        raw_index + self.group_starts(raw_index).count()
+    }
+
+    /// Get the raw index of the char at the given index of the displayed value.
+    /// Separators map to the char after them.
+    pub fn raw_index(&self, display_index: usize) -> usize {
+        let separators = self
+            .group_starts(display_index)
+            .enumerate()
+            .take_while(|&(i, start)| start + i < display_index)
+            .count();
+        display_index - separators
+    }
+
+    /// Get the formatted value of the input and the index of its cursor in it.
+    pub fn display(&self, input: &Input) -> (String, usize) {
+        let formatted = self.format(input.value());
+        let cursor = self
+            .display_index(input.cursor())
+            .min(formatted.chars().count());
+        (formatted, cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let grouping = Grouping::new(vec![4, 6, 5], '-');
+        assert_eq!(grouping.format("378282246310005"), "3782-822463-10005");
+        assert_eq!(grouping.format("3782"), "3782");
+        assert_eq!(grouping.format(""), "");
+
+        for (raw, display) in [(0, 0), (3, 3), (4, 5), (9, 10), (10, 12), (15, 18)] {
+            assert_eq!(grouping.display_index(raw), display);
+            assert_eq!(grouping.raw_index(display), raw);
+        }
+        assert_eq!(grouping.raw_index(4), 4);
+        assert_eq!(grouping.raw_index(11), 10);
+
+        let input = Input::from("3782");
+        assert_eq!(grouping.display(&input), ("3782".into(), 4));
+    }
+}
@@ -0,0 +1,508 @@
+//! History of submitted values.
+
+use crate::completion::{Completer, Completion};
+use crate::Input;
+#[cfg(feature = "json")]
+use std::io;
+use std::ops::RangeBounds;
+use std::time::SystemTime;
+
+/// A history entry.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryEntry {
+    pub value: String,
+    /// Number of times the value was submitted.
+    pub uses: usize,
+    /// Tick of the last submission, used to order by recency.
+    pub last_used: usize,
+    /// Pinned entries are never evicted.
+    pub pinned: bool,
+    /// Time of the last submission, if recorded, see
+    /// [`History::with_timestamps`].
+    pub timestamp: Option<SystemTime>,
+    /// Context of the submission, e.g. the working directory of a shell, see
+    /// [`History::push_with_tag`].
+    pub tag: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Frecency score, favoring values used often and recently.
+    fn score(&self, now: usize) -> usize {
+        let age = now - self.last_used;
+        self.uses * 100 / (age + 1)
+    }
+}
+
+/// How repeated values are recorded.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dedupe {
+    /// Keep a single entry per value, counting its uses (like `erasedups`).
+    #[default]
+    All,
+    /// Collapse repeats of the last value only (like `ignoredups`).
+    Consecutive,
+    /// Record every value.
+    None,
+}
+
+/// History of submitted values. It can also be used as a [`Completer`],
+/// suggesting previously submitted values starting with the current value,
+/// ordered by frecency.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::completion::Completer;
+/// use tui_input::history::History;
+/// use tui_input::Input;
+///
+/// let mut history = History::default();
+/// history.push("git status".into());
+/// history.push("git commit".into());
+/// history.push("git status".into());
+///
+/// let input: Input = "git ".into();
+/// let completions = history.complete(&input);
+///
+/// assert_eq!(completions[0].text, "git status");
+/// assert_eq!(completions[1].text, "git commit");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+    tick: usize,
+    dedupe: Dedupe,
+    ignore_space: bool,
+    max_entries: Option<usize>,
+    timestamps: bool,
+    ignore_masked: bool,
+}
+
+impl History {
+    /// Set how repeated values are recorded.
+    pub fn with_dedupe(mut self, dedupe: Dedupe) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Skip the values starting with a space (like `ignorespace`).
+    pub fn with_ignore_space(mut self, ignore_space: bool) -> Self {
+        self.ignore_space = ignore_space;
+        self
+    }
+
+    /// Skip the values of masked inputs, e.g. passwords, in
+    /// [`History::push_input`] and the line editor.
+    pub fn with_ignore_masked(mut self, ignore_masked: bool) -> Self {
+        self.ignore_masked = ignore_masked;
+        self
+    }
+
+    /// Check if the values of masked inputs are skipped.
+    pub fn ignores_masked(&self) -> bool {
+        self.ignore_masked
+    }
+
+    /// Record the time of the submissions, e.g. to show the history with dates.
+    pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Set the max number of entries. The oldest entries that aren't pinned are
+    /// evicted first.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::History;
+    ///
+    /// let mut history = History::default().with_max_entries(2);
+    /// history.push("a".into());
+    /// history.pin("a");
+    /// history.push("b".into());
+    /// history.push("c".into());
+    ///
+    /// let values: Vec<_> = history.entries().iter().map(|e| &e.value).collect();
+    /// assert_eq!(values, vec!["a", "c"]);
+    /// ```
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self.evict();
+        self
+    }
+
+    /// Pin the entries with the given value, so they're never evicted.
+    /// Returns `false` if there's no such entry.
+    pub fn pin(&mut self, value: &str) -> bool {
+        self.set_pinned(value, true)
+    }
+
+    /// Unpin the entries with the given value.
+    /// Returns `false` if there's no such entry.
+    pub fn unpin(&mut self, value: &str) -> bool {
+        let found = self.set_pinned(value, false);
+        self.evict();
+        found
+    }
+
+    fn set_pinned(&mut self, value: &str, pinned: bool) -> bool {
+        let mut found = false;
+        for entry in self.entries.iter_mut().filter(|e| e.value == value) {
+            entry.pinned = pinned;
+            found = true;
+        }
+        found
+    }
+
+    /// Remove the oldest unpinned entries beyond the max number of entries.
+    fn evict(&mut self) {
+        let max = self.max_entries.unwrap_or(usize::MAX);
+        while self.entries.len() > max {
+            let oldest = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| !e.pinned)
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(i, _)| i);
+            match oldest {
+                Some(i) => self.entries.remove(i),
+                None => break,
+            };
+        }
+    }
+
+    /// Record a submitted value. Returns `false` if it was ignored.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::{Dedupe, History};
+    ///
+    /// let mut history = History::default()
+    ///     .with_dedupe(Dedupe::Consecutive)
+    ///     .with_ignore_space(true);
+    ///
+    /// history.push("ls".into());
+    /// history.push("ls".into());
+    /// history.push("cd".into());
+    /// history.push("ls".into());
+    /// assert!(!history.push(" secret".into()));
+    ///
+    /// assert_eq!(history.len(), 3);
+    /// ```
+    pub fn push(&mut self, value: String) -> bool {
+        self.record(value, None)
+    }
+
+    /// Record the value of the input, unless it's masked and masked inputs are
+    /// ignored. Returns `false` if it was ignored.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::History;
+    /// use tui_input::Input;
+    ///
+    /// let mut history = History::default().with_ignore_masked(true);
+    /// assert!(!history.push_input(&Input::from("hunter2").with_mask('*')));
+    /// assert!(history.push_input(&Input::from("alice")));
+    /// assert_eq!(history.len(), 1);
+    /// ```
+    pub fn push_input(&mut self, input: &Input) -> bool {
+        if self.ignore_masked && input.mask().is_some() {
+            return false;
+        }
+        self.push(input.value().into())
+    }
+
+    /// Record a value submitted in the given context, e.g. the working
+    /// directory. Repeated values are only merged within the same context.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::History;
+    ///
+    /// let mut history = History::default().with_timestamps(true);
+    /// history.push_with_tag("make".into(), "~/a".into());
+    /// history.push_with_tag("make".into(), "~/b".into());
+    /// history.push("ls".into());
+    ///
+    /// let entries = history.entries_tagged("~/b");
+    /// assert_eq!(entries.len(), 1);
+    /// assert!(entries[0].timestamp.is_some());
+    /// ```
+    pub fn push_with_tag(&mut self, value: String, tag: String) -> bool {
+        self.record(value, Some(tag))
+    }
+
+    fn record(&mut self, value: String, tag: Option<String>) -> bool {
+        if self.ignore_space && value.starts_with(' ') {
+            return false;
+        }
+        self.tick += 1;
+        let timestamp = self.timestamps.then(SystemTime::now);
+        let same = |e: &&mut HistoryEntry| e.value == value && e.tag == tag;
+        let existing = match self.dedupe {
+            Dedupe::All => self.entries.iter_mut().find(same),
+            Dedupe::Consecutive => self
+                .entries
+                .iter_mut()
+                .max_by_key(|e| e.last_used)
+                .filter(same),
+            Dedupe::None => None,
+        };
+        match existing {
+            Some(entry) => {
+                entry.uses += 1;
+                entry.last_used = self.tick;
+                entry.timestamp = timestamp;
+            }
+            None => self.entries.push(HistoryEntry {
+                value,
+                uses: 1,
+                last_used: self.tick,
+                pinned: false,
+                timestamp,
+                tag,
+            }),
+        }
+        self.evict();
+        true
+    }
+
+    /// Get the entries, from the oldest to the most recently submitted.
+    pub fn entries(&self) -> Vec<&HistoryEntry> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by_key(|e| e.last_used);
+        entries
+    }
+
+    /// Get the entries submitted in the given context, from the oldest to the
+    /// most recently submitted.
+    pub fn entries_tagged(&self, tag: &str) -> Vec<&HistoryEntry> {
+        let mut entries = self.entries();
+        entries.retain(|e| e.tag.as_deref() == Some(tag));
+        entries
+    }
+
+    /// Get the entries last submitted within the given time range, from the
+    /// oldest to the most recently submitted. Entries without a timestamp are
+    /// skipped.
+    pub fn entries_between<R>(&self, range: R) -> Vec<&HistoryEntry>
+    where
+        R: RangeBounds<SystemTime>,
+    {
+        let mut entries = self.entries();
+        entries.retain(|e| e.timestamp.is_some_and(|t| range.contains(&t)));
+        entries
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the history is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all the entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get the entries starting with the given prefix, ordered by frecency.
+    /// Only the best entry of each value is returned.
+    pub fn matches(&self, prefix: &str) -> Vec<&HistoryEntry> {
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|e| e.value.starts_with(prefix) && e.value != prefix)
+            .collect();
+        entries.sort_by(|a, b| {
+            b.score(self.tick)
+                .cmp(&a.score(self.tick))
+                .then(b.last_used.cmp(&a.last_used))
+        });
+        let mut seen = std::collections::HashSet::new();
+        entries.retain(|e| seen.insert(e.value.as_str()));
+        entries
+    }
+
+    /// Save the history as JSON, passing it through the given function first,
+    /// e.g. to encrypt it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::History;
+    ///
+    /// let rot = |bytes: Vec<u8>| bytes.into_iter().map(|b| b.wrapping_add(1)).collect();
+    /// let unrot = |bytes: Vec<u8>| Ok(bytes.into_iter().map(|b| b.wrapping_sub(1)).collect());
+    ///
+    /// let mut history = History::default();
+    /// history.push("secret".into());
+    ///
+    /// let mut buf = Vec::new();
+    /// history.save(&mut buf, rot).unwrap();
+    /// assert!(!String::from_utf8_lossy(&buf).contains("secret"));
+    ///
+    /// let loaded = History::load(buf.as_slice(), unrot).unwrap();
+    /// assert_eq!(loaded, history);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn save<W, F>(&self, mut writer: W, encrypt: F) -> io::Result<()>
+    where
+        W: io::Write,
+        F: FnOnce(Vec<u8>) -> Vec<u8>,
+    {
+        let json = serde_json::to_vec(self)?;
+        writer.write_all(&encrypt(json))?;
+        writer.flush()
+    }
+
+    /// Load the history saved by [`History::save`], passing it through the
+    /// given function first, e.g. to decrypt it.
+    #[cfg(feature = "json")]
+    pub fn load<R, F>(mut reader: R, decrypt: F) -> io::Result<Self>
+    where
+        R: io::Read,
+        F: FnOnce(Vec<u8>) -> io::Result<Vec<u8>>,
+    {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(serde_json::from_slice(&decrypt(bytes)?)?)
+    }
+
+    /// Get the rest of the best entry starting with the given value, to be
+    /// shown as ghost text with [`Input::set_suggestion`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::history::History;
+    ///
+    /// let mut history = History::default();
+    /// history.push("git status".into());
+    ///
+    /// assert_eq!(history.suggest("git s"), Some("tatus".into()));
+    /// assert_eq!(history.suggest(""), None);
+    /// ```
+    pub fn suggest(&self, value: &str) -> Option<String> {
+        if value.is_empty() {
+            return None;
+        }
+        let best = self.matches(value).into_iter().next()?;
+        Some(best.value[value.len()..].to_string())
+    }
+}
+
+impl Completer for History {
+    fn complete(&self, input: &Input) -> Vec<Completion> {
+        let len = input.char_len();
+        self.matches(input.value())
+            .into_iter()
+            .map(|e| Completion {
+                range: 0..len,
+                text: e.value.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut history = History::default();
+        history.push("a".into());
+        history.push("b".into());
+        history.push("a".into());
+
+        assert_eq!(history.len(), 2);
+        let values: Vec<_> = history.entries().iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["b", "a"]);
+        assert_eq!(history.entries()[1].uses, 2);
+    }
+
+    #[test]
+    fn frecency() {
+        let mut history = History::default();
+        history.push("ls -l".into());
+        history.push("ls -l".into());
+        history.push("ls -a".into());
+        history.push("cd".into());
+        history.push("ls".into());
+
+        let values: Vec<_> = history.matches("ls").iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["ls -l", "ls -a"]);
+
+        history.push("ls -a".into());
+        let values: Vec<_> = history.matches("ls").iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["ls -a", "ls -l"]);
+    }
+
+    #[test]
+    fn dedupe() {
+        let mut history = History::default().with_dedupe(Dedupe::None);
+        history.push("a".into());
+        history.push("a".into());
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.matches("").len(), 1);
+
+        let mut history = History::default().with_dedupe(Dedupe::Consecutive);
+        history.push("a".into());
+        history.push("a".into());
+        history.push("b".into());
+        history.push("a".into());
+        let values: Vec<_> = history.entries().iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["a", "b", "a"]);
+        assert_eq!(history.entries()[0].uses, 2);
+    }
+
+    #[test]
+    fn timestamps_and_tags() {
+        let start = SystemTime::now();
+        let mut history = History::default().with_timestamps(true);
+        history.push_with_tag("make".into(), "a".into());
+        history.push_with_tag("make".into(), "b".into());
+        history.push_with_tag("make".into(), "a".into());
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.entries_tagged("a")[0].uses, 2);
+        assert_eq!(history.entries_between(start..).len(), 2);
+        assert_eq!(history.entries_between(..start).len(), 0);
+
+        let mut history = History::default();
+        history.push("ls".into());
+        assert_eq!(history.entries()[0].timestamp, None);
+        assert_eq!(history.entries_between(..).len(), 0);
+    }
+
+    #[test]
+    fn eviction() {
+        let mut history = History::default();
+        for value in ["a", "b", "c", "d"] {
+            history.push(value.into());
+        }
+        history.pin("b");
+        history.pin("c");
+
+        let mut history = history.with_max_entries(1);
+        let values: Vec<_> = history.entries().iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["b", "c"]);
+
+        assert!(history.unpin("b"));
+        assert!(!history.unpin("z"));
+        let values: Vec<_> = history.entries().iter().map(|e| &e.value).collect();
+        assert_eq!(values, vec!["c"]);
+    }
+}
@@ -1,3 +1,16 @@
+use crate::buffer::{Edit, InputBuffer, SmallString, Storage};
+use crate::clipboard::ClipboardProvider;
+use crate::registers::Registers;
+use crate::request_log::RequestLog;
+use crate::spell::{SharedSpellChecker, SpellChecker};
+use crate::undo::{Coalescing, EditKind, Snapshot, UndoHistory};
+use crate::{Segmenter, Transform, Validator};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
 /// Input requests are used to change the input state.
 ///
 /// Different backends can be used to convert events into requests.
@@ -18,6 +31,242 @@ pub enum InputRequest {
     DeleteNextWord,
     DeleteLine,
     DeleteTillEnd,
+    GoToPrevHighlight,
+    GoToNextHighlight,
+    ToggleMask,
+    CopySelection,
+    CutSelection,
+    /// Replace the selection (or insert at the cursor) with the clipboard.
+    PasteOverSelection,
+    /// Use the given register for the next delete, copy or paste, like vi's
+    /// `"a`.
+    SelectRegister(char),
+    GoToPrevSentence,
+    GoToNextSentence,
+    GoToPrevParagraph,
+    GoToNextParagraph,
+    /// Toggle the case of the char under the cursor and move to the next one.
+    SwapCase,
+    /// Toggle the case of the chars till the start of the next word and move
+    /// there.
+    SwapCaseWord,
+    /// Split the line at the cursor. Rejected by single-line inputs.
+    InsertNewline,
+    /// Overwrite the char under the cursor without moving it, like vi's `r`.
+    ReplaceChar(char),
+    /// Take the value and reset the input. Use [`Input::handle_detailed`] to
+    /// get the taken value. Read-only inputs keep their value. It's undone
+    /// like an edit, restoring the taken value.
+    Submit,
+    /// Repeat the last request that changed the value.
+    RepeatLastEdit,
+    /// Move to the previous line of a multi-line value, keeping the column.
+    GoToPrevLine,
+    /// Move to the next line of a multi-line value, keeping the column.
+    GoToNextLine,
+    /// Undo the last edit. Consecutive typing is undone at once, see
+    /// [`Input::with_undo_coalescing`].
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
+    /// Select the whole value, moving the cursor to the end.
+    SelectAll,
+    /// Select the word under (or right before) the cursor.
+    SelectWord,
+    /// Select the line of the cursor, excluding the newline.
+    SelectLine,
+    /// Move to the next occurrence of the char on the line, like vi's `f`.
+    FindNextChar(char),
+    /// Move to the previous occurrence of the char on the line, like vi's `F`.
+    FindPrevChar(char),
+    /// Move right before the next occurrence of the char on the line, like
+    /// vi's `t`.
+    TillNextChar(char),
+    /// Move right after the previous occurrence of the char on the line, like
+    /// vi's `T`.
+    TillPrevChar(char),
+    /// Repeat the last find, like vi's `;`.
+    RepeatFind,
+    /// Repeat the last find in the opposite direction, like vi's `,`.
+    RepeatFindReverse,
+    /// Move to the start of the next search match, wrapping around.
+    GoToNextMatch,
+    /// Move to the start of the previous search match, wrapping around.
+    GoToPrevMatch,
+    /// Start or stop a rectangular selection of a multi-line value, like vi's
+    /// Ctrl+V. See [`Input::block_selection`].
+    ToggleBlockSelection,
+    /// Append the whole suggestion to the value, see [`Input::set_suggestion`].
+    /// Also sent by [`InputRequest::GoToNextChar`] and [`InputRequest::GoToEnd`]
+    /// at the end of the value.
+    AcceptSuggestion,
+    /// Append the next word of the suggestion to the value. Also sent by
+    /// [`InputRequest::GoToNextWord`] at the end of the value.
+    AcceptSuggestionWord,
+    /// Move to the start of the next misspelled word, see
+    /// [`Input::with_spell_checker`].
+    GoToNextMisspelling,
+    /// Move to the start of the previous misspelled word.
+    GoToPrevMisspelling,
+    /// Move to the start of the previous sub-word, stopping at camelCase humps
+    /// and underscores. See [`Input::sub_words`].
+    GoToPrevSubWord,
+    /// Move to the start of the next sub-word.
+    GoToNextSubWord,
+    /// Delete the sub-word before the cursor.
+    DeletePrevSubWord,
+    /// Delete the sub-word after the cursor.
+    DeleteNextSubWord,
+    /// Extend the selection to the previous char, starting one at the cursor
+    /// if there's none, like Shift+Left in GUI text fields.
+    SelectPrevChar,
+    /// Extend the selection to the next char.
+    SelectNextChar,
+    /// Extend the selection to the start of the previous word, like
+    /// Ctrl+Shift+Left in GUI text fields.
+    SelectPrevWord,
+    /// Extend the selection to the start of the next word.
+    SelectNextWord,
+    /// Extend the selection to the start of the value.
+    SelectToStart,
+    /// Extend the selection to the end of the value.
+    SelectToEnd,
+    /// Toggle the overwrite mode, where typed chars replace the ones under the
+    /// cursor, like the Insert key. See [`Input::is_overwrite`].
+    ToggleOverwrite,
+}
+
+/// Readline command names of the requests that have one.
+const READLINE_NAMES: &[(&str, InputRequest)] = &[
+    ("backward-char", InputRequest::GoToPrevChar),
+    ("forward-char", InputRequest::GoToNextChar),
+    ("backward-word", InputRequest::GoToPrevWord),
+    ("forward-word", InputRequest::GoToNextWord),
+    ("beginning-of-line", InputRequest::GoToStart),
+    ("end-of-line", InputRequest::GoToEnd),
+    ("backward-delete-char", InputRequest::DeletePrevChar),
+    ("delete-char", InputRequest::DeleteNextChar),
+    ("backward-kill-word", InputRequest::DeletePrevWord),
+    ("kill-word", InputRequest::DeleteNextWord),
+    ("kill-whole-line", InputRequest::DeleteLine),
+    ("kill-line", InputRequest::DeleteTillEnd),
+    ("copy-region-as-kill", InputRequest::CopySelection),
+    ("kill-region", InputRequest::CutSelection),
+    ("yank", InputRequest::PasteOverSelection),
+    ("accept-line", InputRequest::Submit),
+    ("undo", InputRequest::Undo),
+];
+
+impl InputRequest {
+    /// Get the request for the given readline command name, e.g.
+    /// `"backward-kill-word"`.
+    ///
+    /// ```
+    /// use tui_input::InputRequest;
+    ///
+    /// assert_eq!(
+    ///     InputRequest::from_name("backward-kill-word"),
+    ///     Some(InputRequest::DeletePrevWord)
+    /// );
+    /// assert_eq!(InputRequest::from_name("no-such-command"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        READLINE_NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, req)| *req)
+    }
+
+    /// Get the readline command name of the request, if it has one.
+    ///
+    /// ```
+    /// use tui_input::InputRequest;
+    ///
+    /// assert_eq!(InputRequest::GoToStart.name(), Some("beginning-of-line"));
+    /// assert_eq!(InputRequest::InsertChar('a').name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        READLINE_NAMES
+            .iter()
+            .find(|(_, req)| req == self)
+            .map(|(n, _)| *n)
+    }
+
+    /// Get a short human readable description of the request, e.g. for a key
+    /// bindings help popup.
+    ///
+    /// ```
+    /// use tui_input::InputRequest;
+    ///
+    /// assert_eq!(InputRequest::DeletePrevWord.description(), "Delete the previous word");
+    /// ```
+    pub fn description(&self) -> &'static str {
+        use InputRequest::*;
+        match self {
+            SetCursor(_) => "Set the cursor",
+            InsertChar(_) => "Insert the char",
+            GoToPrevChar => "Go to the previous char",
+            GoToNextChar => "Go to the next char",
+            GoToPrevWord => "Go to the previous word",
+            GoToNextWord => "Go to the next word",
+            GoToStart => "Go to the start",
+            GoToEnd => "Go to the end",
+            DeletePrevChar => "Delete the previous char",
+            DeleteNextChar => "Delete the next char",
+            DeletePrevWord => "Delete the previous word",
+            DeleteNextWord => "Delete the next word",
+            DeleteLine => "Delete the line",
+            DeleteTillEnd => "Delete till the end",
+            GoToPrevHighlight => "Go to the previous highlight",
+            GoToNextHighlight => "Go to the next highlight",
+            ToggleMask => "Show or hide the value",
+            CopySelection => "Copy the selection",
+            CutSelection => "Cut the selection",
+            PasteOverSelection => "Paste",
+            SelectRegister(_) => "Select a register",
+            GoToPrevSentence => "Go to the previous sentence",
+            GoToNextSentence => "Go to the next sentence",
+            GoToPrevParagraph => "Go to the previous paragraph",
+            GoToNextParagraph => "Go to the next paragraph",
+            SwapCase => "Swap the case of the char",
+            SwapCaseWord => "Swap the case of the word",
+            InsertNewline => "Insert a newline",
+            ReplaceChar(_) => "Replace the char",
+            Submit => "Submit",
+            RepeatLastEdit => "Repeat the last edit",
+            GoToPrevLine => "Go to the previous line",
+            GoToNextLine => "Go to the next line",
+            Undo => "Undo",
+            Redo => "Redo",
+            SelectAll => "Select all",
+            SelectWord => "Select the word",
+            SelectLine => "Select the line",
+            FindNextChar(_) => "Find the next char",
+            FindPrevChar(_) => "Find the previous char",
+            TillNextChar(_) => "Go till the next char",
+            TillPrevChar(_) => "Go till the previous char",
+            RepeatFind => "Repeat the find",
+            RepeatFindReverse => "Repeat the find backwards",
+            GoToNextMatch => "Go to the next match",
+            GoToPrevMatch => "Go to the previous match",
+            ToggleBlockSelection => "Start or stop a block selection",
+            AcceptSuggestion => "Accept the suggestion",
+            AcceptSuggestionWord => "Accept a word of the suggestion",
+            GoToNextMisspelling => "Go to the next misspelling",
+            GoToPrevMisspelling => "Go to the previous misspelling",
+            GoToPrevSubWord => "Go to the previous sub-word",
+            GoToNextSubWord => "Go to the next sub-word",
+            DeletePrevSubWord => "Delete the previous sub-word",
+            DeleteNextSubWord => "Delete the next sub-word",
+            SelectPrevChar => "Extend the selection to the previous char",
+            SelectNextChar => "Extend the selection to the next char",
+            SelectPrevWord => "Extend the selection to the previous word",
+            SelectNextWord => "Extend the selection to the next word",
+            SelectToStart => "Extend the selection to the start",
+            SelectToEnd => "Extend the selection to the end",
+            ToggleOverwrite => "Toggle the overwrite mode",
+        }
+    }
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
@@ -29,6 +278,169 @@ pub struct StateChanged {
 
 pub type InputResponse = Option<StateChanged>;
 
+/// Response of [`Input::handle_detailed`], carrying data produced by the
+/// request along with the state changes.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DetailedResponse {
+    pub state: InputResponse,
+    /// The value taken by [`InputRequest::Submit`].
+    pub submitted: Option<String>,
+    /// The change made to the value, if any.
+    pub edit: Option<Edit>,
+}
+
+/// What single-line inputs do with the newlines sent by
+/// [`InputRequest::InsertNewline`] or [`InputRequest::InsertChar`], e.g. on
+/// Ctrl+J, and with the pasted ones.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NewlinePolicy {
+    /// Ignore the newlines.
+    #[default]
+    Drop,
+    /// Insert spaces instead.
+    Space,
+    /// Submit the value instead, like [`InputRequest::Submit`]. Pasted newlines
+    /// are inserted as spaces, so that pasting never submits.
+    Submit,
+}
+
+/// Why a request was refused by [`Input::try_handle`].
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputError {
+    /// The input is read-only, see [`Input::with_read_only`].
+    ReadOnly,
+    /// The value reached the max length in chars.
+    MaxLen,
+    /// The value would exceed the max length in bytes.
+    MaxBytes,
+    /// The value would exceed the max lines or the max columns of a line.
+    LineLimits,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadOnly => write!(f, "the input is read-only"),
+            Self::MaxLen => write!(f, "the value reached the max length"),
+            Self::MaxBytes => write!(f, "the value would exceed the max bytes"),
+            Self::LineLimits => write!(f, "the value would exceed the line limits"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// A rectangular selection of a multi-line value, see
+/// [`Input::block_selection`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockSelection {
+    /// The selected lines.
+    pub lines: Range<usize>,
+    /// The selected columns of each line, in chars. May be empty, to insert or
+    /// delete at the same column of every line.
+    pub columns: Range<usize>,
+}
+
+/// Length of the value in different units.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LenInfo {
+    pub chars: usize,
+    pub graphemes: usize,
+    pub bytes: usize,
+    /// The configured max length in chars, if any.
+    pub max: Option<usize>,
+}
+
+impl LenInfo {
+    /// Get the formatted counter, e.g. `"12/140"`, if a max length is configured.
+    pub fn counter(&self) -> Option<String> {
+        self.max.map(|max| format!("{}/{}", self.chars, max))
+    }
+}
+
+/// The lines of a multi-line input visible when rendering.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Viewport {
+    /// Index of the first visible line.
+    pub top: usize,
+    /// Number of visible lines, or 0 if unknown.
+    pub height: usize,
+}
+
+/// Position details of a char of the value, see [`Input::inspect`].
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharInfo {
+    /// Index of the char, as used by the cursor.
+    pub index: usize,
+    /// Byte offset of the char in the value.
+    pub byte: usize,
+    /// Column the char is rendered at.
+    pub column: usize,
+    /// Width of the char in columns.
+    pub width: usize,
+    /// Whether the char starts an extended grapheme cluster.
+    pub grapheme_start: bool,
+    pub char: char,
+}
+
+/// How the value is split into words by the word motions and deletions.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WordBoundaries {
+    /// Words are runs of alphanumeric chars, skipping the punctuation like
+    /// readline: `foo.bar(baz)` has the words `foo`, `bar` and `baz`.
+    #[default]
+    Alphanumeric,
+    /// Words are runs of alphanumeric chars and underscores, or runs of the
+    /// other non-blank chars like vi: `foo.bar(baz)` has the words `foo`, `.`,
+    /// `bar`, `(`, `baz` and `)`.
+    Vi,
+    /// Words are split at the Unicode word boundaries (UAX #29), skipping the
+    /// whitespace and ASCII punctuation. Keeps e.g. `"can't"`, `"3.14"` or
+    /// emoji sequences together.
+    Unicode,
+    /// Words are runs of alphanumeric chars of the same script, so that e.g.
+    /// Chinese or Japanese text without spaces is split where it switches
+    /// between Han, Hiragana and Katakana.
+    Script,
+}
+
+/// The script of a word char, as used by [`WordBoundaries::Script`].
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Script {
+    Han,
+    Hiragana,
+    Katakana,
+    Hangul,
+    Thai,
+    Other,
+}
+
+impl Script {
+    /// Get the script of the given char, or none if it isn't part of a word.
+    fn of(c: char) -> Option<Self> {
+        let script = match c as u32 {
+            0x3005 | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => Self::Han,
+            0x20000..=0x3FFFF => Self::Han,
+            0x3040..=0x309F => Self::Hiragana,
+            0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Self::Katakana,
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Self::Hangul,
+            // Includes the vowel and tone marks, which aren't alphanumeric.
+            0x0E01..=0x0E3A | 0x0E40..=0x0E4E | 0x0E50..=0x0E59 => Self::Thai,
+            _ if c.is_alphanumeric() => Self::Other,
+            _ => return None,
+        };
+        Some(script)
+    }
+}
+
 /// The input buffer with cursor support.
 ///
 /// Example:
@@ -41,533 +453,4690 @@ pub type InputResponse = Option<StateChanged>;
 /// assert_eq!(input.cursor(), 11);
 /// assert_eq!(input.to_string(), "Hello World");
 /// ```
-#[derive(Default, Debug, Clone)]
+///
+/// The value is kept in a [`SmallString`] by default, see
+/// [`Input::from_storage`] for other storage.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Input {
-    value: String,
+pub struct Input<S = SmallString> {
+    value: S,
     cursor: usize,
+    placeholder: Option<String>,
+    default_value: Option<String>,
+    max_len: Option<usize>,
+    max_bytes: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    read_only: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    overwrite: bool,
+    /// The value at the last checkpoint, see [`Input::mark_clean`], copied on
+    /// the first change. `None` while the value is still the clean one.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clean: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    validator: Option<Validator>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    transform: Option<Transform>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    highlights: Vec<Range<usize>>,
+    mask: Option<char>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    revealed: bool,
+    selection_anchor: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    block_selection: bool,
+    /// Deleted text, not serialized as it may come from a masked value.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    registers: Registers,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_register: Option<char>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    multiline: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    newline_policy: NewlinePolicy,
+    last_edit: Option<InputRequest>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    viewport: Viewport,
+    sticky_col: Option<usize>,
+    max_lines: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_columns: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    undo: UndoHistory,
+    last_find: Option<InputRequest>,
+    search_query: Option<String>,
+    suggestion: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    spell_checker: Option<SharedSpellChecker>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    word_boundaries: WordBoundaries,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    segmenter: Option<Segmenter>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    request_log: Option<RequestLog>,
+    /// The edits made to the value while mirroring them, see
+    /// [`Input::handle_with_buffer`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    journal: Option<Vec<Edit>>,
+    /// Cached column of the cursor, computed on demand when unknown.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    visual_cursor: Option<usize>,
 }
 
 impl Input {
     /// Initialize a new instance with a given value
     /// Cursor will be set to the given value's length.
     pub fn new(value: String) -> Self {
-        let len = value.chars().count();
-        Self { value, cursor: len }
+        Self::from_storage(value.into())
+    }
+}
+
+impl<S: Storage> Input<S> {
+    /// Initialize a new instance keeping the value in the given storage, e.g.
+    /// `String`, or `Cow<str>` to borrow a read-mostly value until it's edited.
+    /// Cursor will be set to the value's length.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::from_storage(Cow::Borrowed("Hello"));
+    /// assert!(matches!(input.storage(), Cow::Borrowed(_)));
+    ///
+    /// input.handle(InputRequest::InsertChar('!'));
+    /// assert_eq!(input.storage(), "Hello!");
+    /// ```
+    pub fn from_storage(value: S) -> Self {
+        Self {
+            cursor: value.char_count(),
+            clean: None,
+            value,
+            placeholder: None,
+            default_value: None,
+            max_len: None,
+            max_bytes: None,
+            read_only: false,
+            overwrite: false,
+            validator: None,
+            transform: None,
+            highlights: Vec::new(),
+            mask: None,
+            revealed: false,
+            selection_anchor: None,
+            block_selection: false,
+            registers: Default::default(),
+            pending_register: None,
+            multiline: false,
+            newline_policy: Default::default(),
+            last_edit: None,
+            viewport: Default::default(),
+            sticky_col: None,
+            max_lines: None,
+            max_columns: Vec::new(),
+            undo: Default::default(),
+            last_find: None,
+            search_query: None,
+            suggestion: None,
+            spell_checker: None,
+            word_boundaries: Default::default(),
+            segmenter: None,
+            request_log: None,
+            journal: None,
+            visual_cursor: None,
+        }
+    }
+
+    /// Get the storage of the value.
+    pub fn storage(&self) -> &S {
+        &self.value
     }
 
     /// Set the value manually.
     /// Cursor will be set to the given value's length.
     pub fn with_value(mut self, value: String) -> Self {
         self.cursor = value.chars().count();
-        self.value = value;
+        self.clean = None;
+        self.value = value.into();
+        self.refresh_visual_cursor();
         self
     }
 
+    /// Replace the value as an edit, keeping the clean checkpoint unlike
+    /// [`Input::with_value`]. The cursor is clamped to the new value.
+    pub(crate) fn replace_value(&mut self, value: String, cursor: usize) {
+        self.set_value(value);
+        self.cursor = cursor.min(self.char_len());
+        self.clear_selection();
+        self.refresh_visual_cursor();
+    }
+
     /// Set the cursor manually.
     /// If the input is larger than the value length, it'll be auto adjusted.
     pub fn with_cursor(mut self, cursor: usize) -> Self {
-        self.cursor = cursor.min(self.value.chars().count());
+        self.cursor = cursor.min(self.char_len());
+        self.refresh_visual_cursor();
         self
     }
 
-    // Reset the cursor and value to default
-    pub fn reset(&mut self) {
-        self.cursor = Default::default();
-        self.value = Default::default();
+    /// Check if the value changed since the input was created with it, or since
+    /// the last [`Input::mark_clean`], e.g. to warn about unsaved changes.
+    /// Changing the value back makes it clean again.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "draft".into();
+    /// assert!(!input.is_modified());
+    ///
+    /// input.handle(InputRequest::InsertChar('!'));
+    /// assert!(input.is_modified());
+    ///
+    /// input.mark_clean();
+    /// assert!(!input.is_modified());
+    /// ```
+    pub fn is_modified(&self) -> bool {
+        self.clean
+            .as_deref()
+            .is_some_and(|clean| clean != &*self.value)
     }
 
-    /// Handle request and emit response.
-    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+    /// Mark the current value as clean, e.g. after saving it.
+    pub fn mark_clean(&mut self) {
+        self.clean = None;
+    }
+
+    /// Keep a copy of the clean value before it's changed for the first time.
+    fn keep_clean(&mut self) {
+        if self.clean.is_none() {
+            self.clean = Some(self.value.to_string());
+        }
+    }
+
+    /// Set the placeholder text, shown by the renderers when the value is empty.
+    pub fn with_placeholder(mut self, placeholder: String) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Set the value submitted when the value is empty, shown by the renderers
+    /// (e.g. as `[World]`) until something is typed.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let mut input = Input::default().with_default_value("World".into());
+    /// assert_eq!(input.value_and_reset(), "World");
+    ///
+    /// input.insert_char('!');
+    /// assert_eq!(input.value_and_reset(), "!");
+    /// ```
+    pub fn with_default_value(mut self, default_value: String) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    /// Set the max length of the value in chars.
+    /// Inserting chars beyond it will be rejected, but the value set manually
+    /// won't be truncated.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Set the max length of the value in UTF-8 bytes, e.g. for protocols
+    /// limiting the line length. Inserting chars beyond it will be rejected,
+    /// and pasted text will be cut at the last char that fits, but the value
+    /// set manually won't be truncated.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::from("ab").with_max_bytes(4);
+    /// assert_eq!(input.handle(InputRequest::InsertChar('é')).is_some(), true);
+    /// assert_eq!(input.handle(InputRequest::InsertChar('é')), None);
+    /// assert_eq!(input.value(), "abé");
+    /// ```
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Reject the requests changing the value, while still allowing to move the
+    /// cursor, select and copy.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::from("ab").with_read_only(true);
+    /// assert_eq!(input.handle(InputRequest::DeletePrevChar), None);
+    /// assert!(input.handle(InputRequest::GoToStart).is_some());
+    /// ```
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Check if the input is read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Start in overwrite mode, see [`InputRequest::ToggleOverwrite`].
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Check if typed chars replace the ones under the cursor, e.g. to render
+    /// a block cursor instead of a bar.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::from("abc").with_cursor(1);
+    /// input.handle(InputRequest::ToggleOverwrite);
+    /// assert!(input.is_overwrite());
+    ///
+    /// input.handle(InputRequest::InsertChar('x'));
+    /// input.handle(InputRequest::InsertChar('y'));
+    /// input.handle(InputRequest::InsertChar('z'));
+    /// assert_eq!(input.value(), "axyz");
+    /// ```
+    pub fn is_overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Check if adding the given number of bytes would exceed the max bytes.
+    fn exceeds_max_bytes(&self, added: usize) -> bool {
+        self.max_bytes
+            .is_some_and(|max| self.value.len() + added > max)
+    }
+
+    /// Get the length in bytes of the longest prefix of the text that can
+    /// replace the given char range within the max length and the max bytes.
+    fn fitting_len(&self, text: &str, replaced: Range<usize>) -> usize {
+        let len = self.char_len() - replaced.len();
+        let room = self.max_len.map_or(usize::MAX, |max| max.max(len) - len);
+        let bytes = self.value.len() - self.slice(replaced).len();
+        let byte_room = self
+            .max_bytes
+            .map_or(usize::MAX, |max| max.max(bytes) - bytes);
+        text.char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .take(room)
+            .take_while(|end| *end <= byte_room)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// Set what a single-line input does with newlines, dropping them by
+    /// default.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest, NewlinePolicy};
+    ///
+    /// let mut input = Input::from("a").with_newline_policy(NewlinePolicy::Space);
+    /// input.handle(InputRequest::InsertNewline);
+    /// assert_eq!(input.value(), "a ");
+    ///
+    /// let mut input = Input::from("a").with_newline_policy(NewlinePolicy::Submit);
+    /// let resp = input.handle_detailed(InputRequest::InsertChar('\n'));
+    /// assert_eq!(resp.submitted, Some("a".into()));
+    /// ```
+    pub fn with_newline_policy(mut self, policy: NewlinePolicy) -> Self {
+        self.newline_policy = policy;
+        self
+    }
+
+    /// Get what a single-line input does with newlines.
+    pub fn newline_policy(&self) -> NewlinePolicy {
+        self.newline_policy
+    }
+
+    /// Check if the request submits the value: [`InputRequest::Submit`], or a
+    /// newline with [`NewlinePolicy::Submit`].
+    pub fn submits(&self, req: InputRequest) -> bool {
+        matches!(self.resolve_newline(req), Some(InputRequest::Submit))
+    }
+
+    /// Apply the newline policy of single-line inputs to the request, returning
+    /// `None` if it's dropped.
+    fn resolve_newline(&self, req: InputRequest) -> Option<InputRequest> {
         use InputRequest::*;
         match req {
-            SetCursor(pos) => {
-                let pos = pos.min(self.value.chars().count());
-                if self.cursor == pos {
-                    None
-                } else {
-                    self.cursor = pos;
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
+            InsertNewline | InsertChar('\n') if !self.multiline => {
+                match self.newline_policy {
+                    NewlinePolicy::Drop => None,
+                    NewlinePolicy::Space => Some(InsertChar(' ')),
+                    NewlinePolicy::Submit => Some(Submit),
                 }
             }
-            InsertChar(c) => {
-                if self.cursor == self.value.chars().count() {
-                    self.value.push(c);
-                } else {
-                    self.value = self
-                        .value
-                        .chars()
-                        .take(self.cursor)
-                        .chain(
-                            std::iter::once(c)
-                                .chain(self.value.chars().skip(self.cursor)),
-                        )
-                        .collect();
-                }
-                self.cursor += 1;
-                Some(StateChanged {
-                    value: true,
-                    cursor: true,
-                })
-            }
+            req => Some(req),
+        }
+    }
 
-            DeletePrevChar => {
-                if self.cursor == 0 {
-                    None
-                } else {
-                    self.cursor -= 1;
-                    self.value = self
-                        .value
-                        .chars()
-                        .enumerate()
-                        .filter(|(i, _)| i != &self.cursor)
-                        .map(|(_, c)| c)
-                        .collect();
+    /// Set the max number of lines of a multi-line value.
+    /// Inserting newlines beyond it will be rejected.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
 
-                    Some(StateChanged {
-                        value: true,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Set the max length in chars of each line, the last one applying to the
+    /// remaining lines. Inserting chars beyond it will be rejected.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// // Commit message style: 50 columns subject, 72 columns body.
+    /// let mut input = Input::default()
+    ///     .with_multiline(true)
+    ///     .with_max_columns(vec![50, 72]);
+    ///
+    /// assert_eq!(input.max_columns(0), Some(50));
+    /// assert_eq!(input.max_columns(5), Some(72));
+    /// ```
+    pub fn with_max_columns(mut self, max_columns: Vec<usize>) -> Self {
+        self.max_columns = max_columns;
+        self
+    }
 
-            DeleteNextChar => {
-                if self.cursor == self.value.chars().count() {
-                    None
-                } else {
-                    self.value = self
-                        .value
-                        .chars()
-                        .enumerate()
-                        .filter(|(i, _)| i != &self.cursor)
-                        .map(|(_, c)| c)
-                        .collect();
-                    Some(StateChanged {
-                        value: true,
-                        cursor: false,
-                    })
-                }
-            }
+    /// Set the rules grouping consecutive typing into a single undo step.
+    pub fn with_undo_coalescing(mut self, coalescing: Coalescing) -> Self {
+        self.undo = UndoHistory::new(coalescing);
+        self
+    }
 
-            GoToPrevChar => {
-                if self.cursor == 0 {
-                    None
-                } else {
-                    self.cursor -= 1;
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Set how the value is split into words.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest, WordBoundaries};
+    ///
+    /// let mut input = Input::from("can't stop").with_word_boundaries(WordBoundaries::Unicode);
+    /// input.handle(InputRequest::GoToPrevWord);
+    /// input.handle(InputRequest::GoToPrevWord);
+    /// assert_eq!(input.cursor(), 0);
+    /// ```
+    pub fn with_word_boundaries(mut self, word_boundaries: WordBoundaries) -> Self {
+        self.word_boundaries = word_boundaries;
+        self
+    }
 
-            GoToPrevWord => {
-                if self.cursor == 0 {
-                    None
-                } else {
-                    self.cursor = self
-                        .value
-                        .chars()
-                        .rev()
-                        .skip(self.value.chars().count().max(self.cursor) - self.cursor)
-                        .skip_while(|c| !c.is_alphanumeric())
-                        .skip_while(|c| c.is_alphanumeric())
-                        .count();
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Set a custom function splitting the value into words, taking precedence
+    /// over the [`WordBoundaries`].
+    pub fn with_segmenter(mut self, segmenter: Segmenter) -> Self {
+        self.segmenter = Some(segmenter);
+        self
+    }
 
-            GoToNextChar => {
-                if self.cursor == self.value.chars().count() {
-                    None
-                } else {
-                    self.cursor += 1;
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Get how the value is split into words.
+    pub fn word_boundaries(&self) -> WordBoundaries {
+        self.word_boundaries
+    }
 
-            GoToNextWord => {
-                if self.cursor == self.value.chars().count() {
-                    None
-                } else {
-                    self.cursor = self
-                        .value
-                        .chars()
-                        .enumerate()
-                        .skip(self.cursor)
-                        .skip_while(|(_, c)| c.is_alphanumeric())
-                        .find(|(_, c)| c.is_alphanumeric())
-                        .map(|(i, _)| i)
-                        .unwrap_or_else(|| self.value.chars().count());
+    /// Record the handled requests in the given log.
+    pub fn with_request_log(mut self, log: RequestLog) -> Self {
+        self.request_log = Some(log);
+        self
+    }
 
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Get the log of the handled requests, if any.
+    pub fn request_log(&self) -> Option<&RequestLog> {
+        self.request_log.as_ref()
+    }
 
-            DeleteLine => {
-                if self.value.is_empty() {
-                    None
-                } else {
-                    let cursor = self.cursor;
-                    self.value = "".into();
-                    self.cursor = 0;
-                    Some(StateChanged {
-                        value: true,
-                        cursor: self.cursor == cursor,
-                    })
-                }
-            }
+    /// Get the log of the handled requests mutably, e.g. to clear it.
+    pub fn request_log_mut(&mut self) -> Option<&mut RequestLog> {
+        self.request_log.as_mut()
+    }
 
-            DeletePrevWord => {
-                if self.cursor == 0 {
-                    None
-                } else {
-                    let remaining = self.value.chars().skip(self.cursor);
-                    let rev = self
-                        .value
-                        .chars()
-                        .rev()
-                        .skip(self.value.chars().count().max(self.cursor) - self.cursor)
-                        .skip_while(|c| !c.is_alphanumeric())
-                        .skip_while(|c| c.is_alphanumeric())
-                        .collect::<Vec<char>>();
-                    let rev_len = rev.len();
-                    self.value = rev.into_iter().rev().chain(remaining).collect();
-                    self.cursor = rev_len;
-                    Some(StateChanged {
-                        value: true,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Set the validator used to check the value.
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
 
-            DeleteNextWord => {
-                if self.cursor == self.value.chars().count() {
-                    None
-                } else {
-                    self.value = self
-                        .value
-                        .chars()
-                        .take(self.cursor)
-                        .chain(
-                            self.value
-                                .chars()
-                                .skip(self.cursor)
-                                .skip_while(|c| c.is_alphanumeric())
-                                .skip_while(|c| !c.is_alphanumeric()),
-                        )
-                        .collect();
+    /// Set the transform canonicalizing the value as it's edited, e.g. to
+    /// uppercase it. The value set manually won't be transformed.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
 
-                    Some(StateChanged {
-                        value: true,
-                        cursor: false,
-                    })
-                }
-            }
+    /// Allow the value to span multiple lines, separated by `\n`.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
 
-            GoToStart => {
-                if self.cursor == 0 {
-                    None
-                } else {
-                    self.cursor = 0;
-                    Some(StateChanged {
-                        value: false,
-                        cursor: true,
-                    })
-                }
-            }
+    /// Set the char used to mask the value when rendering, e.g. for passwords.
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self.refresh_visual_cursor();
+        self
+    }
 
-            GoToEnd => {
-                let count = self.value.chars().count();
-                if self.cursor == count {
-                    None
+    /// Mask the value again if it was revealed with [`InputRequest::ToggleMask`].
+    /// Call it when the input loses focus.
+    pub fn blur(&mut self) {
+        self.revealed = false;
+        self.refresh_visual_cursor();
+    }
+
+    /// Set the char ranges to highlight, e.g. the matches of a search.
+    /// The ranges are not adjusted when the value changes.
+    pub fn set_highlights(&mut self, mut highlights: Vec<Range<usize>>) {
+        highlights.sort_by_key(|r| (r.start, r.end));
+        self.highlights = highlights;
+    }
+
+    // Reset the cursor and value to default
+    pub fn reset(&mut self) {
+        self.cursor = Default::default();
+        self.replace_range(0..self.char_len(), "");
+        self.visual_cursor = Some(0);
+        self.clear_selection();
+    }
+
+    /// Select the chars between the given anchor and cursor positions.
+    /// The cursor will be moved to the given position.
+    pub fn set_selection(&mut self, anchor: usize, cursor: usize) {
+        self.select(anchor, cursor);
+        self.refresh_visual_cursor();
+    }
+
+    /// Select the chars between the given positions, leaving the visual cursor
+    /// to be tracked by the request handler.
+    fn select(&mut self, anchor: usize, cursor: usize) {
+        let len = self.char_len();
+        self.selection_anchor = Some(anchor.min(len));
+        self.block_selection = false;
+        self.cursor = cursor.min(len);
+    }
+
+    /// Clear the selection, keeping the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+        self.block_selection = false;
+    }
+
+    /// Set the text pasted by [`InputRequest::PasteOverSelection`], e.g. from
+    /// the system clipboard.
+    pub fn set_clipboard(&mut self, text: String) {
+        self.registers.write(None, text);
+    }
+
+    /// Replace the given char range of the value with the given text.
+    /// The cursor is left unchanged.
+    pub(crate) fn replace_range(&mut self, range: Range<usize>, text: &str) {
+        if range.is_empty() && text.is_empty() {
+            return;
+        }
+        self.keep_clean();
+        if let Some(journal) = &mut self.journal {
+            journal.push(Edit {
+                start: range.start,
+                removed: range.len(),
+                inserted: text.into(),
+            });
+        }
+        self.value.remove_range(range.clone());
+        self.value.insert_at(range.start, text);
+    }
+
+    /// Replace the whole value, e.g. to restore a snapshot.
+    fn set_value(&mut self, value: String) {
+        self.keep_clean();
+        if let Some(journal) = &mut self.journal {
+            journal.push(Edit {
+                start: 0,
+                removed: self.value.char_count(),
+                inserted: value.clone(),
+            });
+        }
+        self.value = value.into();
+    }
+
+    /// Remove the chars in the given range, saving them in the selected
+    /// register. Empty ranges leave the register alone.
+    fn kill(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let text = self.slice(range.clone()).into();
+        self.registers.write(self.pending_register, text);
+        self.replace_range(range, "");
+    }
+
+    /// Get the char under the cursor that typing the given char replaces in
+    /// overwrite mode. Newlines are always inserted, and never replaced.
+    fn overwritten_char(&self, c: char) -> Option<char> {
+        if !self.overwrite || self.block_selection || self.selection().is_some() {
+            return None;
+        }
+        let current = self.value.chars().nth(self.cursor)?;
+        (c != '\n' && current != '\n').then_some(current)
+    }
+
+    /// Replace the char under the cursor with the given one and move past it,
+    /// as typed in overwrite mode.
+    fn overwrite_char(&mut self, c: char) -> InputResponse {
+        let current = self.overwritten_char(c)?;
+        if self.exceeds_max_bytes(c.len_utf8().saturating_sub(current.len_utf8())) {
+            return None;
+        }
+        self.replace_range(self.cursor..self.cursor + 1, c.encode_utf8(&mut [0; 4]));
+        self.cursor += 1;
+        Some(StateChanged {
+            value: true,
+            cursor: true,
+        })
+    }
+
+    /// Iterate over the char indexes of the starts of the words, or sub-words.
+    fn word_starts(&self, sub_words: bool) -> Box<dyn Iterator<Item = usize> + '_> {
+        match sub_words {
+            true => Box::new(self.sub_words().map(|w| w.start)),
+            false => Box::new(self.words().map(|w| w.start)),
+        }
+    }
+
+    /// Get the char index of the start of the next word after the cursor.
+    fn next_word_start(&self, sub_words: bool) -> usize {
+        self.word_starts(sub_words)
+            .find(|&start| start > self.cursor)
+            .unwrap_or_else(|| self.char_len())
+    }
+
+    /// Get the char index of the start of the word before the cursor.
+    fn prev_word_start(&self, sub_words: bool) -> usize {
+        self.word_starts(sub_words)
+            .take_while(|&start| start < self.cursor)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// Take the value and reset the input, through [`InputRequest::Submit`].
+    /// Read-only inputs keep their value.
+    pub fn value_and_reset(&mut self) -> String {
+        self.handle_detailed(InputRequest::Submit)
+            .submitted
+            .unwrap_or_default()
+    }
+
+    /// Handle request and emit a detailed response.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "Hello".into();
+    /// let resp = input.handle_detailed(InputRequest::Submit);
+    ///
+    /// assert_eq!(resp.submitted, Some("Hello".into()));
+    /// assert_eq!(input.value(), "");
+    /// ```
+    pub fn handle_detailed(&mut self, req: InputRequest) -> DetailedResponse {
+        let submitted = self.submits(req).then(|| match &self.default_value {
+            Some(default) if self.value.is_empty() => default.clone(),
+            _ => self.value.to_string(),
+        });
+        let (state, edits) = self.journaled(|input| input.handle(req));
+        DetailedResponse {
+            state,
+            edit: self.combined_edit(&edits),
+            submitted,
+        }
+    }
+
+    /// Run the function, returning the edits it made to the value along with
+    /// its result. Nested calls also journal the edits for the outer ones.
+    fn journaled<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> (T, Vec<Edit>) {
+        let outer = self.journal.replace(Vec::new());
+        let out = f(self);
+        let edits = std::mem::replace(&mut self.journal, outer).unwrap_or_default();
+        if let Some(outer) = &mut self.journal {
+            outer.extend(edits.iter().cloned());
+        }
+        (out, edits)
+    }
+
+    /// Combine the consecutive edits into the single edit covering them all,
+    /// taking the inserted text from the current value.
+    fn combined_edit(&self, edits: &[Edit]) -> Option<Edit> {
+        let first = edits.first()?;
+        // The covered range, ending at `old_end` in the original value and at
+        // `new_end` in the edited one.
+        let mut start = first.start;
+        let (mut old_end, mut new_end) = (start, start);
+        for edit in edits {
+            let end = edit.start + edit.removed;
+            if end > new_end {
+                old_end += end - new_end;
+                new_end = end;
+            }
+            start = start.min(edit.start);
+            new_end = new_end + edit.inserted.chars().count() - edit.removed;
+        }
+        Some(Edit {
+            start,
+            removed: old_end - start,
+            inserted: self.slice(start..new_end).into(),
+        })
+    }
+
+    /// Handle request and mirror the change of the value to the linked input,
+    /// e.g. the same field shown in another pane. Only the changed chars are
+    /// applied, see [`Input::apply_edit`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut left: Input = "Hello".into();
+    /// let mut right = left.clone().with_cursor(0);
+    ///
+    /// left.handle_linked(InputRequest::InsertChar('!'), &mut right);
+    /// assert_eq!(right.value(), "Hello!");
+    /// assert_eq!(right.cursor(), 0);
+    /// ```
+    pub fn handle_linked<L: Storage>(
+        &mut self,
+        req: InputRequest,
+        linked: &mut Input<L>,
+    ) -> InputResponse {
+        let resp = self.handle_detailed(req);
+        if let Some(edit) = &resp.edit {
+            linked.apply_edit(edit);
+        }
+        resp.state
+    }
+
+    /// Apply a change of the value made to another input, keeping the cursor
+    /// on the same char. The selection is cleared.
+    ///
+    /// Returns `None` if the edit doesn't fit the value.
+    pub fn apply_edit(&mut self, edit: &Edit) -> InputResponse {
+        let end = edit.start + edit.removed;
+        if end > self.char_len() {
+            return None;
+        }
+        self.replace_range(edit.start..end, &edit.inserted);
+        let old_cursor = self.cursor;
+        let inserted = edit.inserted.chars().count();
+        if self.cursor >= end {
+            self.cursor = self.cursor - edit.removed + inserted;
+        } else if self.cursor > edit.start {
+            self.cursor = self.cursor.min(edit.start + inserted);
+        }
+        self.clear_selection();
+        self.suggestion = None;
+        if self.search_query.is_some() {
+            self.set_search_query(self.search_query.clone());
+        }
+        self.refresh_visual_cursor();
+        Some(StateChanged {
+            value: true,
+            cursor: self.cursor != old_cursor,
+        })
+    }
+
+    /// Handle request and apply the changes of the value to the given buffer,
+    /// which is expected to mirror the value, e.g. a rope or a gap buffer.
+    ///
+    /// The buffer gets the same insertions and removals as the value, so
+    /// mirroring a keystroke costs as much as the keystroke itself. Whole value
+    /// changes, like undo, replace the whole buffer.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut buffer = String::from("Hello");
+    /// let mut input = Input::new(buffer.clone());
+    ///
+    /// input.handle_with_buffer(InputRequest::DeletePrevChar, &mut buffer);
+    /// assert_eq!(buffer, "Hell");
+    /// ```
+    pub fn handle_with_buffer<B: InputBuffer>(
+        &mut self,
+        req: InputRequest,
+        buffer: &mut B,
+    ) -> InputResponse {
+        let (resp, edits) = self.journaled(|input| input.handle(req));
+        for edit in &edits {
+            buffer.apply(edit);
+        }
+        resp
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+        let resp = self.handle_request_and_track(req);
+        if let Some(log) = &mut self.request_log {
+            log.record(req, resp);
+        }
+        resp
+    }
+
+    /// Handle the requests in order, e.g. the ones of a [`RequestLog`] attached
+    /// to a bug report, to reproduce the exact final state.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::default();
+    /// let requests = [InputRequest::InsertChar('b'), InputRequest::GoToStart];
+    /// input.replay(requests.into_iter().chain([InputRequest::InsertChar('a')]));
+    ///
+    /// assert_eq!(input.value(), "ab");
+    /// assert_eq!(input.cursor(), 1);
+    /// ```
+    pub fn replay<I>(&mut self, requests: I) -> InputResponse
+    where
+        I: IntoIterator<Item = InputRequest>,
+    {
+        requests
+            .into_iter()
+            .fold(None, |resp, req| merge_responses(resp, self.handle(req)))
+    }
+
+    /// Handle the requests as a single edit, undone at once, e.g. the requests
+    /// of a paste event or of a macro.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "a".into();
+    /// let requests = [InputRequest::InsertChar('b'), InputRequest::InsertChar(' ')];
+    /// input.handle_all(requests.into_iter().chain([InputRequest::InsertChar('c')]));
+    /// assert_eq!(input.value(), "ab c");
+    ///
+    /// input.handle(InputRequest::Undo);
+    /// assert_eq!(input.value(), "a");
+    /// ```
+    pub fn handle_all<I>(&mut self, requests: I) -> InputResponse
+    where
+        I: IntoIterator<Item = InputRequest>,
+    {
+        let before = self.snapshot();
+        let coalescing = self.undo.coalescing();
+        let undo = std::mem::replace(&mut self.undo, UndoHistory::new(coalescing));
+        let resp = self.replay(requests);
+        self.undo = undo;
+        if before.value != *self.value {
+            self.undo.record(before, EditKind::Other);
+        }
+        resp
+    }
+
+    /// Handle the request the given number of times, e.g. for a burst of key
+    /// repeats, stopping as soon as it does nothing.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "Hello".into();
+    /// assert!(input.handle_repeated(InputRequest::DeletePrevChar, 1000).is_some());
+    /// assert_eq!(input.value(), "");
+    /// ```
+    pub fn handle_repeated(&mut self, req: InputRequest, count: usize) -> InputResponse {
+        let mut resp = None;
+        for _ in 0..count {
+            match self.handle(req) {
+                None => break,
+                r => resp = merge_responses(resp, r),
+            }
+        }
+        resp
+    }
+
+    /// Handle request like [`Input::handle`], but tell apart the requests doing
+    /// nothing from the ones refused by a constraint, e.g. to beep.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputError, InputRequest};
+    ///
+    /// let mut input = Input::from("ab").with_max_len(2);
+    /// assert_eq!(input.try_handle(InputRequest::InsertChar('c')), Err(InputError::MaxLen));
+    /// assert_eq!(input.try_handle(InputRequest::GoToEnd), Ok(None));
+    /// ```
+    pub fn try_handle(
+        &mut self,
+        req: InputRequest,
+    ) -> Result<InputResponse, InputError> {
+        let error = self.rejection(req);
+        match self.handle(req) {
+            None => error.map_or(Ok(None), Err),
+            resp => Ok(resp),
+        }
+    }
+
+    /// Get the constraint refusing the request, if it's refused.
+    fn rejection(&self, req: InputRequest) -> Option<InputError> {
+        use InputRequest::*;
+        if self.read_only && (is_edit(req) || matches!(req, Undo | Redo)) {
+            return Some(InputError::ReadOnly);
+        }
+        if let Some(selection) = self.selection() {
+            if let req @ (InsertChar(_) | InsertNewline | DeletePrevChar
+            | DeleteNextChar) = self.resolve_newline(req)?
+            {
+                return self.selection_rejection(selection, req);
+            }
+        }
+        let joined = match req {
+            DeletePrevChar => self.cursor.checked_sub(1),
+            DeleteNextChar => Some(self.cursor),
+            _ => None,
+        };
+        if let Some(index) = joined {
+            return self
+                .joins_past_line_limits(index)
+                .then_some(InputError::LineLimits);
+        }
+        let (c, replaced) = match self.resolve_newline(req)? {
+            InsertChar(c) => (c, self.overwritten_char(c)),
+            InsertNewline => ('\n', None),
+            ReplaceChar('\n') if !self.multiline => return None,
+            ReplaceChar(c) => (c, Some(self.value.chars().nth(self.cursor)?)),
+            _ => return None,
+        };
+        let len = self.char_len();
+        let added = c
+            .len_utf8()
+            .saturating_sub(replaced.map_or(0, char::len_utf8));
+        if replaced.is_none() && self.max_len.is_some_and(|max| len >= max) {
+            return Some(InputError::MaxLen);
+        }
+        if self.exceeds_max_bytes(added) {
+            return Some(InputError::MaxBytes);
+        }
+        let mut value = self.value.to_string();
+        let start = self.byte_index(self.cursor);
+        let end = start + replaced.map_or(0, char::len_utf8);
+        value.replace_range(start..end, c.encode_utf8(&mut [0; 4]));
+        (!self.fits_line_limits(&value)).then_some(InputError::LineLimits)
+    }
+
+    /// Get the constraint refusing to replace the selection for the given
+    /// insert or delete request, checked before the selection is removed.
+    fn selection_rejection(
+        &self,
+        selection: Range<usize>,
+        req: InputRequest,
+    ) -> Option<InputError> {
+        use InputRequest::*;
+        let inserted = match req {
+            InsertChar(c) => Some(c),
+            InsertNewline => Some('\n'),
+            _ => None,
+        };
+        if let Some(c) = inserted {
+            let len = self.char_len() - selection.len() + 1;
+            if self.max_len.is_some_and(|max| len > max) {
+                return Some(InputError::MaxLen);
+            }
+            let bytes =
+                self.value.len() - self.slice(selection.clone()).len() + c.len_utf8();
+            if self.max_bytes.is_some_and(|max| bytes > max) {
+                return Some(InputError::MaxBytes);
+            }
+        }
+        // Removing a newline joins two lines into one.
+        let joined = self.slice(selection.clone()).contains('\n');
+        if inserted.is_none() && !joined {
+            return None;
+        }
+        let mut value = self.value.to_string();
+        let range = self.byte_index(selection.start)..self.byte_index(selection.end);
+        value.replace_range(range, &inserted.map(String::from).unwrap_or_default());
+        (!self.fits_line_limits(&value)).then_some(InputError::LineLimits)
+    }
+
+    /// Handle request, keeping track of the repeat, undo, search and scroll
+    /// state.
+    fn handle_request_and_track(&mut self, req: InputRequest) -> InputResponse {
+        use InputRequest::*;
+
+        let at_end = self.cursor == self.char_len();
+        let req = match self.resolve_newline(req)? {
+            RepeatLastEdit => self.last_edit?,
+            GoToNextChar | GoToEnd if at_end && self.suggestion.is_some() => {
+                AcceptSuggestion
+            }
+            GoToNextWord if at_end && self.suggestion.is_some() => AcceptSuggestionWord,
+            req => req,
+        };
+        if self.read_only && (is_edit(req) || matches!(req, Undo | Redo)) {
+            return None;
+        }
+
+        if !matches!(req, GoToPrevLine | GoToNextLine) {
+            self.sticky_col = None;
+        }
+
+        let old_cursor = self.cursor;
+        // Submitting is undone like an edit, so the taken value can be restored.
+        let before = (is_edit(req) || req == Submit).then(|| self.snapshot());
+        let resp = match req {
+            Undo if self.undo.can_undo() => {
+                let snapshot = self.undo.undo(self.snapshot());
+                self.restore(snapshot)
+            }
+            Redo if self.undo.can_redo() => {
+                let snapshot = self.undo.redo(self.snapshot());
+                self.restore(snapshot)
+            }
+            Undo | Redo => None,
+            req => self.dispatch(req),
+        };
+
+        // Edits that no longer fit the limits once transformed are undone.
+        let resp = match (resp, &before) {
+            (Some(r), Some(before)) if r.value && !self.apply_transform() => {
+                self.restore(Some(before.clone()));
+                None
+            }
+            (Some(r), None) if r.value => {
+                self.apply_transform();
+                resp
+            }
+            (resp, _) => resp,
+        };
+
+        if resp.is_some_and(|r| r.value) && !matches!(req, Submit | Undo | Redo) {
+            self.last_edit = Some(req);
+        }
+
+        match before {
+            Some(before) if before.value != *self.value => {
+                self.undo.record(before, edit_kind(req));
+            }
+            Some(_) => {}
+            None if resp.is_some_and(|r| r.cursor) => self.undo.break_group(),
+            None => {}
+        }
+
+        if resp.is_some_and(|r| r.value)
+            && !matches!(req, AcceptSuggestion | AcceptSuggestionWord)
+        {
+            self.update_suggestion(req);
+        }
+
+        if resp.is_some_and(|r| r.value) && self.search_query.is_some() {
+            self.set_search_query(self.search_query.clone());
+        }
+
+        if resp.is_some() {
+            let repaint = matches!(req, ToggleMask | Submit);
+            self.track_visual_cursor(
+                old_cursor,
+                repaint || resp.is_some_and(|r| r.value),
+            );
+            self.scroll_to_cursor();
+        }
+        resp
+    }
+
+    /// Canonicalize the value with the transform, if any, moving the cursor
+    /// to the end of the transformed text before it.
+    ///
+    /// Returns `false`, leaving the value alone, if the transformed value no
+    /// longer fits the limits.
+    fn apply_transform(&mut self) -> bool {
+        let Some(transform) = &self.transform else {
+            return true;
+        };
+        let value = transform.apply(&self.value);
+        if value == *self.value {
+            return true;
+        }
+        if !self.fits_limits(&value) && self.fits_limits(&self.value) {
+            return false;
+        }
+        let before_cursor = &self.value[..self.byte_index(self.cursor)];
+        let len = value.chars().count();
+        self.cursor = transform.apply(before_cursor).chars().count().min(len);
+        self.selection_anchor = self.selection_anchor.map(|a| a.min(len));
+        self.set_value(value);
+        true
+    }
+
+    /// Search the value, highlighting the matches. The search is case
+    /// insensitive unless the query has uppercase chars. The matches are kept
+    /// up to date as the value changes, until the query is cleared with `None`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "Foo bar foo".into();
+    /// input.set_search_query(Some("foo".into()));
+    /// assert_eq!(input.search_matches(), &[0..3, 8..11]);
+    ///
+    /// input.handle(InputRequest::GoToNextMatch);
+    /// assert_eq!(input.cursor(), 0);
+    /// ```
+    pub fn set_search_query(&mut self, query: Option<String>) {
+        self.highlights = match query.as_deref() {
+            Some(query) if !query.is_empty() => find_matches(&self.value, query),
+            _ => Vec::new(),
+        };
+        self.search_query = query;
+    }
+
+    /// Set the spell checker flagging the misspelled words, which the renderers
+    /// style and [`InputRequest::GoToNextMisspelling`] jumps to. See
+    /// [`SpellChecker`].
+    pub fn with_spell_checker<C>(mut self, checker: C) -> Self
+    where
+        C: SpellChecker + Send + Sync + 'static,
+    {
+        self.spell_checker = Some(SharedSpellChecker(Arc::new(checker)));
+        self
+    }
+
+    /// Get the char ranges of the misspelled words, sorted by their start.
+    /// The spell checker is run on every call, but not while the value is
+    /// masked, so secrets are neither checked nor flagged when rendering.
+    pub fn misspellings(&self) -> Vec<Range<usize>> {
+        let Some(checker) = self.spell_checker.as_ref().filter(|_| !self.is_masked())
+        else {
+            return Vec::new();
+        };
+        let mut ranges = checker.0.misspellings(&self.value);
+        ranges.sort_by_key(|r| (r.start, r.end));
+        ranges
+    }
+
+    /// Get the search query, if any.
+    pub fn search_query(&self) -> Option<&str> {
+        self.search_query.as_deref()
+    }
+
+    /// Set the text suggested after the value, rendered as ghost text (e.g. the
+    /// rest of a history entry, see [`History::suggest`]). It's kept while
+    /// typing its next chars, and cleared by other changes of the value.
+    ///
+    /// At the end of the value, [`InputRequest::GoToNextChar`] and
+    /// [`InputRequest::GoToEnd`] accept the whole suggestion, and
+    /// [`InputRequest::GoToNextWord`] accepts its next word.
+    ///
+    /// [`History::suggest`]: crate::history::History::suggest
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "git".into();
+    /// input.set_suggestion(Some(" commit --amend".into()));
+    ///
+    /// input.handle(InputRequest::InsertChar(' '));
+    /// assert_eq!(input.suggestion(), Some("commit --amend"));
+    ///
+    /// input.handle(InputRequest::GoToNextWord);
+    /// assert_eq!(input.value(), "git commit");
+    /// assert_eq!(input.suggestion(), Some(" --amend"));
+    ///
+    /// input.handle(InputRequest::GoToEnd);
+    /// assert_eq!(input.value(), "git commit --amend");
+    /// assert_eq!(input.suggestion(), None);
+    /// ```
+    pub fn set_suggestion(&mut self, suggestion: Option<String>) {
+        self.suggestion = suggestion.filter(|s| !s.is_empty());
+    }
+
+    /// Get the text suggested after the value, if any.
+    pub fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+
+    /// Keep the rest of the suggestion if the request typed its next char,
+    /// clearing it otherwise.
+    fn update_suggestion(&mut self, req: InputRequest) {
+        let rest = match (req, self.suggestion.as_deref()) {
+            (InputRequest::InsertChar(c), Some(suggestion))
+                if self.cursor == self.char_len() =>
+            {
+                suggestion.strip_prefix(c).map(String::from)
+            }
+            _ => None,
+        };
+        self.set_suggestion(rest);
+    }
+
+    /// Get the char ranges matching the search query, which are also the
+    /// highlighted ranges.
+    pub fn search_matches(&self) -> &[Range<usize>] {
+        match self.search_query {
+            Some(_) => &self.highlights,
+            None => &[],
+        }
+    }
+
+    /// Get the undo history.
+    pub fn undo_history(&self) -> &UndoHistory {
+        &self.undo
+    }
+
+    /// Restore the state of the given node of the undo tree, see
+    /// [`UndoHistory::nodes`].
+    pub fn undo_to(&mut self, node: usize) -> InputResponse {
+        if self.undo.current() == Some(node) {
+            return None;
+        }
+        let snapshot = self.undo.go_to(node, self.snapshot());
+        let resp = self.restore(snapshot);
+        self.refresh_visual_cursor();
+        resp
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.to_string(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Option<Snapshot>) -> InputResponse {
+        let snapshot = snapshot?;
+        self.set_value(snapshot.value);
+        self.cursor = snapshot.cursor.min(self.char_len());
+        self.clear_selection();
+        Some(StateChanged {
+            value: true,
+            cursor: true,
+        })
+    }
+
+    /// Handle request, reading the clipboard before pasting and writing it
+    /// after copying, cutting or deleting words and lines.
+    ///
+    /// The registers are used as usual when a register is selected, or when
+    /// the clipboard can't be read.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::clipboard::MemoryClipboard;
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut clipboard = MemoryClipboard("World".into());
+    /// let mut input: Input = "Hello ".into();
+    ///
+    /// input.handle_with_clipboard(InputRequest::PasteOverSelection, &mut clipboard)?;
+    /// assert_eq!(input.value(), "Hello World");
+    ///
+    /// input.handle_with_clipboard(InputRequest::DeletePrevWord, &mut clipboard)?;
+    /// assert_eq!(clipboard.0, "World");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn handle_with_clipboard<C: ClipboardProvider>(
+        &mut self,
+        req: InputRequest,
+        clipboard: &mut C,
+    ) -> std::io::Result<InputResponse> {
+        use InputRequest::*;
+        let own_register = self.pending_register.is_some();
+        if req == PasteOverSelection && !own_register {
+            if let Some(text) = clipboard.get_text()? {
+                self.registers.write(None, text);
+            }
+        }
+        let resp = self.handle(req);
+        let copied = matches!(
+            req,
+            CopySelection
+                | CutSelection
+                | DeleteLine
+                | DeletePrevWord
+                | DeleteNextWord
+                | DeletePrevSubWord
+                | DeleteNextSubWord
+                | DeleteTillEnd
+        );
+        if copied && resp.is_some() && !own_register {
+            clipboard.set_text(self.clipboard())?;
+        }
+        Ok(resp)
+    }
+
+    /// Set the number of lines visible when rendering a multi-line input, and
+    /// scroll to keep the cursor visible. A height of 0 disables scrolling.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let mut input = Input::from("1\n2\n3\n4").with_multiline(true);
+    /// input.set_viewport_height(2);
+    ///
+    /// assert_eq!(input.viewport().top, 2);
+    /// assert_eq!(input.line_count(), 4);
+    /// ```
+    pub fn set_viewport_height(&mut self, height: usize) {
+        self.viewport.height = height;
+        self.scroll_to_cursor();
+    }
+
+    /// Scroll the viewport by the given number of lines, without moving the
+    /// cursor, e.g. on mouse wheel.
+    pub fn scroll_viewport(&mut self, lines: isize) {
+        let max = self
+            .line_count()
+            .saturating_sub(self.viewport.height.max(1));
+        self.viewport.top = self.viewport.top.saturating_add_signed(lines).min(max);
+    }
+
+    /// Get the viewport of a multi-line input, e.g. to render a scrollbar.
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    /// Scroll the viewport as little as possible to show the cursor line.
+    fn scroll_to_cursor(&mut self) {
+        let Viewport { top, height } = self.viewport;
+        if height == 0 {
+            return;
+        }
+        let line = self.cursor_line_col().0;
+        self.viewport.top = top.min(line).max(line + 1 - height.min(line + 1));
+    }
+
+    /// The last request that changed the value, repeated by
+    /// [`InputRequest::RepeatLastEdit`].
+    pub fn last_edit(&self) -> Option<InputRequest> {
+        self.last_edit
+    }
+
+    /// Insert the char at the cursor, like [`InputRequest::InsertChar`].
+    pub fn insert_char(&mut self, c: char) -> InputResponse {
+        self.handle(InputRequest::InsertChar(c))
+    }
+
+    /// Insert the text at the cursor, char by char, replacing the selection if
+    /// any. Newlines are inserted as [`InputRequest::InsertNewline`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let mut input: Input = "Hello".into();
+    /// input.insert_str_at_cursor(" World");
+    ///
+    /// assert_eq!(input.value(), "Hello World");
+    /// ```
+    pub fn insert_str_at_cursor(&mut self, text: &str) -> InputResponse {
+        text.chars().fold(None, |resp, c| {
+            let req = match c {
+                '\n' => InputRequest::InsertNewline,
+                c => InputRequest::InsertChar(c),
+            };
+            merge_responses(resp, self.handle(req))
+        })
+    }
+
+    /// Delete the char before the cursor, like [`InputRequest::DeletePrevChar`].
+    pub fn delete_char_before_cursor(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeletePrevChar)
+    }
+
+    /// Delete the char after the cursor, like [`InputRequest::DeleteNextChar`].
+    pub fn delete_char_after_cursor(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeleteNextChar)
+    }
+
+    /// Delete the word before the cursor, like [`InputRequest::DeletePrevWord`].
+    pub fn delete_word_before_cursor(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeletePrevWord)
+    }
+
+    /// Delete the word after the cursor, like [`InputRequest::DeleteNextWord`].
+    pub fn delete_word_after_cursor(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeleteNextWord)
+    }
+
+    /// Delete the whole line, like [`InputRequest::DeleteLine`].
+    pub fn delete_line(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeleteLine)
+    }
+
+    /// Delete from the cursor till the end, like [`InputRequest::DeleteTillEnd`].
+    pub fn delete_till_end(&mut self) -> InputResponse {
+        self.handle(InputRequest::DeleteTillEnd)
+    }
+
+    /// Move the cursor to the given char index, like [`InputRequest::SetCursor`].
+    pub fn set_cursor(&mut self, cursor: usize) -> InputResponse {
+        self.handle(InputRequest::SetCursor(cursor))
+    }
+
+    /// Move the cursor left by `n` chars, stopping at the start.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let mut input: Input = "Hello".into();
+    /// input.move_cursor_left(2);
+    /// assert_eq!(input.cursor(), 3);
+    ///
+    /// input.move_cursor_right(9);
+    /// assert_eq!(input.cursor(), 5);
+    /// ```
+    pub fn move_cursor_left(&mut self, n: usize) -> InputResponse {
+        self.set_cursor(self.cursor.saturating_sub(n))
+    }
+
+    /// Move the cursor right by `n` chars, stopping at the end.
+    pub fn move_cursor_right(&mut self, n: usize) -> InputResponse {
+        self.set_cursor(self.cursor.saturating_add(n))
+    }
+
+    /// Move the cursor to the start of the current or previous word, like
+    /// [`InputRequest::GoToPrevWord`].
+    pub fn move_cursor_to_word_start(&mut self) -> InputResponse {
+        self.handle(InputRequest::GoToPrevWord)
+    }
+
+    /// Move the cursor to the start of the next word, like
+    /// [`InputRequest::GoToNextWord`].
+    pub fn move_cursor_to_next_word(&mut self) -> InputResponse {
+        self.handle(InputRequest::GoToNextWord)
+    }
+
+    /// Move the cursor to the start of the value.
+    pub fn move_cursor_to_start(&mut self) -> InputResponse {
+        self.handle(InputRequest::GoToStart)
+    }
+
+    /// Move the cursor to the end of the value.
+    pub fn move_cursor_to_end(&mut self) -> InputResponse {
+        self.handle(InputRequest::GoToEnd)
+    }
+
+    fn dispatch(&mut self, req: InputRequest) -> InputResponse {
+        use InputRequest::*;
+
+        if let Some(selection) = self.selection() {
+            match req {
+                InsertNewline if !self.multiline => {}
+                InsertChar(_) | InsertNewline | DeletePrevChar | DeleteNextChar => {
+                    if self.selection_rejection(selection.clone(), req).is_some() {
+                        return None;
+                    }
+                    self.replace_range(selection.clone(), "");
+                    self.cursor = selection.start;
+                    self.selection_anchor = None;
+                    if !matches!(req, InsertChar(_) | InsertNewline) {
+                        return Some(StateChanged {
+                            value: true,
+                            cursor: true,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let InsertChar(c) = req {
+            if self.overwritten_char(c).is_some() {
+                return self.overwrite_char(c);
+            }
+        }
+
+        if let SelectRegister(name) = req {
+            return if Registers::is_valid_name(name) {
+                self.pending_register = Some(name);
+                Some(StateChanged {
+                    value: false,
+                    cursor: false,
+                })
+            } else {
+                None
+            };
+        }
+
+        if self.block_selection {
+            if let Some(resp) = self.handle_block_request(req) {
+                self.pending_register = None;
+                return resp;
+            }
+        }
+
+        let cleared = match req {
+            ToggleBlockSelection => false,
+            _ if self.block_selection && !is_edit(req) => false,
+            CopySelection | CutSelection | ToggleMask | ToggleOverwrite => false,
+            SelectPrevChar | SelectNextChar | SelectPrevWord | SelectNextWord
+            | SelectToStart | SelectToEnd => false,
+            // Pasting over a block selection replaces the linear selection.
+            PasteOverSelection => {
+                self.block_selection = false;
+                false
+            }
+            _ => {
+                self.block_selection = false;
+                self.selection_anchor.take().is_some()
+            }
+        };
+
+        let resp = self.handle_request(req);
+
+        self.pending_register = None;
+
+        resp.or(cleared.then_some(StateChanged {
+            value: false,
+            cursor: false,
+        }))
+    }
+
+    fn handle_request(&mut self, req: InputRequest) -> InputResponse {
+        use InputRequest::*;
+        match req {
+            SetCursor(pos) => {
+                let pos = pos.min(self.char_len());
+                if self.cursor == pos {
+                    None
                 } else {
-                    self.cursor = count;
+                    self.cursor = pos;
                     Some(StateChanged {
                         value: false,
                         cursor: true,
                     })
                 }
             }
+            InsertChar(_) if self.max_len.is_some_and(|max| self.char_len() >= max) => {
+                None
+            }
+
+            InsertChar(c) if self.exceeds_max_bytes(c.len_utf8()) => None,
+
+            InsertChar(c)
+                if c != '\n' && {
+                    let (line, col) = self.cursor_line_col();
+                    let len = self.line_range(line).map_or(col, |r| r.len());
+                    self.max_columns(line).is_some_and(|max| len >= max)
+                } =>
+            {
+                None
+            }
+
+            // Splitting a line adds a line, and moves its end onto the next one.
+            InsertChar('\n')
+                if {
+                    let mut value = self.value.to_string();
+                    value.insert(self.byte_index(self.cursor), '\n');
+                    !self.fits_line_limits(&value)
+                } =>
+            {
+                None
+            }
+
+            InsertChar(c) => {
+                self.replace_range(self.cursor..self.cursor, c.encode_utf8(&mut [0; 4]));
+                self.cursor += 1;
+                Some(StateChanged {
+                    value: true,
+                    cursor: true,
+                })
+            }
+
+            DeletePrevChar
+                if self.cursor > 0 && self.joins_past_line_limits(self.cursor - 1) =>
+            {
+                None
+            }
+
+            DeleteNextChar if self.joins_past_line_limits(self.cursor) => None,
+
+            DeletePrevChar => {
+                if self.cursor == 0 {
+                    None
+                } else {
+                    self.cursor -= 1;
+                    self.replace_range(self.cursor..self.cursor + 1, "");
+                    Some(StateChanged {
+                        value: true,
+                        cursor: true,
+                    })
+                }
+            }
+
+            DeleteNextChar => {
+                if self.cursor == self.char_len() {
+                    None
+                } else {
+                    self.replace_range(self.cursor..self.cursor + 1, "");
+                    Some(StateChanged {
+                        value: true,
+                        cursor: false,
+                    })
+                }
+            }
+
+            GoToPrevChar => {
+                if self.cursor == 0 {
+                    None
+                } else {
+                    self.cursor -= 1;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            GoToPrevWord | GoToPrevSubWord => {
+                if self.cursor == 0 {
+                    None
+                } else {
+                    self.cursor = self.prev_word_start(req == GoToPrevSubWord);
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            GoToNextChar => {
+                if self.cursor == self.char_len() {
+                    None
+                } else {
+                    self.cursor += 1;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            GoToNextWord | GoToNextSubWord => {
+                if self.cursor == self.char_len() {
+                    None
+                } else {
+                    self.cursor = self.next_word_start(req == GoToNextSubWord);
+
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            DeleteLine => {
+                if self.value.is_empty() {
+                    None
+                } else {
+                    let cursor = self.cursor;
+                    self.kill(0..self.char_len());
+                    self.cursor = 0;
+                    Some(StateChanged {
+                        value: true,
+                        cursor: self.cursor == cursor,
+                    })
+                }
+            }
+
+            DeletePrevWord | DeletePrevSubWord => {
+                if self.cursor == 0 {
+                    None
+                } else {
+                    let start = self.prev_word_start(req == DeletePrevSubWord);
+                    self.kill(start..self.cursor);
+                    self.cursor = start;
+                    Some(StateChanged {
+                        value: true,
+                        cursor: true,
+                    })
+                }
+            }
+
+            DeleteNextWord | DeleteNextSubWord => {
+                if self.cursor == self.char_len() {
+                    None
+                } else {
+                    let end = self.next_word_start(req == DeleteNextSubWord);
+                    self.kill(self.cursor..end);
+                    Some(StateChanged {
+                        value: true,
+                        cursor: false,
+                    })
+                }
+            }
+
+            GoToPrevSentence | GoToNextSentence | GoToPrevParagraph
+            | GoToNextParagraph => {
+                let chars: Vec<char> = self.value.chars().collect();
+                let stops = if matches!(req, GoToPrevSentence | GoToNextSentence) {
+                    sentence_starts(&chars)
+                } else {
+                    paragraph_breaks(&chars)
+                };
+                let cursor = if matches!(req, GoToPrevSentence | GoToPrevParagraph) {
+                    stops
+                        .into_iter()
+                        .rev()
+                        .find(|i| *i < self.cursor)
+                        .unwrap_or(0)
+                } else {
+                    stops
+                        .into_iter()
+                        .find(|i| *i > self.cursor)
+                        .unwrap_or(chars.len())
+                };
+                if cursor == self.cursor {
+                    None
+                } else {
+                    self.cursor = cursor;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            ReplaceChar('\n') if !self.multiline => None,
+
+            ReplaceChar(c) => {
+                let current = self.value.chars().nth(self.cursor);
+                let added = current.map_or(0, |current| {
+                    c.len_utf8().saturating_sub(current.len_utf8())
+                });
+                let replaced = || {
+                    let mut value = self.value.to_string();
+                    let start = self.byte_index(self.cursor);
+                    let end = self.byte_index(self.cursor + 1);
+                    value.replace_range(start..end, c.encode_utf8(&mut [0; 4]));
+                    value
+                };
+                if current.is_none_or(|current| current == c)
+                    || self.exceeds_max_bytes(added)
+                    || !self.fits_line_limits(&replaced())
+                {
+                    None
+                } else {
+                    self.replace_range(
+                        self.cursor..self.cursor + 1,
+                        c.encode_utf8(&mut [0; 4]),
+                    );
+                    Some(StateChanged {
+                        value: true,
+                        cursor: false,
+                    })
+                }
+            }
+
+            Submit => {
+                self.revealed = false;
+                if self.value.is_empty() && self.cursor == 0 {
+                    None
+                } else if self.read_only {
+                    Some(StateChanged {
+                        value: false,
+                        cursor: false,
+                    })
+                } else {
+                    let cursor = self.cursor;
+                    self.reset();
+                    Some(StateChanged {
+                        value: true,
+                        cursor: cursor != 0,
+                    })
+                }
+            }
+
+            RepeatLastEdit | Undo | Redo => None,
+
+            GoToPrevLine | GoToNextLine => {
+                let (line, col) = self.cursor_line_col();
+                let target = match req {
+                    GoToPrevLine => line.checked_sub(1),
+                    _ => Some(line + 1),
+                };
+                match target.and_then(|line| self.line_range(line)) {
+                    Some(range) => {
+                        let col = *self.sticky_col.get_or_insert(col);
+                        self.cursor = (range.start + col).min(range.end);
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            InsertNewline if !self.multiline => None,
+
+            InsertNewline
+                if self.max_lines.is_some_and(|max| self.line_count() >= max) =>
+            {
+                None
+            }
+
+            InsertNewline => self.handle_request(InsertChar('\n')),
+
+            SwapCase | SwapCaseWord => {
+                let end = if req == SwapCase {
+                    self.cursor + 1
+                } else {
+                    self.next_word_start(false)
+                };
+                let len = self.char_len();
+                if self.cursor >= len {
+                    return None;
+                }
+                let swapped: String = self
+                    .value
+                    .chars()
+                    .skip(self.cursor)
+                    .take(end.min(len) - self.cursor)
+                    .flat_map(|c| -> Box<dyn Iterator<Item = char>> {
+                        if c.is_uppercase() {
+                            Box::new(c.to_lowercase())
+                        } else if c.is_lowercase() {
+                            Box::new(c.to_uppercase())
+                        } else {
+                            Box::new(std::iter::once(c))
+                        }
+                    })
+                    .collect();
+                let mut value = self.value.clone();
+                value.remove_range(self.cursor..end);
+                value.insert_at(self.cursor, &swapped);
+                if !self.fits_limits(&value) {
+                    return None;
+                }
+                self.replace_range(self.cursor..end, &swapped);
+                self.cursor += swapped.chars().count();
+                Some(StateChanged {
+                    value: true,
+                    cursor: true,
+                })
+            }
+
+            GoToStart => {
+                if self.cursor == 0 {
+                    None
+                } else {
+                    self.cursor = 0;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            GoToEnd => {
+                let count = self.char_len();
+                if self.cursor == count {
+                    None
+                } else {
+                    self.cursor = count;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            GoToPrevMisspelling => {
+                let misspellings = self.misspellings();
+                match misspellings.iter().rev().find(|r| r.start < self.cursor) {
+                    Some(r) => {
+                        self.cursor = r.start;
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            GoToNextMisspelling => {
+                let len = self.char_len();
+                let misspellings = self.misspellings();
+                match misspellings
+                    .iter()
+                    .find(|r| r.start > self.cursor && r.start <= len)
+                {
+                    Some(r) => {
+                        self.cursor = r.start;
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            GoToPrevHighlight => {
+                match self.highlights.iter().rev().find(|r| r.start < self.cursor) {
+                    Some(r) => {
+                        self.cursor = r.start;
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            GoToNextHighlight => {
+                let len = self.char_len();
+                match self
+                    .highlights
+                    .iter()
+                    .find(|r| r.start > self.cursor && r.start <= len)
+                {
+                    Some(r) => {
+                        self.cursor = r.start;
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            AcceptSuggestion | AcceptSuggestionWord => {
+                let suggestion = self.suggestion.as_deref()?;
+                let len = match req {
+                    AcceptSuggestion => suggestion.len(),
+                    _ => suggestion
+                        .char_indices()
+                        .skip_while(|(_, c)| !c.is_alphanumeric())
+                        .find(|(_, c)| !c.is_alphanumeric())
+                        .map_or(suggestion.len(), |(i, _)| i),
+                };
+                let end = self.char_len();
+                let len = self.fitting_len(&suggestion[..len], end..end);
+                let accepted = self.value.to_string() + &suggestion[..len];
+                if len == 0 || !self.fits_line_limits(&accepted) {
+                    return None;
+                }
+                let suggestion = self.suggestion.take()?;
+                let (accepted, rest) = suggestion.split_at(len);
+                self.replace_range(end..end, accepted);
+                self.cursor = self.char_len();
+                self.set_suggestion(Some(rest.into()));
+                Some(StateChanged {
+                    value: true,
+                    cursor: true,
+                })
+            }
+
+            SelectPrevChar | SelectNextChar | SelectPrevWord | SelectNextWord
+            | SelectToStart | SelectToEnd => {
+                let anchor = self.selection_anchor.unwrap_or(self.cursor);
+                let motion = match req {
+                    SelectPrevChar => GoToPrevChar,
+                    SelectNextChar => GoToNextChar,
+                    SelectPrevWord => GoToPrevWord,
+                    SelectNextWord => GoToNextWord,
+                    SelectToStart => GoToStart,
+                    _ => GoToEnd,
+                };
+                let resp = self.handle_request(motion)?;
+                self.selection_anchor = Some(anchor);
+                Some(resp)
+            }
+
+            ToggleOverwrite => {
+                self.overwrite = !self.overwrite;
+                Some(StateChanged {
+                    value: false,
+                    cursor: false,
+                })
+            }
+
+            ToggleBlockSelection if !self.multiline => None,
+
+            ToggleBlockSelection => {
+                if self.block_selection {
+                    self.clear_selection();
+                } else {
+                    self.selection_anchor = Some(self.cursor);
+                    self.block_selection = true;
+                }
+                Some(StateChanged {
+                    value: false,
+                    cursor: false,
+                })
+            }
+
+            ToggleMask => {
+                if self.mask.is_none() {
+                    None
+                } else {
+                    self.revealed = !self.revealed;
+                    Some(StateChanged {
+                        value: false,
+                        cursor: false,
+                    })
+                }
+            }
+
+            SelectAll => {
+                let len = self.char_len();
+                if len == 0 {
+                    None
+                } else {
+                    self.select(0, len);
+                    Some(StateChanged {
+                        value: false,
+                        cursor: true,
+                    })
+                }
+            }
+
+            SelectWord | SelectLine => {
+                let range = match req {
+                    SelectWord => self
+                        .words()
+                        .find(|w| w.start <= self.cursor && self.cursor <= w.end),
+                    _ => self.line_range(self.cursor_line_col().0),
+                };
+                match range.filter(|r| !r.is_empty()) {
+                    Some(range) => {
+                        self.select(range.start, range.end);
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            FindNextChar(_) | FindPrevChar(_) | TillNextChar(_) | TillPrevChar(_) => {
+                self.last_find = Some(req);
+                self.find_char(req, false)
+            }
+
+            RepeatFind | RepeatFindReverse => {
+                let find = self.last_find?;
+                let find = match (req, find) {
+                    (RepeatFind, find) => find,
+                    (_, FindNextChar(c)) => FindPrevChar(c),
+                    (_, FindPrevChar(c)) => FindNextChar(c),
+                    (_, TillNextChar(c)) => TillPrevChar(c),
+                    (_, TillPrevChar(c)) => TillNextChar(c),
+                    (_, find) => find,
+                };
+                self.find_char(find, true)
+            }
+
+            GoToNextMatch | GoToPrevMatch => {
+                let matches = self.search_matches();
+                let target = match req {
+                    GoToNextMatch => matches
+                        .iter()
+                        .find(|r| r.start > self.cursor)
+                        .or(matches.first()),
+                    _ => matches
+                        .iter()
+                        .rev()
+                        .find(|r| r.start < self.cursor)
+                        .or(matches.last()),
+                };
+                match target.map(|r| r.start).filter(|&t| t != self.cursor) {
+                    Some(target) => {
+                        self.cursor = target;
+                        Some(StateChanged {
+                            value: false,
+                            cursor: true,
+                        })
+                    }
+                    None => None,
+                }
+            }
+
+            CopySelection => match self.selection() {
+                Some(selection) => {
+                    let text = self.slice(selection).into();
+                    self.registers.write(self.pending_register, text);
+                    Some(StateChanged {
+                        value: false,
+                        cursor: false,
+                    })
+                }
+                None => None,
+            },
+
+            CutSelection => match self.selection() {
+                Some(selection) => {
+                    let text = self.slice(selection.clone()).into();
+                    self.registers.write(self.pending_register, text);
+                    self.replace_range(selection.clone(), "");
+                    self.cursor = selection.start;
+                    self.selection_anchor = None;
+                    Some(StateChanged {
+                        value: true,
+                        cursor: true,
+                    })
+                }
+                None => None,
+            },
+
+            SelectRegister(_) => None,
+
+            PasteOverSelection => {
+                let selection = self.selection().unwrap_or(self.cursor..self.cursor);
+                let newline = match self.newline_policy {
+                    _ if self.multiline => Some('\n'),
+                    NewlinePolicy::Drop => None,
+                    NewlinePolicy::Space | NewlinePolicy::Submit => Some(' '),
+                };
+                let text: String = self
+                    .registers
+                    .read(self.pending_register)
+                    .chars()
+                    .filter_map(|c| if c == '\n' { newline } else { Some(c) })
+                    .collect();
+                let text = &text[..self.fitting_len(&text, selection.clone())];
+                if text.is_empty() && selection.is_empty() {
+                    return None;
+                }
+                let mut pasted = self.value.clone();
+                pasted.remove_range(selection.clone());
+                pasted.insert_at(selection.start, text);
+                if !self.fits_line_limits(&pasted) {
+                    return None;
+                }
+                self.replace_range(selection.clone(), text);
+                self.cursor = selection.start + text.chars().count();
+                self.selection_anchor = None;
+                Some(StateChanged {
+                    value: true,
+                    cursor: true,
+                })
+            }
+
+            DeleteTillEnd if self.cursor == self.char_len() => None,
+
+            DeleteTillEnd => {
+                self.kill(self.cursor..self.char_len());
+                Some(StateChanged {
+                    value: true,
+                    cursor: false,
+                })
+            }
+        }
+    }
+
+    /// Get a reference to the current value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Iterate over the chars of the value.
+    pub fn chars(&self) -> std::str::Chars<'_> {
+        self.value.chars()
+    }
+
+    /// Iterate over the extended grapheme clusters of the value.
+    pub fn graphemes(&self) -> unicode_segmentation::Graphemes<'_> {
+        unicode_segmentation::UnicodeSegmentation::graphemes(&*self.value, true)
+    }
+
+    /// Iterate over the char ranges of the words, as used by the word motions.
+    /// See [`WordBoundaries`] and [`Segmenter`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "foo, bar-baz".into();
+    /// let words: Vec<_> = input.words().map(|w| input.slice(w)).collect();
+    ///
+    /// assert_eq!(words, vec!["foo", "bar", "baz"]);
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        if let Some(segmenter) = &self.segmenter {
+            let words: Box<dyn Iterator<Item = Range<usize>>> =
+                Box::new(segmenter.segment(&self.value).into_iter());
+            return words;
+        }
+        let words: Box<dyn Iterator<Item = Range<usize>>> = match self.word_boundaries {
+            WordBoundaries::Alphanumeric => {
+                let mut chars = self.value.chars().enumerate().peekable();
+                Box::new(std::iter::from_fn(move || {
+                    let (start, _) = chars.find(|(_, c)| c.is_alphanumeric())?;
+                    let mut end = start + 1;
+                    while chars.next_if(|(_, c)| c.is_alphanumeric()).is_some() {
+                        end += 1;
+                    }
+                    Some(start..end)
+                }))
+            }
+            WordBoundaries::Vi => {
+                let class = |c: char| match c {
+                    c if c.is_whitespace() => None,
+                    c if c.is_alphanumeric() || c == '_' => Some(true),
+                    _ => Some(false),
+                };
+                let mut chars = self.value.chars().enumerate().peekable();
+                Box::new(std::iter::from_fn(move || {
+                    let (start, word) =
+                        chars.find_map(|(i, c)| class(c).map(|w| (i, w)))?;
+                    let mut end = start + 1;
+                    while chars.next_if(|&(_, c)| class(c) == Some(word)).is_some() {
+                        end += 1;
+                    }
+                    Some(start..end)
+                }))
+            }
+            WordBoundaries::Unicode => {
+                let mut end = 0;
+                let segments = self.value.split_word_bounds().map(move |segment| {
+                    let start = end;
+                    end += segment.chars().count();
+                    (start..end, segment)
+                });
+                Box::new(segments.filter_map(|(range, segment)| {
+                    segment
+                        .chars()
+                        .any(|c| !c.is_whitespace() && !c.is_ascii_punctuation())
+                        .then_some(range)
+                }))
+            }
+            WordBoundaries::Script => {
+                let mut chars = self.value.chars().enumerate().peekable();
+                Box::new(std::iter::from_fn(move || {
+                    let (start, script) =
+                        chars.find_map(|(i, c)| Script::of(c).map(|s| (i, s)))?;
+                    let mut end = start + 1;
+                    while chars
+                        .next_if(|&(_, c)| Script::of(c) == Some(script))
+                        .is_some()
+                    {
+                        end += 1;
+                    }
+                    Some(start..end)
+                }))
+            }
+        };
+        words
+    }
+
+    /// Iterate over the char ranges of the sub-words, as used by the sub-word
+    /// motions: the [`Input::words`] split at camelCase humps and underscores.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "parseHTTPHeader utf8_decode".into();
+    /// let words: Vec<_> = input.sub_words().map(|w| input.slice(w)).collect();
+    ///
+    /// assert_eq!(words, vec!["parse", "HTTP", "Header", "utf8", "decode"]);
+    /// ```
+    pub fn sub_words(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let chars: Vec<char> = self.value.chars().collect();
+        self.words()
+            .flat_map(move |word| split_sub_words(&chars[word.clone()], word.start))
+    }
+
+    /// Iterate over the chars of the value along with their char index and
+    /// byte offset.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "héllo".into();
+    /// let offsets: Vec<_> = input.chars_with_offsets().take(3).collect();
+    ///
+    /// assert_eq!(offsets, vec![(0, 0, 'h'), (1, 1, 'é'), (2, 3, 'l')]);
+    /// ```
+    pub fn chars_with_offsets(&self) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+        self.value
+            .char_indices()
+            .enumerate()
+            .map(|(i, (offset, c))| (i, offset, c))
+    }
+
+    /// Get the selected char range, if any. The cursor is at one of its ends.
+    ///
+    /// A block selection is not a single range, see [`Input::block_selection`].
+    pub fn selection(&self) -> Option<Range<usize>> {
+        if self.block_selection {
+            return None;
+        }
+        let anchor = self.selection_anchor?;
+        match anchor.cmp(&self.cursor) {
+            std::cmp::Ordering::Less => Some(anchor..self.cursor),
+            std::cmp::Ordering::Greater => Some(self.cursor..anchor),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Get the rectangle between the anchor and the cursor, if a block
+    /// selection was started with [`InputRequest::ToggleBlockSelection`].
+    ///
+    /// While it's active, the motions extend it, typing inserts at its left
+    /// column of every line (replacing the selected columns), and deleting or
+    /// cutting removes the selected columns. With no columns selected, deleting
+    /// removes the char before or after the column of every line.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{BlockSelection, Input, InputRequest};
+    ///
+    /// let mut input = Input::from("a1\nb2\nc3").with_multiline(true).with_cursor(1);
+    /// input.handle(InputRequest::ToggleBlockSelection);
+    /// input.handle(InputRequest::GoToNextLine);
+    /// input.handle(InputRequest::GoToNextLine);
+    ///
+    /// assert_eq!(
+    ///     input.block_selection(),
+    ///     Some(BlockSelection { lines: 0..3, columns: 1..1 })
+    /// );
+    ///
+    /// input.handle(InputRequest::InsertChar(','));
+    /// assert_eq!(input.value(), "a,1\nb,2\nc,3");
+    /// ```
+    pub fn block_selection(&self) -> Option<BlockSelection> {
+        if !self.block_selection {
+            return None;
+        }
+        let (anchor_line, anchor_col) = self.line_col(self.selection_anchor?);
+        let (line, col) = self.cursor_line_col();
+        Some(BlockSelection {
+            lines: anchor_line.min(line)..anchor_line.max(line) + 1,
+            columns: anchor_col.min(col)..anchor_col.max(col),
+        })
+    }
+
+    /// Get the selected char ranges, one per line of a block selection.
+    pub fn selected_ranges(&self) -> Vec<Range<usize>> {
+        match self.block_selection() {
+            Some(block) => block
+                .lines
+                .filter_map(|line| {
+                    let range = self.line_range(line)?;
+                    let start = (range.start + block.columns.start).min(range.end);
+                    let end = (range.start + block.columns.end).min(range.end);
+                    Some(start..end)
+                })
+                .filter(|range| !range.is_empty())
+                .collect(),
+            None => self.selection().into_iter().collect(),
+        }
+    }
+
+    /// Handle the requests acting on the block selection, returning `None` for
+    /// the others.
+    fn handle_block_request(&mut self, req: InputRequest) -> Option<InputResponse> {
+        use InputRequest::*;
+        let block = self.block_selection()?;
+        let unchanged = Some(StateChanged {
+            value: false,
+            cursor: false,
+        });
+        let (columns, col) = match req {
+            InsertChar(c) if c != '\n' => {
+                (block.columns.clone(), block.columns.start + 1)
+            }
+            DeletePrevChar if block.columns.is_empty() => {
+                let col = block.columns.start.checked_sub(1)?;
+                (col..col + 1, col)
+            }
+            DeleteNextChar if block.columns.is_empty() => (
+                block.columns.start..block.columns.start + 1,
+                block.columns.start,
+            ),
+            DeletePrevChar | DeleteNextChar | CutSelection => {
+                (block.columns.clone(), block.columns.start)
+            }
+            CopySelection => {
+                let text = self.block_text(&block);
+                self.registers.write(self.pending_register, text);
+                return Some(unchanged);
+            }
+            _ => return None,
+        };
+        if req == CutSelection {
+            let text = self.block_text(&block);
+            self.registers.write(self.pending_register, text);
+        }
+
+        let (anchor_line, _) = self.line_col(self.selection_anchor?);
+        let (cursor_line, _) = self.cursor_line_col();
+        let old = self.value.clone();
+        for line in block.lines.rev() {
+            let Some(range) = self.line_range(line) else {
+                continue;
+            };
+            if range.len() < columns.start {
+                continue;
+            }
+            let start = range.start + columns.start;
+            let end = (range.start + columns.end).min(range.end);
+            match req {
+                InsertChar(c) => {
+                    self.replace_range(start..end, c.encode_utf8(&mut [0; 4]))
+                }
+                _ => self.replace_range(start..end, ""),
+            }
+        }
+        let too_long = self.max_len.is_some_and(|max| self.char_len() > max)
+            || self.exceeds_max_bytes(0);
+        if too_long || !self.fits_line_limits(&self.value) {
+            self.set_value(old.into());
+            return Some(None);
+        }
+
+        let index = |input: &Self, line| {
+            let range = input.line_range(line).unwrap_or_default();
+            (range.start + col).min(range.end)
+        };
+        self.selection_anchor = Some(index(self, anchor_line));
+        self.cursor = index(self, cursor_line);
+        Some(Some(StateChanged {
+            value: *self.value != *old,
+            cursor: true,
+        }))
+    }
+
+    /// Get the text of the selected columns, a line per selected line.
+    fn block_text(&self, block: &BlockSelection) -> String {
+        let mut text = String::new();
+        for (i, line) in self.lines().enumerate() {
+            if block.lines.contains(&i) {
+                if i > block.lines.start {
+                    text.push('\n');
+                }
+                text.extend(
+                    line.chars()
+                        .skip(block.columns.start)
+                        .take(block.columns.len()),
+                );
+            }
+        }
+        text
+    }
+
+    /// Get the selected byte range of the value, if any.
+    pub fn selection_bytes(&self) -> Option<Range<usize>> {
+        let selection = self.selection()?;
+        Some(self.byte_index(selection.start)..self.byte_index(selection.end))
+    }
+
+    /// Get the selected range in visual columns of the rendered (and possibly
+    /// masked) value, counted from its start.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let mut input: Input = "ａｂc".into();
+    /// input.set_selection(1, 3);
+    ///
+    /// assert_eq!(input.selection(), Some(1..3));
+    /// assert_eq!(input.selection_bytes(), Some(3..7));
+    /// assert_eq!(input.selection_visual(), Some(2..5));
+    /// ```
+    pub fn selection_visual(&self) -> Option<Range<usize>> {
+        let selection = self.selection()?;
+        let value = self.display_value();
+        let mut columns = value
+            .chars()
+            .scan(0, |column, c| {
+                let start = *column;
+                *column += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                Some(start)
+            })
+            .collect::<Vec<_>>();
+        columns.push(unicode_width::UnicodeWidthStr::width(value.as_ref()));
+        Some(columns[selection.start]..columns[selection.end])
+    }
+
+    /// Get the registers written by deletes and copies.
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Get the text copied, cut or deleted by the last request, pasted by
+    /// [`InputRequest::PasteOverSelection`].
+    pub fn clipboard(&self) -> &str {
+        self.registers.unnamed()
+    }
+
+    /// Get the text in the given char range. The range is clamped to the
+    /// value length.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "héllo world".into();
+    ///
+    /// assert_eq!(input.slice(1..5), "éllo");
+    /// assert_eq!(input.slice(0..input.cursor()), "héllo world");
+    /// assert_eq!(input.slice(6..99), "world");
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> &str {
+        let start = self.byte_index(range.start);
+        let end = self.byte_index(range.end.max(range.start));
+        &self.value[start..end]
+    }
+
+    /// Check if the value can span multiple lines.
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    /// Get the lines of the value. An empty value has one empty line.
+    pub fn lines(&self) -> std::str::Split<'_, char> {
+        self.value.split('\n')
+    }
+
+    /// Get the max length in chars of the given line, if any.
+    pub fn max_columns(&self, line: usize) -> Option<usize> {
+        self.max_columns
+            .get(line)
+            .or(self.max_columns.last())
+            .copied()
+    }
+
+    /// Get the max number of lines, if any.
+    pub fn max_lines(&self) -> Option<usize> {
+        self.max_lines
+    }
+
+    /// Check if the given value fits the max length, the max bytes and the
+    /// line limits.
+    pub(crate) fn fits_limits(&self, value: &str) -> bool {
+        self.max_len.is_none_or(|max| value.chars().count() <= max)
+            && self.max_bytes.is_none_or(|max| value.len() <= max)
+            && self.fits_line_limits(value)
+    }
+
+    /// Check if the given value fits the max lines and columns.
+    fn fits_line_limits(&self, value: &str) -> bool {
+        let mut lines = value.split('\n').enumerate();
+        lines.clone().count() <= self.max_lines.unwrap_or(usize::MAX)
+            && lines.all(|(i, line)| {
+                self.max_columns(i)
+                    .is_none_or(|max| line.chars().count() <= max)
+            })
+    }
+
+    /// Check if removing the char at the given index joins two lines into one
+    /// longer than allowed.
+    fn joins_past_line_limits(&self, index: usize) -> bool {
+        if self.max_columns.is_empty() || self.value.chars().nth(index) != Some('\n') {
+            return false;
+        }
+        let mut value = self.value.to_string();
+        value.remove(self.byte_index(index));
+        !self.fits_line_limits(&value)
+    }
+
+    /// Get the number of lines of the value.
+    pub fn line_count(&self) -> usize {
+        self.lines().count()
+    }
+
+    /// Move the cursor for the given find request. When repeating, a till
+    /// request skips the occurrence right next to the cursor, like vi.
+    fn find_char(&mut self, req: InputRequest, repeat: bool) -> InputResponse {
+        use InputRequest::*;
+        let (c, forward, till) = match req {
+            FindNextChar(c) => (c, true, false),
+            FindPrevChar(c) => (c, false, false),
+            TillNextChar(c) => (c, true, true),
+            TillPrevChar(c) => (c, false, true),
+            _ => return None,
+        };
+        let line = self.line_range(self.cursor_line_col().0)?;
+        let chars: Vec<char> = self.value.chars().collect();
+        let skip = usize::from(till && repeat);
+        let target = if forward {
+            (self.cursor + 1 + skip..line.end)
+                .find(|&i| chars[i] == c)
+                .map(|i| i - usize::from(till))
+        } else {
+            (line.start..self.cursor.saturating_sub(skip))
+                .rev()
+                .find(|&i| chars[i] == c)
+                .map(|i| i + usize::from(till))
+        };
+        match target.filter(|&t| t != self.cursor) {
+            Some(target) => {
+                self.cursor = target;
+                Some(StateChanged {
+                    value: false,
+                    cursor: true,
+                })
+            }
+            None => None,
+        }
+    }
+
+    /// Get the char range of the given line, excluding the newline.
+    fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let mut start = 0;
+        for (i, text) in self.lines().enumerate() {
+            let end = start + text.chars().count();
+            if i == line {
+                return Some(start..end);
+            }
+            start = end + 1;
+        }
+        None
+    }
+
+    /// Get the line and the column (in chars) of the cursor.
+    pub fn cursor_line_col(&self) -> (usize, usize) {
+        self.line_col(self.cursor)
+    }
+
+    /// Get the line and the column (in chars) of the given char index.
+    fn line_col(&self, index: usize) -> (usize, usize) {
+        let before = self.value.chars().take(index);
+        let mut line_col = (0, 0);
+        for c in before {
+            if c == '\n' {
+                line_col = (line_col.0 + 1, 0);
+            } else {
+                line_col.1 += 1;
+            }
+        }
+        line_col
+    }
+
+    /// Get the char used to mask the value, if any.
+    pub fn mask(&self) -> Option<char> {
+        self.mask
+    }
+
+    /// Check if the value is currently masked when rendering.
+    pub fn is_masked(&self) -> bool {
+        self.mask.is_some() && !self.revealed
+    }
+
+    /// Get the value as it should be rendered, masked if needed.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input = Input::from("secret").with_mask('*');
+    /// assert_eq!(input.display_value(), "******");
+    ///
+    /// input.handle(InputRequest::ToggleMask);
+    /// assert_eq!(input.display_value(), "secret");
+    /// ```
+    pub fn display_value(&self) -> Cow<'_, str> {
+        match self.mask.filter(|_| !self.revealed) {
+            Some(mask) => self.value.chars().map(|_| mask).collect(),
+            None => Cow::Borrowed(&*self.value),
+        }
+    }
+
+    /// Get a reference to the placeholder text, if any.
+    pub fn placeholder(&self) -> Option<&str> {
+        self.placeholder.as_deref()
+    }
+
+    /// Get the value submitted when the value is empty, if any.
+    pub fn default_value(&self) -> Option<&str> {
+        self.default_value.as_deref()
+    }
+
+    /// Get the highlighted char ranges, sorted by their start.
+    pub fn highlights(&self) -> &[Range<usize>] {
+        &self.highlights
+    }
+
+    /// Get the char indices of the bracket under (or else right before) the
+    /// cursor and of its matching bracket, if both exist. Useful to highlight
+    /// them while typing expressions.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input = Input::from("f(a[1], b)").with_cursor(1);
+    /// assert_eq!(input.matching_bracket(), Some((1, 9)));
+    ///
+    /// let input = Input::from("f(a[1], b)");
+    /// assert_eq!(input.matching_bracket(), Some((9, 1)));
+    ///
+    /// let input = Input::from("f(a").with_cursor(2);
+    /// assert_eq!(input.matching_bracket(), None);
+    /// ```
+    pub fn matching_bracket(&self) -> Option<(usize, usize)> {
+        const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+        let chars: Vec<char> = self.value.chars().collect();
+        let is_bracket = |i: usize| {
+            chars
+                .get(i)
+                .is_some_and(|c| PAIRS.iter().any(|(o, p)| c == o || c == p))
+        };
+        let index = Some(self.cursor)
+            .filter(|&i| is_bracket(i))
+            .or_else(|| self.cursor.checked_sub(1).filter(|&i| is_bracket(i)))?;
+
+        let c = chars[index];
+        let (open, close, forward) = PAIRS.iter().find_map(|&(open, close)| {
+            (c == open || c == close).then_some((open, close, c == open))
+        })?;
+        let mut depth = 0;
+        let mut check = |i: usize| {
+            if chars[i] == open {
+                depth += 1;
+            } else if chars[i] == close {
+                depth -= 1;
+            }
+            depth == 0
+        };
+        let found = if forward {
+            (index..chars.len()).find(|&i| check(i))
+        } else {
+            (0..=index).rev().find(|&i| check(i))
+        };
+        found.map(|other| (index, other))
+    }
+
+    /// Get the configured max length in chars, if any.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Get the configured max length in bytes, if any.
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// Validate the current value. Always valid if no validator is set.
+    pub fn validate(&self) -> Result<(), String> {
+        self.validator
+            .as_ref()
+            .map_or(Ok(()), |v| v.validate(&self.value))
+    }
+
+    /// Check if the current value is valid.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Get the length of the value in chars, as used by the cursor.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "e\u{301}☆".into();
+    /// assert_eq!(input.char_len(), 3);
+    /// assert_eq!(input.grapheme_len(), 2);
+    /// assert_eq!(input.byte_len(), 6);
+    /// ```
+    pub fn char_len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    /// Get the length of the value in extended grapheme clusters, i.e. the
+    /// chars as perceived by the user.
+    pub fn grapheme_len(&self) -> usize {
+        self.graphemes().count()
+    }
+
+    /// Get the length of the value in UTF-8 bytes.
+    pub fn byte_len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Get the length of the value in chars, graphemes and bytes.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input = Input::from("héllo").with_max_len(140);
+    /// let info = input.len_info();
+    ///
+    /// assert_eq!(info.chars, 5);
+    /// assert_eq!(info.bytes, 6);
+    /// assert_eq!(info.counter(), Some("5/140".into()));
+    /// ```
+    pub fn len_info(&self) -> LenInfo {
+        LenInfo {
+            chars: self.char_len(),
+            graphemes: self.grapheme_len(),
+            bytes: self.byte_len(),
+            max: self.max_len,
+        }
+    }
+
+    /// Get the currect cursor placement.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Get the byte index of the given char index, clamped to the value length.
+    pub(crate) fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map_or_else(|| self.value.len(), |(index, _)| index)
+    }
+
+    /// Get the current cursor position with account for multispace characters.
+    ///
+    /// It's tracked as the requests are handled, so that getting it on every
+    /// frame doesn't measure the whole value.
+    pub fn visual_cursor(&self) -> usize {
+        self.visual_cursor
+            .unwrap_or_else(|| self.compute_visual_cursor())
+    }
+
+    /// Get the display width of the value as rendered, masked if needed, e.g.
+    /// to right-align it or to size a popup. For multi-line values, it's the
+    /// width of the widest line.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// assert_eq!(Input::from("ａｂc").visual_width(), 5);
+    /// assert_eq!(Input::from("👩‍💻!").visual_width(), 3);
+    /// assert_eq!(Input::from("ab\ncde").with_multiline(true).visual_width(), 3);
+    /// ```
+    pub fn visual_width(&self) -> usize {
+        self.display_value()
+            .split('\n')
+            .map(unicode_width::UnicodeWidthStr::width)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the longest prefix of the value fitting in the given display width
+    /// without splitting a grapheme cluster, and whether the value was cut,
+    /// e.g. to render a read-only preview of a long value.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "ａｂc".into();
+    /// assert_eq!(input.truncated(3), ("ａ", true));
+    /// assert_eq!(input.truncated(5), ("ａｂc", false));
+    /// ```
+    pub fn truncated(&self, width: usize) -> (&str, bool) {
+        let mut used = 0;
+        for (i, g) in self.value.grapheme_indices(true) {
+            used += unicode_width::UnicodeWidthStr::width(g);
+            if used > width {
+                return (&self.value[..i], true);
+            }
+        }
+        (&self.value, false)
+    }
+
+    /// Panic if the internal state is inconsistent, e.g. after each request of
+    /// a fuzz target: the cursor and the selection anchor must be within the
+    /// value, and the tracked visual cursor must match the measured one.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::{Input, InputRequest};
+    ///
+    /// let mut input: Input = "héllo".into();
+    /// for req in [InputRequest::GoToPrevWord, InputRequest::DeleteNextChar] {
+    ///     input.handle(req);
+    ///     input.assert_invariants();
+    /// }
+    /// ```
+    pub fn assert_invariants(&self) {
+        let len = self.char_len();
+        assert!(
+            self.cursor <= len,
+            "cursor {} beyond the value length {len}",
+            self.cursor
+        );
+        if let Some(anchor) = self.selection_anchor {
+            assert!(
+                anchor <= len,
+                "selection anchor {anchor} beyond the value length {len}"
+            );
+        }
+        assert!(
+            !self.block_selection || self.selection_anchor.is_some(),
+            "block selection without an anchor"
+        );
+        if let Some(visual) = self.visual_cursor {
+            assert_eq!(
+                visual,
+                self.compute_visual_cursor(),
+                "tracked visual cursor out of sync"
+            );
+        }
+    }
+
+    /// Update the cached visual cursor after the cursor moved from the given
+    /// position, measuring only the chars in between unless the value (or its
+    /// rendering) changed.
+    ///
+    /// Widths only add up across plain ASCII chars: emoji sequences, combining
+    /// marks, ligatures like lam-alef and `"\r\n"` are measured as a whole, so
+    /// the whole prefix is measured again when the cursor moves over others.
+    fn track_visual_cursor(&mut self, old_cursor: usize, value_changed: bool) {
+        let from = old_cursor.min(self.cursor);
+        let to = old_cursor.max(self.cursor);
+        let masked = self.mask.filter(|_| !self.revealed);
+        let width = match (self.visual_cursor, masked) {
+            (Some(_), _) if value_changed => None,
+            (Some(_), Some(mask)) => Some(
+                (to - from) * unicode_width::UnicodeWidthChar::width(mask).unwrap_or(0),
+            ),
+            (Some(_), None) => {
+                let start = self.byte_index(from.saturating_sub(1));
+                let moved = &self.value[self.byte_index(from)..self.byte_index(to)];
+                let around = &self.value[start..self.byte_index(to)];
+                around
+                    .chars()
+                    .all(|c| c.is_ascii() && c != '\r')
+                    .then(|| unicode_width::UnicodeWidthStr::width(moved))
+            }
+            (None, _) => None,
+        };
+        let visual = match (self.visual_cursor, width) {
+            (Some(visual), Some(width)) if self.cursor >= old_cursor => visual + width,
+            (Some(visual), Some(width)) => visual.saturating_sub(width),
+            _ => self.compute_visual_cursor(),
+        };
+        self.visual_cursor = Some(visual);
+    }
+
+    fn refresh_visual_cursor(&mut self) {
+        self.visual_cursor = Some(self.compute_visual_cursor());
+    }
+
+    /// Measure the visual cursor from the start of the value.
+    fn compute_visual_cursor(&self) -> usize {
+        if self.cursor == 0 {
+            return 0;
+        }
+
+        if let Some(mask) = self.mask.filter(|_| !self.revealed) {
+            return self.cursor
+                * unicode_width::UnicodeWidthChar::width(mask).unwrap_or(0);
+        }
+
+        // Safe, because the end index will always be within bounds
+        unicode_width::UnicodeWidthStr::width(unsafe {
+            self.value.get_unchecked(0..self.byte_index(self.cursor))
+        })
+    }
+
+    /// Get the char index rendered at the given column, relative to the start
+    /// of the rendered value scrolled by the given visual scroll.
+    /// Columns past the end of the value map to the value length.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "ａｂc".into();
+    ///
+    /// assert_eq!(input.char_index_at_visual_x(1, 0), 0);
+    /// assert_eq!(input.char_index_at_visual_x(2, 0), 1);
+    /// assert_eq!(input.char_index_at_visual_x(0, 4), 2);
+    /// assert_eq!(input.char_index_at_visual_x(9, 0), 3);
+    /// ```
+    pub fn char_index_at_visual_x(&self, x: usize, scroll: usize) -> usize {
+        let target = x + scroll;
+        let mut column = 0;
+        for (i, c) in self.display_value().chars().enumerate() {
+            column += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            if column > target {
+                return i;
+            }
+        }
+        self.char_len()
+    }
+
+    /// Iterate over the position details of each char of the value, e.g. to
+    /// debug cursor drifts with wide chars or combining marks.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "e\u{301}ｂ".into();
+    /// let info: Vec<_> = input.inspect().collect();
+    ///
+    /// assert_eq!((info[1].byte, info[1].width, info[1].grapheme_start), (1, 0, false));
+    /// assert_eq!((info[2].byte, info[2].column, info[2].width), (3, 1, 2));
+    /// ```
+    pub fn inspect(&self) -> impl Iterator<Item = CharInfo> + '_ {
+        let mut graphemes = self.value.grapheme_indices(true).map(|(i, _)| i).peekable();
+        let mut column = 0;
+        self.chars_with_offsets().map(move |(index, byte, c)| {
+            let width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            let info = CharInfo {
+                index,
+                byte,
+                column,
+                width,
+                grapheme_start: graphemes.next_if_eq(&byte).is_some(),
+                char: c,
+            };
+            column += width;
+            info
+        })
+    }
+
+    /// Format the position details of each char as a table, with the cursor
+    /// marked by `>`. See [`Input::inspect`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input = Input::from("aｂ").with_cursor(1);
+    ///
+    /// assert_eq!(
+    ///     input.inspect_table(),
+    ///     "  char byte col width grapheme
+    ///   0    0    0   1     yes      'a'
+    /// > 1    1    1   2     yes      'ｂ'
+    ///   2    4    3   0     end
+    /// "
+    /// );
+    /// ```
+    pub fn inspect_table(&self) -> String {
+        let marker = |i| if i == self.cursor { '>' } else { ' ' };
+        let mut table = String::from("  char byte col width grapheme\n");
+        for info in self.inspect() {
+            table.push_str(&format!(
+                "{} {:<4} {:<4} {:<3} {:<5} {:<8} {:?}\n",
+                marker(info.index),
+                info.index,
+                info.byte,
+                info.column,
+                info.width,
+                if info.grapheme_start { "yes" } else { "no" },
+                info.char,
+            ));
+        }
+        let len = self.char_len();
+        table.push_str(&format!(
+            "{} {:<4} {:<4} {:<3} {:<5} end\n",
+            marker(len),
+            len,
+            self.value.len(),
+            unicode_width::UnicodeWidthStr::width(&*self.value),
+            0,
+        ));
+        table
+    }
+
+    /// Get the part of the value visible in the given width (in chars), as
+    /// rendered by the terminal backends. See
+    /// [`backend::visible_window`](crate::backend::visible_window).
+    pub fn visible_window(&self, width: usize) -> (usize, &str, usize) {
+        crate::backend::visible_window(&self.value, self.cursor, width)
+    }
+
+    /// Get the scroll position with account for multispace characters.
+    ///
+    /// The window always starts at a grapheme boundary, so that wide chars and
+    /// clusters like emoji sequences are never cut in half.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    ///
+    /// let input: Input = "👩‍💻ab".into();
+    /// assert_eq!(input.visual_cursor(), 4);
+    /// assert_eq!(input.visual_scroll(2), 2);
+    /// ```
+    pub fn visual_scroll(&self, width: usize) -> usize {
+        let scroll = (self.visual_cursor()).max(width) - width;
+        let mut uscroll = 0;
+        let value = self.display_value();
+        let mut graphemes = value.graphemes(true);
+
+        while uscroll < scroll {
+            match graphemes.next() {
+                Some(g) => uscroll += unicode_width::UnicodeWidthStr::width(g),
+                None => break,
+            }
+        }
+        uscroll
+    }
+}
+
+/// Check if the request may change the value, and thus be undone.
+fn is_edit(req: InputRequest) -> bool {
+    use InputRequest::*;
+    !matches!(
+        req,
+        SetCursor(_)
+            | GoToPrevChar
+            | GoToNextChar
+            | GoToPrevWord
+            | GoToNextWord
+            | GoToPrevSubWord
+            | GoToNextSubWord
+            | SelectPrevChar
+            | SelectNextChar
+            | SelectPrevWord
+            | SelectNextWord
+            | SelectToStart
+            | SelectToEnd
+            | ToggleOverwrite
+            | GoToStart
+            | GoToEnd
+            | GoToPrevHighlight
+            | GoToNextHighlight
+            | ToggleMask
+            | CopySelection
+            | SelectRegister(_)
+            | GoToPrevSentence
+            | GoToNextSentence
+            | GoToPrevParagraph
+            | GoToNextParagraph
+            | Submit
+            | RepeatLastEdit
+            | GoToPrevLine
+            | GoToNextLine
+            | Undo
+            | Redo
+            | SelectAll
+            | SelectWord
+            | SelectLine
+            | FindNextChar(_)
+            | FindPrevChar(_)
+            | TillNextChar(_)
+            | TillPrevChar(_)
+            | RepeatFind
+            | RepeatFindReverse
+            | GoToNextMatch
+            | GoToPrevMatch
+            | ToggleBlockSelection
+            | GoToNextMisspelling
+            | GoToPrevMisspelling
+    )
+}
+
+fn edit_kind(req: InputRequest) -> EditKind {
+    match req {
+        InputRequest::InsertChar(c) => EditKind::Insert(c),
+        InputRequest::InsertNewline => EditKind::Insert('\n'),
+        InputRequest::DeletePrevChar | InputRequest::DeleteNextChar => EditKind::Delete,
+        _ => EditKind::Other,
+    }
+}
+
+/// Get the non-overlapping char ranges of the value matching the query,
+/// ignoring the case unless the query has uppercase chars.
+fn find_matches(value: &str, query: &str) -> Vec<Range<usize>> {
+    let ignore_case = !query.chars().any(char::is_uppercase);
+    let fold = |c: char| {
+        if ignore_case {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+    let value: Vec<char> = value.chars().map(fold).collect();
+    let query: Vec<char> = query.chars().map(fold).collect();
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + query.len() <= value.len() {
+        if value[i..i + query.len()] == query[..] {
+            matches.push(i..i + query.len());
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+/// Combine the responses of consecutive requests.
+pub(crate) fn merge_responses(a: InputResponse, b: InputResponse) -> InputResponse {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(StateChanged {
+            value: a.value || b.value,
+            cursor: a.cursor || b.cursor,
+        }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Split the chars of a word starting at the given offset at camelCase humps
+/// (keeping acronyms together) and underscores.
+fn split_sub_words(chars: &[char], offset: usize) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if let Some(start) = start.take() {
+                words.push(offset + start..offset + i);
+            }
+            continue;
+        }
+        let hump = i > 0 && c.is_uppercase() && {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            prev.is_lowercase() || prev.is_numeric() || prev.is_uppercase() && next_lower
+        };
+        match start {
+            Some(s) if hump => {
+                words.push(offset + s..offset + i);
+                start = Some(i);
+            }
+            Some(_) => {}
+            None => start = Some(i),
+        }
+    }
+    if let Some(start) = start {
+        words.push(offset + start..offset + chars.len());
+    }
+    words
+}
+
+/// Get the start positions of the sentences, i.e. the first non-whitespace
+/// chars following a `.`, `!` or `?` and whitespace, or a paragraph break.
+fn sentence_starts(chars: &[char]) -> Vec<usize> {
+    let mut starts = vec![];
+    let mut boundary = true;
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_whitespace() {
+            if i > 0 && matches!(chars[i - 1], '.' | '!' | '?') {
+                boundary = true;
+            }
+            if *c == '\n' && i > 0 && chars[i - 1] == '\n' {
+                boundary = true;
+            }
+        } else if boundary {
+            starts.push(i);
+            boundary = false;
+        }
+    }
+    starts
+}
+
+/// Get the start positions of the blank lines separating paragraphs.
+fn paragraph_breaks(chars: &[char]) -> Vec<usize> {
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| **c == '\n' && (*i == 0 || chars[i - 1] == '\n'))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Self::from_storage(SmallString::new())
+    }
+}
+
+impl<S: Storage> From<Input<S>> for String {
+    fn from(input: Input<S>) -> Self {
+        input.value.into()
+    }
+}
+
+impl From<String> for Input {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(value: &str) -> Self {
+        Self::from_storage(value.into())
+    }
+}
+
+impl<S: Storage> std::fmt::Display for Input<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&*self.value, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    const TEXT: &str = "first second, third.";
+
+    use super::*;
+
+    #[test]
+    fn format() {
+        let input: Input = TEXT.into();
+        println!("{}", input);
+        println!("{}", input);
+    }
+
+    #[test]
+    fn block_selection() {
+        use InputRequest::*;
+        let mut input = Input::from("abcd\nef\nghij").with_multiline(true);
+        input.handle(SetCursor(1));
+        assert_eq!(
+            input.handle(ToggleBlockSelection).map(|r| r.value),
+            Some(false)
+        );
+        input.handle(GoToNextLine);
+        input.handle(GoToNextLine);
+        input.handle(GoToNextChar);
+        input.handle(GoToNextChar);
+        assert_eq!(
+            input.block_selection(),
+            Some(BlockSelection {
+                lines: 0..3,
+                columns: 1..3
+            })
+        );
+        assert_eq!(input.selection(), None);
+        assert_eq!(input.selected_ranges(), vec![1..3, 6..7, 9..11]);
+
+        input.handle(CopySelection);
+        assert_eq!(input.registers().read(None), "bc\nf\nhi");
+
+        input.handle(DeleteNextChar);
+        assert_eq!(input.value(), "ad\ne\ngj");
+        assert_eq!(input.cursor_line_col(), (2, 1));
+
+        input.handle(InsertChar('-'));
+        assert_eq!(input.value(), "a-d\ne-\ng-j");
+        input.handle(DeletePrevChar);
+        assert_eq!(input.value(), "ad\ne\ngj");
+
+        input.handle(Undo);
+        assert_eq!(input.value(), "a-d\ne-\ng-j");
+
+        assert_eq!(input.block_selection(), None);
+        input.handle(InsertChar('x'));
+        assert_eq!(input.value(), "a-d\ne-\ng-xj");
+
+        let mut input = Input::from("ab");
+        assert_eq!(input.handle(ToggleBlockSelection), None);
+    }
+
+    #[test]
+    fn suggestion_limits() {
+        use InputRequest::*;
+        let mut input = Input::from("a").with_max_len(3);
+        input.set_suggestion(Some("bcdef".into()));
+        assert!(input.handle(AcceptSuggestion).is_some());
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.suggestion(), Some("def"));
+        assert_eq!(input.handle(AcceptSuggestion), None);
+        assert_eq!(input.suggestion(), Some("def"));
+
+        let mut input = Input::from("a").with_max_bytes(3);
+        input.set_suggestion(Some("éé".into()));
+        input.handle(AcceptSuggestionWord);
+        assert_eq!(input.value(), "aé");
+
+        let mut input = Input::from("a")
+            .with_multiline(true)
+            .with_max_columns(vec![2]);
+        input.set_suggestion(Some("b\nccc".into()));
+        assert_eq!(input.handle(AcceptSuggestion), None);
+        assert_eq!(input.value(), "a");
+    }
+
+    #[test]
+    fn suggestion() {
+        use InputRequest::*;
+        let mut input: Input = "ab".into();
+        input.set_suggestion(Some("cd".into()));
+        input.handle(GoToPrevChar);
+        assert_eq!(input.handle(GoToEnd).map(|r| r.value), Some(false));
+        assert_eq!(input.suggestion(), Some("cd"));
+
+        input.handle(InsertChar('x'));
+        assert_eq!(input.suggestion(), None);
+        assert_eq!(input.handle(AcceptSuggestion), None);
+    }
+
+    #[test]
+    fn detailed_edit() {
+        let mut input = Input::from("Hello");
+        input.set_selection(1, 3);
+        let resp = input.handle_detailed(InputRequest::InsertChar('☆'));
+        assert_eq!(
+            resp.edit,
+            Some(Edit {
+                start: 1,
+                removed: 2,
+                inserted: "☆".into(),
+            })
+        );
+        assert_eq!(input.handle_detailed(InputRequest::GoToStart).edit, None);
+
+        let mut right = Input::from_storage(input.value().to_string());
+        input.handle_linked(InputRequest::DeleteNextChar, &mut right);
+        input.handle_linked(InputRequest::Undo, &mut right);
+        assert_eq!(right.value(), input.value());
+    }
+
+    #[test]
+    fn linked_inputs() {
+        let mut left: Input = "one two".into();
+        let mut right = left.clone().with_cursor(5);
+
+        left.handle_linked(InputRequest::DeletePrevWord, &mut right);
+        assert_eq!(right.value(), "one ");
+        assert_eq!(right.cursor(), 4);
+
+        left.handle_linked(InputRequest::GoToStart, &mut right);
+        left.handle_linked(InputRequest::InsertChar('>'), &mut right);
+        assert_eq!(right.value(), ">one ");
+        assert_eq!(right.cursor(), 5);
+
+        let edit = Edit {
+            start: 9,
+            removed: 1,
+            inserted: "x".into(),
+        };
+        assert_eq!(right.apply_edit(&edit), None);
+    }
+
+    #[test]
+    fn readline_names() {
+        for (name, req) in READLINE_NAMES {
+            assert_eq!(InputRequest::from_name(name), Some(*req));
+            assert_eq!(req.name(), Some(*name));
+        }
+        assert_eq!(
+            InputRequest::from_name("beginning-of-line"),
+            Some(InputRequest::GoToStart)
+        );
+        assert_eq!(InputRequest::InsertChar('x').name(), None);
+    }
+
+    #[test]
+    fn set_cursor() {
+        let mut input: Input = TEXT.into();
+
+        let req = InputRequest::SetCursor(3);
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: false,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "first second, third.");
+        assert_eq!(input.cursor(), 3);
+
+        let req = InputRequest::SetCursor(30);
+        let resp = input.handle(req);
+
+        assert_eq!(input.cursor(), TEXT.chars().count());
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: false,
+                cursor: true,
+            })
+        );
+
+        let req = InputRequest::SetCursor(TEXT.chars().count());
+        let resp = input.handle(req);
+
+        assert_eq!(input.cursor(), TEXT.chars().count());
+        assert_eq!(resp, None);
+    }
+
+    #[test]
+    fn insert_char() {
+        let mut input: Input = TEXT.into();
+
+        let req = InputRequest::InsertChar('x');
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "first second, third.x");
+        assert_eq!(input.cursor(), TEXT.chars().count() + 1);
+        input.handle(req);
+        assert_eq!(input.value(), "first second, third.xx");
+        assert_eq!(input.cursor(), TEXT.chars().count() + 2);
+
+        let mut input = input.with_cursor(3);
+        input.handle(req);
+        assert_eq!(input.value(), "firxst second, third.xx");
+        assert_eq!(input.cursor(), 4);
+
+        input.handle(req);
+        assert_eq!(input.value(), "firxxst second, third.xx");
+        assert_eq!(input.cursor(), 5);
+    }
+
+    #[test]
+    fn go_to_prev_char() {
+        let mut input: Input = TEXT.into();
+
+        let req = InputRequest::GoToPrevChar;
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: false,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "first second, third.");
+        assert_eq!(input.cursor(), TEXT.chars().count() - 1);
+
+        let mut input = input.with_cursor(3);
+        input.handle(req);
+        assert_eq!(input.value(), "first second, third.");
+        assert_eq!(input.cursor(), 2);
+
+        input.handle(req);
+        assert_eq!(input.value(), "first second, third.");
+        assert_eq!(input.cursor(), 1);
+    }
+
+    #[test]
+    fn remove_unicode_chars() {
+        let mut input: Input = "¡test¡".into();
+
+        let req = InputRequest::DeletePrevChar;
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "¡test");
+        assert_eq!(input.cursor(), 5);
+
+        input.handle(InputRequest::GoToStart);
+
+        let req = InputRequest::DeleteNextChar;
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: false,
+            })
+        );
+
+        assert_eq!(input.value(), "test");
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn insert_unicode_chars() {
+        let mut input = Input::from("¡test¡").with_cursor(5);
+
+        let req = InputRequest::InsertChar('☆');
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "¡test☆¡");
+        assert_eq!(input.cursor(), 6);
+
+        input.handle(InputRequest::GoToStart);
+        input.handle(InputRequest::GoToNextChar);
+
+        let req = InputRequest::InsertChar('☆');
+        let resp = input.handle(req);
+
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+
+        assert_eq!(input.value(), "¡☆test☆¡");
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn multispace_characters() {
+        let input: Input = "Ｈｅｌｌｏ, ｗｏｒｌｄ!".into();
+        assert_eq!(input.cursor(), 13);
+        assert_eq!(input.visual_cursor(), 23);
+        assert_eq!(input.visual_scroll(6), 18);
+
+        let input: Input = "👩‍💻ab".into();
+        assert_eq!(input.visual_scroll(1), 3);
+    }
+
+    #[test]
+    fn visual_width() {
+        let mut input = Input::from("ｗｏｒｌｄ").with_mask('*');
+        assert_eq!(input.visual_width(), 5);
+        input.handle(InputRequest::ToggleMask);
+        assert_eq!(input.visual_width(), 10);
+        assert_eq!(Input::default().visual_width(), 0);
+    }
+
+    #[test]
+    fn truncated() {
+        let input: Input = "e\u{301}👩‍💻x".into();
+        assert_eq!(input.truncated(0), ("", true));
+        assert_eq!(input.truncated(1), ("e\u{301}", true));
+        assert_eq!(input.truncated(2), ("e\u{301}", true));
+        assert_eq!(input.truncated(3), ("e\u{301}👩‍💻", true));
+        assert_eq!(input.truncated(4), (input.value(), false));
+    }
+
+    #[test]
+    fn tracked_visual_cursor() {
+        use InputRequest::*;
+        let mut input = Input::from("Ｈｅｌｌｏ, ｗｏｒｌｄ!").with_mask('*');
+        let requests = [
+            GoToPrevWord,
+            ToggleMask,
+            GoToPrevChar,
+            InsertChar('ａ'),
+            GoToStart,
+            GoToNextWord,
+            DeleteNextWord,
+            Undo,
+            ToggleMask,
+            GoToEnd,
+        ];
+        for req in requests {
+            input.handle(req);
+            assert_eq!(input.visual_cursor(), input.compute_visual_cursor());
+        }
+
+        for value in ["a❤\u{FE0F}b", "👨\u{200D}👩", "\u{644}\u{627}", "x\r\ny"] {
+            let mut input = Input::from(value).with_multiline(true);
+            for req in [
+                GoToStart,
+                GoToNextChar,
+                GoToNextChar,
+                GoToNextChar,
+                GoToPrevChar,
+            ] {
+                input.handle(req);
+                assert_eq!(input.visual_cursor(), input.compute_visual_cursor());
+                input.assert_invariants();
+            }
+        }
+    }
+
+    #[test]
+    fn invariants() {
+        use InputRequest::*;
+        let requests = [
+            InsertChar('a'),
+            InsertChar('☆'),
+            InsertChar('\u{200D}'),
+            InsertChar('\u{FE0F}'),
+            InsertChar(' '),
+            InsertNewline,
+            GoToPrevChar,
+            GoToNextWord,
+            GoToStart,
+            GoToPrevLine,
+            GoToNextLine,
+            DeletePrevChar,
+            DeleteNextWord,
+            DeleteLine,
+            SelectWord,
+            SelectPrevChar,
+            SelectToEnd,
+            CutSelection,
+            PasteOverSelection,
+            ToggleBlockSelection,
+            ReplaceChar('é'),
+            SwapCaseWord,
+            ToggleMask,
+            Undo,
+            Redo,
+            Submit,
+        ];
+        let mut input = Input::default().with_multiline(true).with_mask('*');
+        let mut seed = 42u32;
+        for _ in 0..2000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            input.handle(requests[seed as usize % requests.len()]);
+            input.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn transform() {
+        use InputRequest::*;
+        let mut input = Input::default().with_transform(Transform::capitalize());
+        input.insert_str_at_cursor("ada");
+        input.handle(GoToStart);
+        input.handle(DeleteNextChar);
+        assert_eq!(input.value(), "Da");
+        assert_eq!(input.cursor(), 0);
+
+        let mut input = Input::from("ß").with_transform(Transform::uppercase());
+        input.handle(GoToStart);
+        input.handle(InsertChar('a'));
+        assert_eq!(input.value(), "ASS");
+        assert_eq!(input.cursor(), 1);
+        input.assert_invariants();
+
+        let mut input = Input::from("A")
+            .with_transform(Transform::uppercase())
+            .with_max_len(2);
+        assert_eq!(input.handle(InsertChar('ß')), None);
+        assert_eq!(input.value(), "A");
+        assert_eq!(input.cursor(), 1);
+        assert!(!input.undo_history().can_undo());
+        input.assert_invariants();
+        assert!(input.handle(InsertChar('b')).is_some());
+        assert_eq!(input.value(), "AB");
+    }
+
+    #[test]
+    fn modified() {
+        use InputRequest::*;
+        let mut input = Input::default();
+        assert!(!input.is_modified());
+        input.handle(InsertChar('a'));
+        assert!(input.is_modified());
+        input.handle(Undo);
+        assert!(!input.is_modified());
+
+        let mut input = Input::from("a").with_value("b".into());
+        assert!(!input.is_modified());
+        input.handle(Submit);
+        assert!(input.is_modified());
+    }
+
+    #[test]
+    fn max_len() {
+        let mut input = Input::from("ab").with_max_len(3);
+
+        let req = InputRequest::InsertChar('c');
+        assert!(input.handle(req).is_some());
+        assert_eq!(input.handle(req), None);
+        assert_eq!(input.value(), "abc");
+
+        input.handle(InputRequest::DeletePrevChar);
+        assert!(input.handle(req).is_some());
+        assert_eq!(input.value(), "abc");
+    }
+
+    #[test]
+    fn newline_policy() {
+        let mut input = Input::from("a");
+        assert_eq!(input.handle(InputRequest::InsertChar('\n')), None);
+        input.set_clipboard("b\nc".into());
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "abc");
+
+        let mut input = Input::from("a").with_newline_policy(NewlinePolicy::Submit);
+        input.set_clipboard("b\nc".into());
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "ab c");
+        assert!(input.submits(InputRequest::InsertNewline));
+        assert!(!input
+            .with_multiline(true)
+            .submits(InputRequest::InsertNewline));
+    }
+
+    #[test]
+    fn max_bytes() {
+        let mut input = Input::from("a").with_max_bytes(4);
+        input.set_clipboard("☆☆".into());
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "a☆");
+
+        input.handle(InputRequest::GoToStart);
+        assert_eq!(input.handle(InputRequest::ReplaceChar('é')), None);
+        assert!(input.handle(InputRequest::DeleteNextChar).is_some());
+        assert_eq!(input.handle(InputRequest::InsertChar('é')), None);
+        assert!(input.handle(InputRequest::InsertChar('b')).is_some());
+        assert_eq!(input.value(), "b☆");
+    }
+
+    #[test]
+    fn overwrite() {
+        let mut input = Input::from("ab\ncd").with_multiline(true).with_max_len(5);
+        assert_eq!(
+            input.handle(InputRequest::ToggleOverwrite),
+            Some(StateChanged {
+                value: false,
+                cursor: false
+            })
+        );
+        input.handle(InputRequest::GoToStart);
+        for c in "xy".chars() {
+            assert!(input.try_handle(InputRequest::InsertChar(c)).is_ok());
+        }
+        assert_eq!(
+            input.try_handle(InputRequest::InsertChar('z')),
+            Err(InputError::MaxLen)
+        );
+        assert_eq!(input.value(), "xy\ncd");
+        assert_eq!(input.cursor(), 2);
+
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "ab\ncd");
+
+        input.handle(InputRequest::ToggleOverwrite);
+        assert!(!input.is_overwrite());
+        input.handle(InputRequest::DeleteNextChar);
+        input.handle(InputRequest::InsertChar('z'));
+        assert_eq!(input.value(), "zb\ncd");
+    }
+
+    #[test]
+    fn try_handle() {
+        let mut input = Input::from("a").with_max_bytes(2);
+        assert_eq!(
+            input.try_handle(InputRequest::InsertChar('é')),
+            Err(InputError::MaxBytes)
+        );
+        assert_eq!(input.try_handle(InputRequest::GoToEnd), Ok(None));
+        assert!(input.try_handle(InputRequest::InsertChar('b')).is_ok());
+
+        let mut input = Input::default()
+            .with_multiline(true)
+            .with_max_lines(1)
+            .with_max_columns(vec![1]);
+        assert!(input.try_handle(InputRequest::InsertChar('a')).is_ok());
+        assert_eq!(
+            input.try_handle(InputRequest::InsertChar('b')),
+            Err(InputError::LineLimits)
+        );
+        assert_eq!(
+            input.try_handle(InputRequest::InsertNewline),
+            Err(InputError::LineLimits)
+        );
+
+        let mut input = input.with_read_only(true);
+        assert_eq!(
+            input.try_handle(InputRequest::DeletePrevChar),
+            Err(InputError::ReadOnly)
+        );
+        assert_eq!(
+            input.try_handle(InputRequest::Undo),
+            Err(InputError::ReadOnly)
+        );
+        assert_eq!(input.value(), "a");
+    }
+
+    #[test]
+    fn len_info() {
+        let input: Input = "e\u{301}☆".into();
+        assert_eq!(
+            input.len_info(),
+            LenInfo {
+                chars: 3,
+                graphemes: 2,
+                bytes: 6,
+                max: None,
+            }
+        );
+        assert_eq!(input.len_info().counter(), None);
+    }
+
+    #[test]
+    fn validate() {
+        let validator =
+            Validator::new(|v| v.parse::<u8>().map(|_| ()).map_err(|e| e.to_string()));
+        let mut input = Input::from("25").with_validator(validator);
+        assert!(input.is_valid());
+
+        input.handle(InputRequest::InsertChar('6'));
+        assert_eq!(
+            input.validate(),
+            Err("number too large to fit in target type".into())
+        );
+    }
+
+    #[test]
+    fn go_to_highlights() {
+        let mut input: Input = TEXT.into();
+        input.set_highlights(vec![14..19, 6..12]);
+
+        let req = InputRequest::GoToPrevHighlight;
+        assert!(input.handle(req).is_some());
+        assert_eq!(input.cursor(), 14);
+        input.handle(req);
+        assert_eq!(input.cursor(), 6);
+        assert_eq!(input.handle(req), None);
+
+        let req = InputRequest::GoToNextHighlight;
+        input.handle(req);
+        assert_eq!(input.cursor(), 14);
+        assert_eq!(input.handle(req), None);
+    }
+
+    #[test]
+    fn mask() {
+        let mut input = Input::from("ｗｏｒｌｄ").with_mask('•');
+        assert!(input.is_masked());
+        assert_eq!(input.display_value(), "•••••");
+        assert_eq!(input.visual_cursor(), 5);
+
+        let resp = input.handle(InputRequest::ToggleMask);
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: false,
+                cursor: false,
+            })
+        );
+        assert!(!input.is_masked());
+        assert_eq!(input.visual_cursor(), 10);
+
+        input.blur();
+        assert!(input.is_masked());
+
+        let mut input = Input::from("plain");
+        assert_eq!(input.handle(InputRequest::ToggleMask), None);
+    }
+
+    #[test]
+    fn copy_cut_paste() {
+        let mut input: Input = TEXT.into();
+        assert_eq!(input.handle(InputRequest::CopySelection), None);
+
+        input.set_selection(6, 12);
+        assert_eq!(input.selection(), Some(6..12));
+        input.handle(InputRequest::CopySelection);
+        assert_eq!(input.clipboard(), "second");
+
+        input.set_selection(14, 19);
+        let resp = input.handle(InputRequest::CutSelection);
+        assert_eq!(
+            resp,
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        );
+        assert_eq!(input.value(), "first second, .");
+        assert_eq!(input.cursor(), 14);
+        assert_eq!(input.selection(), None);
+
+        input.set_selection(6, 0);
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "thirdsecond, .");
+        assert_eq!(input.cursor(), 5);
+    }
+
+    #[test]
+    fn typing_replaces_selection() {
+        let mut input: Input = TEXT.into();
+        input.set_selection(0, 5);
+        input.handle(InputRequest::InsertChar('1'));
+        assert_eq!(input.value(), "1 second, third.");
+        assert_eq!(input.cursor(), 1);
+
+        input.set_selection(2, 9);
+        input.handle(InputRequest::DeletePrevChar);
+        assert_eq!(input.value(), "1  third.");
+        assert_eq!(input.cursor(), 2);
+
+        input.set_selection(0, 2);
+        let resp = input.handle(InputRequest::GoToStart);
+        assert_eq!(input.selection(), None);
+        assert!(resp.is_some());
+    }
+
+    #[test]
+    fn kill_and_yank() {
+        let mut input: Input = TEXT.into();
+        input.handle(InputRequest::DeletePrevWord);
+        assert_eq!(input.clipboard(), "third.");
+
+        input.handle(InputRequest::GoToStart);
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "third.first second, ");
+
+        let mut input = Input::from("foo foo").with_cursor(0);
+        input.handle(InputRequest::DeleteNextWord);
+        assert_eq!(input.clipboard(), "foo ");
+
+        let mut input = Input::from("a a a").with_cursor(2);
+        input.handle(InputRequest::DeleteTillEnd);
+        assert_eq!(input.clipboard(), "a a");
+
+        let mut clipboard = crate::clipboard::MemoryClipboard("copied".into());
+        let resp =
+            input.handle_with_clipboard(InputRequest::DeleteTillEnd, &mut clipboard);
+        assert_eq!(resp.unwrap(), None);
+        assert_eq!(input.clipboard(), "a a");
+        assert_eq!(clipboard.0, "copied");
+    }
+
+    #[test]
+    fn registers() {
+        let mut input: Input = TEXT.into();
+        input.handle(InputRequest::SelectRegister('a'));
+        input.handle(InputRequest::DeletePrevWord);
+        input.handle(InputRequest::DeletePrevWord);
+        assert_eq!(input.registers().get('a'), Some("third."));
+        assert_eq!(input.clipboard(), "second, ");
+
+        input.handle(InputRequest::SelectRegister('a'));
+        input.handle(InputRequest::PasteOverSelection);
+        assert_eq!(input.value(), "first third.");
+
+        assert_eq!(input.handle(InputRequest::SelectRegister('1')), None);
+    }
+
+    #[test]
+    fn sentence_motions() {
+        let mut input: Input = "One. Two? Three!\n\nFour".into();
+
+        let req = InputRequest::GoToPrevSentence;
+        input.handle(req);
+        assert_eq!(input.cursor(), 18);
+        input.handle(req);
+        assert_eq!(input.cursor(), 10);
+        input.handle(req);
+        assert_eq!(input.cursor(), 5);
+        input.handle(req);
+        input.handle(req);
+        assert_eq!(input.cursor(), 0);
+        assert_eq!(input.handle(req), None);
+
+        let req = InputRequest::GoToNextSentence;
+        input.handle(req);
+        assert_eq!(input.cursor(), 5);
+        input.handle(InputRequest::GoToNextChar);
+        input.handle(req);
+        assert_eq!(input.cursor(), 10);
+    }
+
+    #[test]
+    fn paragraph_motions() {
+        let mut input: Input = "first\n\nsecond\n\n\nthird".into();
+
+        let req = InputRequest::GoToPrevParagraph;
+        input.handle(req);
+        assert_eq!(input.cursor(), 15);
+        input.handle(req);
+        assert_eq!(input.cursor(), 14);
+        input.handle(req);
+        assert_eq!(input.cursor(), 6);
+        input.handle(req);
+        assert_eq!(input.cursor(), 0);
+
+        let req = InputRequest::GoToNextParagraph;
+        input.handle(req);
+        assert_eq!(input.cursor(), 6);
+        input.handle(req);
+        input.handle(req);
+        input.handle(req);
+        assert_eq!(input.cursor(), 21);
+        assert_eq!(input.handle(req), None);
+    }
+
+    #[test]
+    fn char_index_at_visual_x() {
+        let input: Input = "Ｈｅｌｌｏ, ｗｏｒｌｄ!".into();
+        let scroll = input.visual_scroll(6);
+        assert_eq!(input.char_index_at_visual_x(0, scroll), 10);
+        assert_eq!(input.char_index_at_visual_x(3, scroll), 11);
+        assert_eq!(input.char_index_at_visual_x(5, scroll), 13);
+
+        let input = input.with_mask('*');
+        assert_eq!(input.char_index_at_visual_x(3, 0), 3);
+    }
+
+    #[test]
+    fn swap_case() {
+        let mut input = Input::from("hELLO wOrld ß").with_cursor(0);
+
+        input.handle(InputRequest::SwapCase);
+        assert_eq!(input.value(), "HELLO wOrld ß");
+        assert_eq!(input.cursor(), 1);
+
+        input.handle(InputRequest::SwapCaseWord);
+        assert_eq!(input.value(), "Hello wOrld ß");
+        assert_eq!(input.cursor(), 6);
+
+        input.handle(InputRequest::SwapCaseWord);
+        input.handle(InputRequest::SwapCaseWord);
+        assert_eq!(input.value(), "Hello WoRLD SS");
+        assert_eq!(input.handle(InputRequest::SwapCase), None);
+
+        let mut input = Input::from("aß").with_cursor(1).with_max_len(2);
+        assert_eq!(input.handle(InputRequest::SwapCase), None);
+        assert_eq!(input.value(), "aß");
+    }
+
+    #[test]
+    fn handle_with_buffer() {
+        use crate::buffer::InputBuffer;
+        use std::borrow::Cow;
+
+        #[derive(Default)]
+        struct Recorder(String, Vec<(usize, usize, String)>);
+
+        impl InputBuffer for Recorder {
+            fn char_count(&self) -> usize {
+                self.0.char_count()
+            }
+
+            fn insert_at(&mut self, index: usize, text: &str) {
+                self.1.push((index, 0, text.into()));
+                self.0.insert_at(index, text);
+            }
 
-            DeleteTillEnd => {
-                self.value = self.value.chars().take(self.cursor).collect();
-                Some(StateChanged {
-                    value: true,
-                    cursor: false,
-                })
+            fn remove_range(&mut self, range: Range<usize>) {
+                self.1.push((range.start, range.len(), String::new()));
+                self.0.remove_range(range);
+            }
+
+            fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+                self.0.slice(range)
             }
         }
-    }
 
-    /// Get a reference to the current value.
-    pub fn value(&self) -> &str {
-        self.value.as_str()
-    }
+        let mut buffer = Recorder("Hello World".into(), Vec::new());
+        let mut input = Input::from("Hello World").with_cursor(5);
+        input.handle_with_buffer(InputRequest::InsertChar(','), &mut buffer);
+        assert_eq!(buffer.1, vec![(5, 0, ",".into())]);
 
-    /// Get the currect cursor placement.
-    pub fn cursor(&self) -> usize {
-        self.cursor
+        for req in [
+            InputRequest::DeleteNextWord,
+            InputRequest::Undo,
+            InputRequest::SelectAll,
+            InputRequest::InsertChar('x'),
+            InputRequest::Submit,
+        ] {
+            input.handle_with_buffer(req, &mut buffer);
+            assert_eq!(buffer.0, input.value());
+        }
     }
 
-    /// Get the current cursor position with account for multispace characters.
-    pub fn visual_cursor(&self) -> usize {
-        if self.cursor == 0 {
-            return 0;
-        }
+    #[test]
+    fn storage() {
+        use std::borrow::Cow;
 
-        // Safe, because the end index will always be within bounds
-        unicode_width::UnicodeWidthStr::width(unsafe {
-            self.value.get_unchecked(
-                0..self
-                    .value
-                    .char_indices()
-                    .nth(self.cursor)
-                    .map_or_else(|| self.value.len(), |(index, _)| index),
-            )
-        })
+        let mut input = Input::from_storage(String::from("Hello")).with_max_len(7);
+        input.handle(InputRequest::InsertChar(' '));
+        input.handle(InputRequest::InsertChar('W'));
+        assert_eq!(input.handle(InputRequest::InsertChar('o')), None);
+        assert_eq!(input.storage(), "Hello W");
+        input.handle(InputRequest::DeletePrevWord);
+        assert_eq!(input.storage(), "Hello ");
+        input.assert_invariants();
+
+        let mut input = Input::from_storage(Cow::Borrowed("a☆"));
+        input.handle(InputRequest::GoToStart);
+        assert!(matches!(input.storage(), Cow::Borrowed(_)));
+        input.handle(InputRequest::DeleteNextChar);
+        assert_eq!(String::from(input), "☆");
     }
 
-    /// Get the scroll position with account for multispace characters.
-    pub fn visual_scroll(&self, width: usize) -> usize {
-        let scroll = (self.visual_cursor()).max(width) - width;
-        let mut uscroll = 0;
-        let mut chars = self.value().chars();
+    #[test]
+    fn limits_replacing_selection() {
+        let mut input = Input::from("ab").with_max_bytes(2);
+        input.set_selection(0, 1);
+        assert_eq!(
+            input.try_handle(InputRequest::InsertChar('👩')),
+            Err(InputError::MaxBytes)
+        );
+        assert_eq!(input.value(), "ab");
+        assert_eq!(input.selection(), Some(0..1));
+        input.assert_invariants();
 
-        while uscroll < scroll {
-            match chars.next() {
-                Some(c) => {
-                    uscroll += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                }
-                None => break,
-            }
+        let mut input = Input::from("ab").with_max_len(2);
+        input.set_selection(0, 1);
+        assert!(input.handle(InputRequest::InsertChar('é')).is_some());
+        assert_eq!(input.value(), "éb");
+        input.assert_invariants();
+
+        let mut input = Input::from("abc\ndef")
+            .with_multiline(true)
+            .with_max_columns(vec![4]);
+        for req in [InputRequest::DeletePrevChar, InputRequest::InsertChar('x')] {
+            input.set_selection(3, 4);
+            assert_eq!(input.try_handle(req), Err(InputError::LineLimits));
+            assert_eq!(input.value(), "abc\ndef");
+            input.assert_invariants();
         }
-        uscroll
+        input.set_selection(2, 5);
+        assert!(input.handle(InputRequest::DeleteNextChar).is_some());
+        assert_eq!(input.value(), "abef");
     }
-}
 
-impl From<Input> for String {
-    fn from(input: Input) -> Self {
-        input.value
+    #[cfg(feature = "json")]
+    #[test]
+    fn deserialize_baseline() {
+        let input: Input =
+            serde_json::from_str(r#"{"value":"Hello","cursor":2}"#).unwrap();
+        assert_eq!(input.value(), "Hello");
+        assert_eq!(input.cursor(), 2);
+        assert!(!input.is_read_only());
+        assert!(!input.is_modified());
+        input.assert_invariants();
     }
-}
 
-impl From<String> for Input {
-    fn from(value: String) -> Self {
-        Self::new(value)
-    }
-}
+    #[cfg(feature = "json")]
+    #[test]
+    fn serialize_without_registers() {
+        let mut input = Input::from("hunter2").with_mask('*');
+        input.handle(InputRequest::ToggleMask);
+        input.handle(InputRequest::DeleteLine);
+        let json = serde_json::to_string(&input).unwrap();
+        assert!(!json.contains("hunter2"));
 
-impl From<&str> for Input {
-    fn from(value: &str) -> Self {
-        Self::new(value.into())
+        let input: Input = serde_json::from_str(&json).unwrap();
+        assert!(input.is_masked());
     }
-}
 
-impl std::fmt::Display for Input {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.value.fmt(f)
+    #[test]
+    fn submit_read_only() {
+        let mut input = Input::from("a").with_read_only(true);
+        assert_eq!(
+            input.try_handle(InputRequest::Submit),
+            Ok(Some(StateChanged {
+                value: false,
+                cursor: false,
+            }))
+        );
+        assert_eq!(input.value_and_reset(), "a");
+        assert_eq!(input.value(), "a");
     }
-}
-
-#[cfg(test)]
-mod tests {
-
-    const TEXT: &str = "first second, third.";
-
-    use super::*;
 
     #[test]
-    fn format() {
-        let input: Input = TEXT.into();
-        println!("{}", input);
-        println!("{}", input);
+    fn max_bytes_in_handle_all() {
+        let mut input = Input::default().with_max_bytes(4);
+        input.handle_all("aé☆".chars().map(InputRequest::InsertChar));
+        assert_eq!(input.value(), "aé");
+
+        input.handle(InputRequest::SelectAll);
+        input.handle_all("☆bc".chars().map(InputRequest::InsertChar));
+        assert_eq!(input.value(), "☆b");
     }
 
     #[test]
-    fn set_cursor() {
-        let mut input: Input = TEXT.into();
-
-        let req = InputRequest::SetCursor(3);
-        let resp = input.handle(req);
+    fn insert_newline() {
+        let mut input: Input = "firstsecond".into();
+        input.handle(InputRequest::SetCursor(5));
+        assert_eq!(input.handle(InputRequest::InsertNewline), None);
 
+        let mut input = input.with_multiline(true);
+        let resp = input.handle(InputRequest::InsertNewline);
         assert_eq!(
             resp,
             Some(StateChanged {
-                value: false,
+                value: true,
                 cursor: true,
             })
         );
+        assert_eq!(input.value(), "first\nsecond");
+        assert_eq!(input.cursor_line_col(), (1, 0));
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["first", "second"]);
+    }
 
-        assert_eq!(input.value(), "first second, third.");
-        assert_eq!(input.cursor(), 3);
-
-        let req = InputRequest::SetCursor(30);
-        let resp = input.handle(req);
+    #[test]
+    fn replace_char() {
+        let mut input = Input::from("¡test").with_cursor(0);
 
-        assert_eq!(input.cursor(), TEXT.chars().count());
+        let resp = input.handle(InputRequest::ReplaceChar('☆'));
         assert_eq!(
             resp,
             Some(StateChanged {
-                value: false,
-                cursor: true,
+                value: true,
+                cursor: false,
             })
         );
+        assert_eq!(input.value(), "☆test");
+        assert_eq!(input.cursor(), 0);
 
-        let req = InputRequest::SetCursor(TEXT.chars().count());
-        let resp = input.handle(req);
+        assert_eq!(input.handle(InputRequest::ReplaceChar('☆')), None);
+        input.handle(InputRequest::GoToEnd);
+        assert_eq!(input.handle(InputRequest::ReplaceChar('x')), None);
 
-        assert_eq!(input.cursor(), TEXT.chars().count());
-        assert_eq!(resp, None);
+        let mut input = Input::from("abc").with_cursor(1);
+        assert_eq!(input.try_handle(InputRequest::ReplaceChar('\n')), Ok(None));
+        assert_eq!(input.value(), "abc");
+
+        let mut input = Input::from("ab\ncd")
+            .with_multiline(true)
+            .with_max_columns(vec![2])
+            .with_cursor(2);
+        assert_eq!(
+            input.try_handle(InputRequest::ReplaceChar('x')),
+            Err(InputError::LineLimits)
+        );
+        assert_eq!(input.value(), "ab\ncd");
+
+        let mut input = input.with_max_columns(Vec::new()).with_max_lines(2);
+        input.handle(InputRequest::GoToStart);
+        assert_eq!(
+            input.try_handle(InputRequest::ReplaceChar('\n')),
+            Err(InputError::LineLimits)
+        );
+        assert_eq!(input.value(), "ab\ncd");
     }
 
     #[test]
-    fn insert_char() {
-        let mut input: Input = TEXT.into();
-
-        let req = InputRequest::InsertChar('x');
-        let resp = input.handle(req);
+    fn submit() {
+        let mut input = Input::from("secret").with_mask('*');
+        input.handle(InputRequest::ToggleMask);
 
+        let resp = input.handle_detailed(InputRequest::Submit);
         assert_eq!(
             resp,
-            Some(StateChanged {
-                value: true,
-                cursor: true,
-            })
+            DetailedResponse {
+                state: Some(StateChanged {
+                    value: true,
+                    cursor: true,
+                }),
+                submitted: Some("secret".into()),
+                edit: Some(Edit {
+                    start: 0,
+                    removed: 6,
+                    inserted: "".into(),
+                }),
+            }
         );
+        assert_eq!(input.value(), "");
+        assert!(input.is_masked());
 
-        assert_eq!(input.value(), "first second, third.x");
-        assert_eq!(input.cursor(), TEXT.chars().count() + 1);
-        input.handle(req);
-        assert_eq!(input.value(), "first second, third.xx");
-        assert_eq!(input.cursor(), TEXT.chars().count() + 2);
+        let resp = input.handle_detailed(InputRequest::Submit);
+        assert_eq!(resp.state, None);
+        assert_eq!(resp.submitted, Some("".into()));
 
-        let mut input = input.with_cursor(3);
-        input.handle(req);
-        assert_eq!(input.value(), "firxst second, third.xx");
-        assert_eq!(input.cursor(), 4);
+        let mut input: Input = "value".into();
+        assert_eq!(input.value_and_reset(), "value");
+        assert_eq!(input.cursor(), 0);
 
-        input.handle(req);
-        assert_eq!(input.value(), "firxxst second, third.xx");
-        assert_eq!(input.cursor(), 5);
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "value");
     }
 
     #[test]
-    fn go_to_prev_char() {
+    fn repeat_last_edit() {
         let mut input: Input = TEXT.into();
 
-        let req = InputRequest::GoToPrevChar;
-        let resp = input.handle(req);
+        input.handle(InputRequest::DeletePrevWord);
+        input.handle(InputRequest::GoToPrevChar);
+        assert_eq!(input.last_edit(), Some(InputRequest::DeletePrevWord));
+
+        let resp = input.handle(InputRequest::RepeatLastEdit);
+        assert!(resp.is_some_and(|r| r.value));
+        assert_eq!(input.value(), "first  ");
+
+        input.handle(InputRequest::Submit);
+        assert_eq!(input.last_edit(), Some(InputRequest::DeletePrevWord));
+
+        let mut input = Input::default();
+        assert_eq!(input.handle(InputRequest::RepeatLastEdit), None);
+    }
+
+    #[test]
+    fn iterators() {
+        let input: Input = "e\u{301}a".into();
+        assert_eq!(input.chars().count(), 3);
+        assert_eq!(input.graphemes().collect::<Vec<_>>(), vec!["e\u{301}", "a"]);
+        assert_eq!(input.chars_with_offsets().last(), Some((2, 3, 'a')));
+    }
+
+    #[test]
+    fn imperative_edits() {
+        let mut input = Input::from("ab").with_max_len(4);
 
+        let resp = input.insert_str_at_cursor("cde");
         assert_eq!(
             resp,
             Some(StateChanged {
-                value: false,
+                value: true,
                 cursor: true,
             })
         );
+        assert_eq!(input.value(), "abcd");
 
-        assert_eq!(input.value(), "first second, third.");
-        assert_eq!(input.cursor(), TEXT.chars().count() - 1);
+        input.delete_char_before_cursor();
+        input.insert_char(' ');
+        assert_eq!(input.insert_char('x'), None);
 
-        let mut input = input.with_cursor(3);
-        input.handle(req);
-        assert_eq!(input.value(), "first second, third.");
-        assert_eq!(input.cursor(), 2);
+        input.delete_word_before_cursor();
+        assert_eq!(input.value(), "");
+        assert_eq!(input.delete_char_after_cursor(), None);
+    }
 
-        input.handle(req);
-        assert_eq!(input.value(), "first second, third.");
-        assert_eq!(input.cursor(), 1);
+    #[test]
+    fn imperative_motions() {
+        let mut input: Input = TEXT.into();
+
+        assert_eq!(input.move_cursor_right(1), None);
+        input.move_cursor_to_word_start();
+        assert_eq!(input.cursor(), 14);
+
+        input.move_cursor_left(3);
+        assert_eq!(input.cursor(), 11);
+
+        input.move_cursor_to_next_word();
+        assert_eq!(input.cursor(), 14);
+
+        input.move_cursor_to_start();
+        assert_eq!(input.move_cursor_left(1), None);
+        input.move_cursor_to_end();
+        assert_eq!(input.cursor(), TEXT.chars().count());
     }
 
     #[test]
-    fn remove_unicode_chars() {
-        let mut input: Input = "¡test¡".into();
+    fn words() {
+        let input: Input = TEXT.into();
+        let words: Vec<_> = input.words().collect();
+        assert_eq!(words, vec![0..5, 6..12, 14..19]);
 
-        let req = InputRequest::DeletePrevChar;
-        let resp = input.handle(req);
+        assert_eq!(Input::from(" ,.").words().count(), 0);
+
+        let input = Input::from("I'm 3.14 🏳️‍🌈, ok?")
+            .with_word_boundaries(WordBoundaries::Unicode);
+        let words: Vec<_> = input.words().map(|w| input.slice(w)).collect();
+        assert_eq!(words, vec!["I'm", "3.14", "🏳️‍🌈", "ok"]);
+
+        let mut input =
+            Input::from("foo.bar(baz_1) ").with_word_boundaries(WordBoundaries::Vi);
+        let words: Vec<_> = input.words().map(|w| input.slice(w)).collect();
+        assert_eq!(words, vec!["foo", ".", "bar", "(", "baz_1", ")"]);
+        input.handle(InputRequest::GoToPrevWord);
+        input.handle(InputRequest::GoToPrevWord);
+        assert_eq!(input.cursor(), 8);
+
+        let input = Input::from("今日はいい天気、ok")
+            .with_word_boundaries(WordBoundaries::Script);
+        let words: Vec<_> = input.words().map(|w| input.slice(w)).collect();
+        assert_eq!(words, vec!["今日", "はいい", "天気", "ok"]);
+
+        let input = Input::from("สวัสดี ครับ").with_word_boundaries(WordBoundaries::Script);
+        assert_eq!(input.words().count(), 2);
+    }
 
+    #[test]
+    fn sub_word_motions() {
+        use InputRequest::*;
+        let mut input = Input::from("let fooBar = XMLHttp_request;")
+            .with_word_boundaries(WordBoundaries::Vi);
+        let words: Vec<_> = input.sub_words().map(|w| input.slice(w)).collect();
         assert_eq!(
-            resp,
-            Some(StateChanged {
-                value: true,
-                cursor: true,
-            })
+            words,
+            vec!["let", "foo", "Bar", "=", "XML", "Http", "request", ";"]
         );
 
-        assert_eq!(input.value(), "¡test");
-        assert_eq!(input.cursor(), 5);
+        input.handle(GoToPrevSubWord);
+        input.handle(GoToPrevSubWord);
+        assert_eq!(input.cursor(), 21);
+        input.handle(DeletePrevSubWord);
+        assert_eq!(input.value(), "let fooBar = XMLrequest;");
+        assert_eq!(input.registers().read(None), "Http_");
+
+        input.handle(GoToStart);
+        input.handle(GoToNextSubWord);
+        input.handle(GoToNextSubWord);
+        input.handle(DeleteNextSubWord);
+        assert_eq!(input.value(), "let foo= XMLrequest;");
+    }
+
+    #[test]
+    fn viewport() {
+        let mut input = Input::from("1\n2\n3\n4\n5").with_multiline(true);
+        input.set_viewport_height(3);
+        assert_eq!(input.viewport(), Viewport { top: 2, height: 3 });
+
+        input.handle(InputRequest::SetCursor(3));
+        assert_eq!(input.viewport().top, 1);
 
         input.handle(InputRequest::GoToStart);
+        assert_eq!(input.viewport().top, 0);
 
-        let req = InputRequest::DeleteNextChar;
-        let resp = input.handle(req);
+        input.scroll_viewport(9);
+        assert_eq!(input.viewport().top, 2);
+        input.scroll_viewport(-1);
+        assert_eq!(input.viewport().top, 1);
+    }
 
-        assert_eq!(
-            resp,
-            Some(StateChanged {
-                value: true,
-                cursor: false,
-            })
-        );
+    #[test]
+    fn sticky_column() {
+        let mut input = Input::from("long line\nab\nlonger line").with_multiline(true);
+        input.handle(InputRequest::SetCursor(7));
 
-        assert_eq!(input.value(), "test");
-        assert_eq!(input.cursor(), 0);
+        input.handle(InputRequest::GoToNextLine);
+        assert_eq!(input.cursor_line_col(), (1, 2));
+
+        input.handle(InputRequest::GoToNextLine);
+        assert_eq!(input.cursor_line_col(), (2, 7));
+        assert_eq!(input.handle(InputRequest::GoToNextLine), None);
+
+        input.handle(InputRequest::GoToPrevChar);
+        input.handle(InputRequest::GoToPrevLine);
+        assert_eq!(input.cursor_line_col(), (1, 2));
+        input.handle(InputRequest::GoToPrevLine);
+        assert_eq!(input.cursor_line_col(), (0, 6));
     }
 
     #[test]
-    fn insert_unicode_chars() {
-        let mut input = Input::from("¡test¡").with_cursor(5);
+    fn max_lines_and_columns() {
+        let mut input = Input::default()
+            .with_multiline(true)
+            .with_max_lines(2)
+            .with_max_columns(vec![2, 3]);
 
-        let req = InputRequest::InsertChar('☆');
-        let resp = input.handle(req);
+        input.insert_str_at_cursor("abc\ndefg\nh");
+        assert_eq!(input.value(), "ab\ndef");
+
+        input.handle(InputRequest::SetCursor(1));
+        assert_eq!(input.handle(InputRequest::InsertNewline), None);
+
+        input.set_clipboard("xy".into());
+        assert_eq!(input.handle(InputRequest::PasteOverSelection), None);
+        input.handle(InputRequest::DeletePrevChar);
+        input.set_clipboard("x".into());
+        assert!(input.handle(InputRequest::PasteOverSelection).is_some());
+        assert_eq!(input.value(), "xb\ndef");
 
+        input.handle(InputRequest::SetCursor(1));
+        assert_eq!(input.handle(InputRequest::InsertChar('\n')), None);
+        input.handle(InputRequest::SetCursor(3));
+        assert_eq!(input.handle(InputRequest::DeletePrevChar), None);
+        input.handle(InputRequest::SetCursor(2));
         assert_eq!(
-            resp,
-            Some(StateChanged {
-                value: true,
-                cursor: true,
-            })
+            input.try_handle(InputRequest::DeleteNextChar),
+            Err(InputError::LineLimits)
         );
+        assert_eq!(input.value(), "xb\ndef");
 
-        assert_eq!(input.value(), "¡test☆¡");
-        assert_eq!(input.cursor(), 6);
+        let mut input = Input::from("a").with_multiline(true).with_max_lines(1);
+        assert_eq!(input.handle(InputRequest::InsertChar('\n')), None);
+        assert_eq!(input.value(), "a");
+    }
 
+    #[test]
+    fn undo_redo() {
+        let mut input = Input::default();
+        input.insert_str_at_cursor("one two");
         input.handle(InputRequest::GoToStart);
-        input.handle(InputRequest::GoToNextChar);
+        input.handle(InputRequest::DeleteNextChar);
+        input.handle(InputRequest::DeleteNextChar);
+        assert_eq!(input.value(), "e two");
 
-        let req = InputRequest::InsertChar('☆');
-        let resp = input.handle(req);
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "one two");
+        assert_eq!(input.cursor(), 0);
+
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "one ");
+        input.handle(InputRequest::Undo);
+        assert_eq!(input.value(), "");
+        assert_eq!(input.handle(InputRequest::Undo), None);
+
+        input.handle(InputRequest::Redo);
+        assert_eq!(input.value(), "one ");
+        input.handle(InputRequest::InsertChar('x'));
+        assert_eq!(input.handle(InputRequest::Redo), None);
+    }
+
+    #[test]
+    fn select_all() {
+        let mut input: Input = Input::from("abc").with_cursor(1);
+        input.handle(InputRequest::SelectAll);
+        assert_eq!(input.selection(), Some(0..3));
+
+        input.handle(InputRequest::CopySelection);
+        assert_eq!(input.clipboard(), "abc");
+
+        input.handle(InputRequest::InsertChar('x'));
+        assert_eq!(input.value(), "x");
+        assert_eq!(Input::default().handle(InputRequest::SelectAll), None);
+    }
+
+    #[test]
+    fn select_word_and_line() {
+        let mut input = Input::from("one two\nthree").with_multiline(true);
+        input.handle(InputRequest::SetCursor(5));
+        input.handle(InputRequest::SelectWord);
+        assert_eq!(input.selection(), Some(4..7));
+
+        input.handle(InputRequest::SelectLine);
+        assert_eq!(input.selection(), Some(0..7));
 
+        input.handle(InputRequest::SetCursor(3));
         assert_eq!(
-            resp,
-            Some(StateChanged {
-                value: true,
-                cursor: true,
-            })
+            input.handle(InputRequest::SelectWord).map(|r| r.cursor),
+            Some(true)
         );
+        assert_eq!(input.selection(), Some(0..3));
+    }
 
-        assert_eq!(input.value(), "¡☆test☆¡");
+    #[test]
+    fn find_char() {
+        let mut input = Input::from("a,b,c,d\ne,f").with_multiline(true);
+        input.handle(InputRequest::GoToStart);
+
+        input.handle(InputRequest::FindNextChar(','));
+        assert_eq!(input.cursor(), 1);
+        input.handle(InputRequest::RepeatFind);
+        assert_eq!(input.cursor(), 3);
+        input.handle(InputRequest::RepeatFindReverse);
+        assert_eq!(input.cursor(), 1);
+
+        input.handle(InputRequest::TillNextChar(','));
         assert_eq!(input.cursor(), 2);
+        input.handle(InputRequest::RepeatFind);
+        assert_eq!(input.cursor(), 4);
+
+        input.handle(InputRequest::TillPrevChar('a'));
+        assert_eq!(input.cursor(), 1);
+        assert_eq!(input.handle(InputRequest::FindNextChar('e')), None);
     }
 
     #[test]
-    fn multispace_characters() {
-        let input: Input = "Ｈｅｌｌｏ, ｗｏｒｌｄ!".into();
-        assert_eq!(input.cursor(), 13);
-        assert_eq!(input.visual_cursor(), 23);
-        assert_eq!(input.visual_scroll(6), 18);
+    fn search() {
+        let mut input: Input = "aXa xa".into();
+        input.set_search_query(Some("Xa".into()));
+        assert_eq!(input.search_matches().len(), 1);
+        assert_eq!(input.search_matches()[0], 1..3);
+
+        input.set_search_query(Some("xa".into()));
+        assert_eq!(input.search_matches(), &[1..3, 4..6]);
+
+        input.handle(InputRequest::GoToPrevMatch);
+        assert_eq!(input.cursor(), 4);
+        input.handle(InputRequest::GoToNextMatch);
+        assert_eq!(input.cursor(), 1);
+
+        input.handle(InputRequest::DeleteNextChar);
+        assert_eq!(input.search_matches().len(), 1);
+        assert_eq!(input.search_matches()[0], 3..5);
+
+        input.set_search_query(None);
+        assert!(input.highlights().is_empty());
     }
 }
@@ -0,0 +1,255 @@
+//! Custom key bindings, taking precedence over the default mappings of the
+//! backends.
+
+use crate::InputRequest;
+
+/// The action bound to a key.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyAction<C = ()> {
+    /// Send the request to the input.
+    Request(InputRequest),
+    /// An application defined action, handled by the caller.
+    Custom(C),
+}
+
+/// Keys that may be reported in several equivalent ways, e.g. with or without
+/// the Shift modifier of an uppercase char, looked up by [`Keymap::lookup`].
+pub trait KeyPattern: Sized {
+    /// Get the canonical form of the key, equal for all the equivalent keys.
+    fn normalized(&self) -> Self;
+
+    /// Split the key into the key without modifiers and the bit mask of the
+    /// modifiers held, if the backend reports them separately.
+    fn split_modifiers(&self) -> Option<(Self, u32)> {
+        None
+    }
+}
+
+/// Maps keys to actions. `K` is the key type of the backend, e.g.
+/// `(KeyCode, KeyModifiers)` for crossterm or `Key` for termion.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::keymap::{KeyAction, Keymap};
+/// use tui_input::InputRequest;
+///
+/// let keymap: Keymap<&str> = Keymap::default()
+///     .bind("F2", KeyAction::Request(InputRequest::DeleteLine))
+///     .bind("F1", KeyAction::Custom(()));
+///
+/// assert_eq!(
+///     keymap.get(&"F2"),
+///     Some(&KeyAction::Request(InputRequest::DeleteLine))
+/// );
+/// assert_eq!(keymap.get(&"F3"), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keymap<K, C = ()> {
+    bindings: Vec<(K, KeyAction<C>)>,
+    descriptions: Vec<(K, String)>,
+}
+
+impl<K, C> Default for Keymap<K, C> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            descriptions: Vec::new(),
+        }
+    }
+}
+
+impl<K: PartialEq, C> Keymap<K, C> {
+    /// Bind the key to the action, replacing the existing binding.
+    pub fn bind(mut self, key: K, action: KeyAction<C>) -> Self {
+        self.insert(key, action);
+        self
+    }
+
+    /// Bind the key to the action with a description shown by [`Keymap::help`],
+    /// replacing the existing binding. Useful for describing custom actions.
+    pub fn bind_with_description(
+        mut self,
+        key: K,
+        action: KeyAction<C>,
+        description: impl Into<String>,
+    ) -> Self
+    where
+        K: Clone,
+    {
+        self.insert(key.clone(), action);
+        self.descriptions.push((key, description.into()));
+        self
+    }
+
+    /// Bind the keys of the fallback keymap that aren't bound yet, e.g. to
+    /// include the default mappings of a backend in the [`Keymap::help`].
+    pub fn or(mut self, fallback: Self) -> Self {
+        let bound = self.bindings.len();
+        for (key, action) in fallback.bindings {
+            if self.get(&key).is_none() {
+                self.bindings.push((key, action));
+            }
+        }
+        for (key, description) in fallback.descriptions {
+            if self.bindings[bound..].iter().any(|(k, _)| *k == key) {
+                self.descriptions.push((key, description));
+            }
+        }
+        self
+    }
+
+    /// Bind the key to the action, returning the replaced action. The
+    /// description of the replaced binding is dropped.
+    pub fn insert(&mut self, key: K, action: KeyAction<C>) -> Option<KeyAction<C>> {
+        self.descriptions.retain(|(k, _)| *k != key);
+        match self.bindings.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, a)) => Some(std::mem::replace(a, action)),
+            None => {
+                self.bindings.push((key, action));
+                None
+            }
+        }
+    }
+
+    /// Remove the binding of the key, returning its action.
+    pub fn remove(&mut self, key: &K) -> Option<KeyAction<C>> {
+        let i = self.bindings.iter().position(|(k, _)| k == key)?;
+        self.descriptions.retain(|(k, _)| k != key);
+        Some(self.bindings.remove(i).1)
+    }
+
+    /// Get the action bound to the key.
+    pub fn get(&self, key: &K) -> Option<&KeyAction<C>> {
+        self.bindings.iter().find(|(k, _)| k == key).map(|(_, a)| a)
+    }
+
+    /// Get the action bound to the key, or else to an equivalent key, e.g. to
+    /// match Ctrl+Shift+Z whether or not the terminal reports the Shift of the
+    /// uppercase `Z`.
+    ///
+    /// Failing that, the key matches the binding of the same key held with
+    /// fewer modifiers, the one with the most of them first, e.g. Ctrl+Alt+Left
+    /// matches Ctrl+Left.
+    pub fn lookup(&self, key: &K) -> Option<&KeyAction<C>>
+    where
+        K: KeyPattern,
+    {
+        if let Some(action) = self.get(key) {
+            return Some(action);
+        }
+        let key = key.normalized();
+        let bindings = || self.bindings.iter().map(|(k, a)| (k.normalized(), a));
+        if let Some((_, action)) = bindings().find(|(k, _)| *k == key) {
+            return Some(action);
+        }
+        let (base, held) = key.split_modifiers()?;
+        bindings()
+            .rev()
+            .filter_map(|(k, a)| {
+                let (k, mask) = k.split_modifiers()?;
+                (k == base && mask & held == mask).then_some((mask.count_ones(), a))
+            })
+            .max_by_key(|(count, _)| *count)
+            .map(|(_, a)| a)
+    }
+
+    /// Iterate over the bindings, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &KeyAction<C>)> {
+        self.bindings.iter().map(|(k, a)| (k, a))
+    }
+
+    /// Iterate over the bindings with their human readable descriptions, in the
+    /// order they were added, e.g. to generate a key bindings help popup.
+    ///
+    /// Requests are described by [`InputRequest::description`], unless given
+    /// a description with [`Keymap::bind_with_description`]. Custom actions
+    /// without one are described as `None`.
+    ///
+    /// ```
+    /// use tui_input::keymap::{KeyAction, Keymap};
+    /// use tui_input::InputRequest;
+    ///
+    /// let keymap: Keymap<&str, &str> = Keymap::default()
+    ///     .bind("F2", KeyAction::Request(InputRequest::DeleteLine))
+    ///     .bind_with_description("F10", KeyAction::Custom("quit"), "Quit the app");
+    ///
+    /// let help: Vec<_> = keymap.help().map(|(k, _, d)| (*k, d)).collect();
+    /// assert_eq!(
+    ///     help,
+    ///     vec![("F2", Some("Delete the line")), ("F10", Some("Quit the app"))]
+    /// );
+    /// ```
+    pub fn help(&self) -> impl Iterator<Item = (&K, &KeyAction<C>, Option<&str>)> {
+        self.bindings.iter().map(|(key, action)| {
+            let description = self
+                .descriptions
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, d)| d.as_str())
+                .or(match action {
+                    KeyAction::Request(req) => Some(req.description()),
+                    KeyAction::Custom(_) => None,
+                });
+            (key, action, description)
+        })
+    }
+
+    /// Number of bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Check if there are no bindings.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut keymap: Keymap<u8, char> = Keymap::default();
+        assert_eq!(keymap.insert(1, KeyAction::Custom('a')), None);
+        assert_eq!(
+            keymap.insert(1, KeyAction::Custom('b')),
+            Some(KeyAction::Custom('a'))
+        );
+        keymap.insert(2, KeyAction::Request(InputRequest::GoToEnd));
+        assert_eq!(keymap.len(), 2);
+
+        assert_eq!(keymap.remove(&1), Some(KeyAction::Custom('b')));
+        assert_eq!(keymap.remove(&1), None);
+        let keys: Vec<_> = keymap.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![2]);
+    }
+
+    #[test]
+    fn help() {
+        let keymap: Keymap<u8, char> = Keymap::default()
+            .bind(1, KeyAction::Custom('a'))
+            .bind_with_description(2, KeyAction::Request(InputRequest::Undo), "Oops")
+            .or(Keymap::default()
+                .bind(2, KeyAction::Custom('b'))
+                .bind(3, KeyAction::Request(InputRequest::GoToEnd)));
+
+        let help: Vec<_> = keymap.help().map(|(k, _, d)| (*k, d)).collect();
+        assert_eq!(
+            help,
+            vec![(1, None), (2, Some("Oops")), (3, Some("Go to the end"))]
+        );
+
+        let keymap = keymap.bind(2, KeyAction::Custom('c'));
+        assert_eq!(keymap.help().nth(1).map(|(_, _, d)| d), Some(None));
+
+        let keymap: Keymap<u8, char> = Keymap::default()
+            .bind(1, KeyAction::Custom('a'))
+            .or(Keymap::default().bind_with_description(1, KeyAction::Custom('b'), "B"));
+        assert_eq!(keymap.help().next().map(|(_, _, d)| d), Some(None));
+    }
+}
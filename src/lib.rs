@@ -18,6 +18,38 @@
 //! See other examples in the [GitHub repo](https://github.com/sayanarijit/tui-input/tree/main/examples).
 
 mod input;
+mod segmenter;
+mod transform;
+mod validator;
 
 pub mod backend;
-pub use input::{Input, InputRequest, InputResponse, StateChanged};
+pub mod blink;
+pub mod buffer;
+pub mod clipboard;
+pub mod color;
+pub mod completion;
+pub mod duration;
+pub mod form;
+pub mod fuzzy;
+pub mod grouping;
+pub mod history;
+pub mod keymap;
+pub mod line_editor;
+pub mod number;
+pub mod palette;
+pub mod registers;
+pub mod request_log;
+pub mod slider;
+pub mod spell;
+pub mod spinner;
+pub mod testing;
+pub mod undo;
+#[cfg(feature = "ratatui")]
+pub mod widget;
+pub use input::{
+    BlockSelection, CharInfo, DetailedResponse, Input, InputError, InputRequest,
+    InputResponse, LenInfo, NewlinePolicy, StateChanged, Viewport, WordBoundaries,
+};
+pub use segmenter::Segmenter;
+pub use transform::Transform;
+pub use validator::Validator;
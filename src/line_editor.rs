@@ -0,0 +1,346 @@
+//! A readline-like line editor, bundling an input with history, completion
+//! and key bindings.
+
+use crate::completion::{Completer, CompletionRequest, CompletionState};
+use crate::history::{History, HistoryEntry};
+use crate::keymap::KeyAction;
+use crate::Input;
+use std::fmt;
+
+/// Requests of the line editor, besides the ones sent to the input. Bind them
+/// to keys as [`KeyAction::Custom`] actions.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EditorRequest {
+    /// Recall the previous history entry, or select the previous completion.
+    PrevHistory,
+    /// Recall the next history entry, or select the next completion.
+    NextHistory,
+    /// Recall the previous history entry starting with the text before the
+    /// cursor, like zsh's `history-beginning-search-backward`, or select the
+    /// previous completion.
+    PrevHistoryWithPrefix,
+    /// Recall the next history entry starting with the text before the cursor,
+    /// like zsh's `history-beginning-search-forward`, or select the next
+    /// completion.
+    NextHistoryWithPrefix,
+    /// Complete the value, or select the next completion.
+    Complete,
+    /// Accept the line, or the selected completion.
+    Accept,
+    /// Discard the line, or close the completions.
+    Cancel,
+}
+
+/// The outcome of reading a line.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReadLine {
+    /// The accepted line.
+    Line(String),
+    /// The line was discarded.
+    Cancelled,
+}
+
+/// A readline-like line editor: an [`Input`] with a [`History`] of the accepted
+/// lines, an optional [`Completer`], and the kill ring of the input registers.
+///
+/// Drive it with the key actions of a backend, e.g. using
+/// `backend::crossterm::to_key_action` with
+/// `backend::crossterm::line_editor_keymap`.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::keymap::KeyAction::{Custom, Request};
+/// use tui_input::line_editor::{EditorRequest, LineEditor, ReadLine};
+/// use tui_input::InputRequest;
+///
+/// let mut editor = LineEditor::default();
+/// let keys = [Request(InputRequest::InsertChar('l')), Custom(EditorRequest::Accept)];
+/// assert_eq!(editor.read_line(keys), Some(ReadLine::Line("l".into())));
+///
+/// let keys = [Custom(EditorRequest::PrevHistory), Custom(EditorRequest::Accept)];
+/// assert_eq!(editor.read_line(keys), Some(ReadLine::Line("l".into())));
+/// ```
+#[derive(Default)]
+pub struct LineEditor {
+    input: Input,
+    history: History,
+    history_pos: Option<usize>,
+    draft: String,
+    draft_cursor: usize,
+    completer: Option<Box<dyn Completer>>,
+    completions: CompletionState,
+}
+
+impl LineEditor {
+    /// Initialize a new editor with the given input, e.g. to set a max length
+    /// or a validator.
+    pub fn new(input: Input) -> Self {
+        Self {
+            input,
+            ..Default::default()
+        }
+    }
+
+    /// Set the history of the accepted lines.
+    pub fn with_history(mut self, history: History) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Set the completer used by [`EditorRequest::Complete`].
+    pub fn with_completer<C: Completer + 'static>(mut self, completer: C) -> Self {
+        self.completer = Some(Box::new(completer));
+        self
+    }
+
+    /// Get a reference to the input.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Get a mutable reference to the input.
+    pub fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
+    }
+
+    /// Get a reference to the history.
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Get a mutable reference to the history, e.g. to load it.
+    pub fn history_mut(&mut self) -> &mut History {
+        &mut self.history
+    }
+
+    /// Get the state of the completion menu, e.g. to render it.
+    pub fn completions(&self) -> &CompletionState {
+        &self.completions
+    }
+
+    /// Handle the actions until a line is accepted or cancelled. Returns
+    /// `None` if the actions run out first.
+    pub fn read_line<I>(&mut self, actions: I) -> Option<ReadLine>
+    where
+        I: IntoIterator<Item = KeyAction<EditorRequest>>,
+    {
+        actions.into_iter().find_map(|action| self.handle(action))
+    }
+
+    /// Handle the action, returning the outcome once a line is accepted or
+    /// cancelled.
+    pub fn handle(&mut self, action: KeyAction<EditorRequest>) -> Option<ReadLine> {
+        use EditorRequest::*;
+        let req = match action {
+            KeyAction::Request(req) => {
+                self.completions.close();
+                self.input.handle(req);
+                return None;
+            }
+            KeyAction::Custom(req) => req,
+        };
+
+        if self.completions.is_open() {
+            let req = match req {
+                PrevHistory | PrevHistoryWithPrefix => CompletionRequest::SelectPrev,
+                NextHistory | NextHistoryWithPrefix | Complete => {
+                    CompletionRequest::SelectNext
+                }
+                Accept => CompletionRequest::Accept,
+                Cancel => CompletionRequest::Dismiss,
+            };
+            self.completions.handle(req, &mut self.input);
+            return None;
+        }
+
+        match req {
+            PrevHistory => self.recall(-1, false),
+            NextHistory => self.recall(1, false),
+            PrevHistoryWithPrefix => self.recall(-1, true),
+            NextHistoryWithPrefix => self.recall(1, true),
+            Complete => self.complete(),
+            Accept => {
+                let line = self.input.value_and_reset();
+                let secret =
+                    self.input.mask().is_some() && self.history.ignores_masked();
+                if !line.is_empty() && !secret {
+                    self.history.push(line.clone());
+                }
+                self.history_pos = None;
+                return Some(ReadLine::Line(line));
+            }
+            Cancel => {
+                self.input.reset();
+                self.history_pos = None;
+                return Some(ReadLine::Cancelled);
+            }
+        }
+        None
+    }
+
+    /// Recall the previous (negative offset) or the next history entry, going
+    /// back to the edited line past the most recent entry.
+    ///
+    /// With a prefix, only the entries starting with the text before the cursor
+    /// of the edited line are recalled, skipping the one already shown, and the
+    /// cursor stays after the prefix.
+    fn recall(&mut self, offset: isize, with_prefix: bool) {
+        if self.history_pos.is_none() {
+            self.draft = self.input.value().into();
+            self.draft_cursor = self.input.cursor();
+        }
+        let prefix: String = match with_prefix {
+            true => self.draft.chars().take(self.draft_cursor).collect(),
+            false => String::new(),
+        };
+        let entries = self.history.entries();
+        let current = self.input.value();
+        let is_match = |e: &&HistoryEntry| {
+            !with_prefix || (e.value.starts_with(&prefix) && e.value != current)
+        };
+        let pos = if offset < 0 {
+            let end = self.history_pos.unwrap_or(entries.len());
+            match entries[..end].iter().rposition(is_match) {
+                Some(pos) => Some(pos),
+                None => return,
+            }
+        } else {
+            let Some(start) = self.history_pos.map(|pos| pos + 1) else {
+                return;
+            };
+            entries[start..]
+                .iter()
+                .position(is_match)
+                .map(|pos| start + pos)
+        };
+        let (value, cursor) = match pos {
+            Some(pos) if with_prefix => {
+                (entries[pos].value.clone(), prefix.chars().count())
+            }
+            Some(pos) => (entries[pos].value.clone(), usize::MAX),
+            None => (std::mem::take(&mut self.draft), self.draft_cursor),
+        };
+        self.history_pos = pos;
+        self.input.replace_value(value, cursor);
+    }
+
+    /// Apply the only completion, or open the menu to choose one.
+    fn complete(&mut self) {
+        let Some(completer) = &self.completer else {
+            return;
+        };
+        match completer.complete(&self.input).as_slice() {
+            [] => {}
+            [completion] => {
+                self.input.apply_completion(completion);
+            }
+            completions => self.completions.set_completions(completions.to_vec()),
+        }
+    }
+}
+
+impl fmt::Debug for LineEditor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineEditor")
+            .field("input", &self.input)
+            .field("history", &self.history)
+            .field("completions", &self.completions)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completion::Completion;
+    use crate::InputRequest;
+    use EditorRequest::*;
+    use KeyAction::{Custom, Request};
+
+    #[test]
+    fn history() {
+        let mut history = History::default();
+        history.push("a".into());
+        history.push("b".into());
+        let mut editor = LineEditor::default().with_history(history);
+
+        editor.handle(Request(InputRequest::InsertChar('c')));
+        editor.handle(Custom(PrevHistory));
+        editor.handle(Custom(PrevHistory));
+        editor.handle(Custom(PrevHistory));
+        assert_eq!(editor.input().value(), "a");
+
+        editor.handle(Custom(NextHistory));
+        editor.handle(Custom(NextHistory));
+        assert_eq!(editor.input().value(), "c");
+        assert_eq!(editor.handle(Custom(Cancel)), Some(ReadLine::Cancelled));
+        assert_eq!(editor.history().len(), 2);
+    }
+
+    #[test]
+    fn history_with_prefix() {
+        let mut history = History::default();
+        for value in ["git status", "ls", "git log", "git log"] {
+            history.push(value.into());
+        }
+        let mut editor = LineEditor::default().with_history(history);
+        for c in "git".chars() {
+            editor.handle(Request(InputRequest::InsertChar(c)));
+        }
+
+        editor.handle(Custom(PrevHistoryWithPrefix));
+        assert_eq!(editor.input().value(), "git log");
+        assert_eq!(editor.input().cursor(), 3);
+        editor.handle(Custom(PrevHistoryWithPrefix));
+        assert_eq!(editor.input().value(), "git status");
+        editor.handle(Custom(PrevHistoryWithPrefix));
+        assert_eq!(editor.input().value(), "git status");
+
+        editor.handle(Custom(NextHistoryWithPrefix));
+        assert_eq!(editor.input().value(), "git log");
+        editor.handle(Custom(NextHistoryWithPrefix));
+        assert_eq!(editor.input().value(), "git");
+    }
+
+    #[test]
+    fn ignore_masked() {
+        let history = History::default().with_ignore_masked(true);
+        let input = Input::default().with_mask('*');
+        let mut editor = LineEditor::new(input).with_history(history);
+
+        let keys = [Request(InputRequest::InsertChar('x')), Custom(Accept)];
+        assert_eq!(editor.read_line(keys), Some(ReadLine::Line("x".into())));
+        assert!(editor.history().is_empty());
+    }
+
+    #[test]
+    fn complete() {
+        let completer = |input: &Input| {
+            ["cat", "cd"]
+                .iter()
+                .filter(|c| c.starts_with(input.value()))
+                .map(|c| Completion {
+                    range: 0..input.char_len(),
+                    text: c.to_string(),
+                })
+                .collect()
+        };
+        let mut editor = LineEditor::default().with_completer(completer);
+
+        editor.handle(Request(InputRequest::InsertChar('c')));
+        editor.handle(Custom(Complete));
+        assert_eq!(editor.completions().completions().len(), 2);
+
+        editor.handle(Custom(NextHistory));
+        assert_eq!(editor.handle(Custom(Accept)), None);
+        assert_eq!(editor.input().value(), "cd");
+
+        editor.handle(Request(InputRequest::DeletePrevChar));
+        editor.handle(Request(InputRequest::InsertChar('a')));
+        editor.handle(Custom(Complete));
+        assert_eq!(editor.input().value(), "cat");
+    }
+}
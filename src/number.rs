@@ -0,0 +1,283 @@
+//! A numeric input, formatting the thousands separators and the decimal mark
+//! of a locale while editing.
+
+use crate::{Input, InputRequest, InputResponse, StateChanged};
+
+/// The separators used to format numbers.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberLocale {
+    /// Separator of the thousands groups, if any.
+    pub group_separator: Option<char>,
+    /// Separator of the fractional part.
+    pub decimal_mark: char,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::EN
+    }
+}
+
+impl NumberLocale {
+    /// `1,234,567.89`
+    pub const EN: Self = Self {
+        group_separator: Some(','),
+        decimal_mark: '.',
+    };
+    /// `1.234.567,89`
+    pub const DE: Self = Self {
+        group_separator: Some('.'),
+        decimal_mark: ',',
+    };
+    /// `1 234 567,89`, with narrow no-break spaces.
+    pub const FR: Self = Self {
+        group_separator: Some('\u{202f}'),
+        decimal_mark: ',',
+    };
+    /// `1'234'567.89`
+    pub const CH: Self = Self {
+        group_separator: Some('\''),
+        decimal_mark: '.',
+    };
+}
+
+/// A numeric input, following the same request/response pattern as
+/// [`Input`]. Only digits, a leading minus and a decimal mark can be typed,
+/// and the thousands separators are inserted as the value changes. Typing `.`
+/// or `,` inserts the decimal mark of the locale.
+///
+/// Render it with the wrapped [`NumberInput::input`].
+///
+/// Example:
+///
+/// ```
+/// use tui_input::number::{NumberInput, NumberLocale};
+/// use tui_input::InputRequest;
+///
+/// let mut number = NumberInput::new(NumberLocale::DE);
+/// for c in "-1234567.8x".chars() {
+///     number.handle(InputRequest::InsertChar(c));
+/// }
+///
+/// assert_eq!(number.input().value(), "-1.234.567,8");
+/// assert_eq!(number.value(), Some(-1234567.8));
+/// assert_eq!(number.normalized(), "-1234567.8");
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberInput {
+    input: Input,
+    locale: NumberLocale,
+    max_decimals: Option<usize>,
+}
+
+impl NumberInput {
+    /// Initialize a new empty input formatted with the given locale.
+    pub fn new(locale: NumberLocale) -> Self {
+        Self {
+            locale,
+            ..Default::default()
+        }
+    }
+
+    /// Set the value, formatting it.
+    pub fn with_value(mut self, value: f64) -> Self {
+        let raw = value
+            .to_string()
+            .replace('.', &self.locale.decimal_mark.to_string());
+        self.input = std::mem::take(&mut self.input).with_value(raw);
+        self.reformat();
+        self.input.mark_clean();
+        self
+    }
+
+    /// Set the max number of digits after the decimal mark.
+    pub fn with_max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Get the locale.
+    pub fn locale(&self) -> NumberLocale {
+        self.locale
+    }
+
+    /// Get a reference to the formatted input, e.g. to render it.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Get the value without the thousands separators and with `.` as the
+    /// decimal mark, e.g. to parse it as a decimal type.
+    pub fn normalized(&self) -> String {
+        self.input
+            .value()
+            .chars()
+            .filter(|&c| Some(c) != self.locale.group_separator)
+            .map(|c| {
+                if c == self.locale.decimal_mark {
+                    '.'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    /// Get the parsed value, if any.
+    pub fn value(&self) -> Option<f64> {
+        self.normalized().parse().ok()
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: InputRequest) -> InputResponse {
+        use InputRequest::*;
+        let separator = self.locale.group_separator;
+        let chars: Vec<char> = self.input.value().chars().collect();
+        let cursor = self.input.cursor();
+        let req = match req {
+            InsertChar('.' | ',') => InsertChar(self.locale.decimal_mark),
+            req => req,
+        };
+        match req {
+            InsertNewline => return None,
+            InsertChar(c) if !self.accepts(c) => return None,
+            // Delete the digit behind the separator instead.
+            DeletePrevChar if cursor > 0 && Some(chars[cursor - 1]) == separator => {
+                self.input.handle(GoToPrevChar);
+            }
+            DeleteNextChar
+                if separator.is_some() && chars.get(cursor) == separator.as_ref() =>
+            {
+                self.input.handle(GoToNextChar);
+            }
+            _ => {}
+        }
+        let resp = self.input.handle(req);
+        if resp.is_some_and(|r| r.value) {
+            self.reformat();
+            Some(StateChanged {
+                value: true,
+                cursor: true,
+            })
+        } else {
+            resp
+        }
+    }
+
+    /// Check if the char can be inserted at the cursor.
+    fn accepts(&self, c: char) -> bool {
+        let value = self.input.value();
+        let cursor = self.input.cursor();
+        if c == '-' {
+            return cursor == 0 && !value.starts_with('-');
+        }
+        if c == self.locale.decimal_mark {
+            return !value.contains(c);
+        }
+        if !c.is_ascii_digit() {
+            return false;
+        }
+        let decimals = value
+            .split_once(self.locale.decimal_mark)
+            .map(|(int, frac)| (int.chars().count(), frac.chars().count()));
+        match (decimals, self.max_decimals) {
+            (Some((int, frac)), Some(max)) => cursor <= int || frac < max,
+            _ => true,
+        }
+    }
+
+    /// Reinsert the thousands separators, keeping the cursor after the same
+    /// digit.
+    fn reformat(&mut self) {
+        let separator = self.locale.group_separator;
+        let significant = |c: &char| Some(*c) != separator;
+        let value = self.input.value();
+        let before = value
+            .chars()
+            .take(self.input.cursor())
+            .filter(significant)
+            .count();
+        let raw: String = value.chars().filter(significant).collect();
+
+        let (sign, unsigned) = match raw.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", raw.as_str()),
+        };
+        let (int, frac) = match unsigned.split_once(self.locale.decimal_mark) {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (unsigned, None),
+        };
+        let mut formatted = String::from(sign);
+        let len = int.chars().count();
+        for (i, c) in int.chars().enumerate() {
+            if let Some(separator) = separator.filter(|_| i > 0 && (len - i) % 3 == 0) {
+                formatted.push(separator);
+            }
+            formatted.push(c);
+        }
+        if let Some(frac) = frac {
+            formatted.push(self.locale.decimal_mark);
+            formatted.push_str(frac);
+        }
+
+        let mut seen = 0;
+        let cursor = formatted
+            .chars()
+            .position(|c| {
+                if seen == before {
+                    return true;
+                }
+                seen += usize::from(significant(&c));
+                false
+            })
+            .unwrap_or(formatted.chars().count());
+        self.input.replace_value(formatted, cursor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use InputRequest::*;
+
+    #[test]
+    fn edit() {
+        let mut number = NumberInput::new(NumberLocale::EN).with_max_decimals(2);
+        for c in "1234.567".chars() {
+            number.handle(InsertChar(c));
+        }
+        assert_eq!(number.input().value(), "1,234.56");
+
+        number.handle(GoToStart);
+        number.handle(GoToNextChar);
+        number.handle(GoToNextChar);
+        assert_eq!(number.input().cursor(), 2);
+        number.handle(DeletePrevChar);
+        assert_eq!(number.input().value(), "234.56");
+        assert_eq!(number.input().cursor(), 0);
+
+        number.handle(InsertChar('9'));
+        assert_eq!(number.input().value(), "9,234.56");
+        assert_eq!(number.input().cursor(), 1);
+        number.handle(DeleteNextChar);
+        assert_eq!(number.input().value(), "934.56");
+        assert_eq!(number.handle(InsertChar('-')), None);
+        assert_eq!(number.handle(InsertChar(',')), None);
+        assert_eq!(number.value(), Some(934.56));
+    }
+
+    #[test]
+    fn modified() {
+        let mut number = NumberInput::new(NumberLocale::EN);
+        for c in "12345".chars() {
+            number.handle(InsertChar(c));
+        }
+        assert_eq!(number.input().value(), "12,345");
+        assert!(number.input().is_modified());
+
+        let number = NumberInput::new(NumberLocale::EN).with_value(1234.0);
+        assert!(!number.input().is_modified());
+    }
+}
@@ -0,0 +1,188 @@
+//! A command palette: a query input fuzzy filtering a list of commands.
+
+use crate::fuzzy::{fuzzy_match, FuzzyMatch};
+use crate::{Input, InputRequest};
+
+/// Requests used to change the palette state.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaletteRequest {
+    SelectPrev,
+    SelectNext,
+    Accept,
+    /// Edit the query, filtering the commands.
+    Input(InputRequest),
+}
+
+/// Response of [`CommandPalette::handle`].
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaletteResponse {
+    /// The query or the selection changed.
+    Changed,
+    /// The command at the given index was accepted.
+    Accepted(usize),
+}
+
+/// A query input fuzzy filtering a list of commands, with a selected match.
+///
+/// The matches are sorted by score, and listed in the original order while the
+/// query is empty.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::palette::{CommandPalette, PaletteRequest, PaletteResponse};
+/// use tui_input::InputRequest;
+///
+/// let mut palette = CommandPalette::new(vec!["Open file", "Save file", "Quit"]);
+///
+/// palette.handle(PaletteRequest::Input(InputRequest::InsertChar('f')));
+/// assert_eq!(palette.matches().count(), 2);
+///
+/// palette.handle(PaletteRequest::SelectNext);
+/// let resp = palette.handle(PaletteRequest::Accept);
+/// assert_eq!(resp, Some(PaletteResponse::Accepted(1)));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CommandPalette<T> {
+    input: Input,
+    commands: Vec<T>,
+    matches: Vec<(usize, FuzzyMatch)>,
+    selected: usize,
+}
+
+impl<T: AsRef<str>> CommandPalette<T> {
+    /// Initialize a new palette listing the given commands.
+    pub fn new(commands: Vec<T>) -> Self {
+        let mut palette = Self {
+            input: Input::default(),
+            commands,
+            matches: vec![],
+            selected: 0,
+        };
+        palette.filter();
+        palette
+    }
+
+    /// Set the placeholder of the query input.
+    pub fn with_placeholder(mut self, placeholder: String) -> Self {
+        self.input = self.input.with_placeholder(placeholder);
+        self
+    }
+
+    /// Replace the commands, keeping the query.
+    pub fn set_commands(&mut self, commands: Vec<T>) {
+        self.commands = commands;
+        self.filter();
+    }
+
+    /// Get all the commands.
+    pub fn commands(&self) -> &[T] {
+        &self.commands
+    }
+
+    /// Get a reference to the query input.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Iterate over the commands matching the query, best first, along with
+    /// their index and the matched chars.
+    pub fn matches(&self) -> impl Iterator<Item = (usize, &T, &FuzzyMatch)> {
+        self.matches
+            .iter()
+            .map(|(i, m)| (*i, &self.commands[*i], m))
+    }
+
+    /// Get the position of the selected command among the matches, if any.
+    pub fn selected(&self) -> Option<usize> {
+        (!self.matches.is_empty()).then_some(self.selected)
+    }
+
+    /// Get the index of the selected command, if any.
+    pub fn selected_command(&self) -> Option<usize> {
+        self.matches.get(self.selected).map(|(i, _)| *i)
+    }
+
+    /// Clear the query, listing all the commands again.
+    pub fn reset(&mut self) {
+        self.input.reset();
+        self.filter();
+    }
+
+    fn filter(&mut self) {
+        let query = self.input.value();
+        self.matches = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| fuzzy_match(query, c.as_ref()).map(|m| (i, m)))
+            .collect();
+        if !query.is_empty() {
+            let commands = &self.commands;
+            self.matches.sort_by(|(a, ma), (b, mb)| {
+                mb.score.cmp(&ma.score).then(
+                    commands[*a]
+                        .as_ref()
+                        .len()
+                        .cmp(&commands[*b].as_ref().len()),
+                )
+            });
+        }
+        self.selected = 0;
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: PaletteRequest) -> Option<PaletteResponse> {
+        let len = self.matches.len();
+        match req {
+            PaletteRequest::SelectPrev | PaletteRequest::SelectNext if len < 2 => None,
+            PaletteRequest::SelectPrev => {
+                self.selected = (self.selected + len - 1) % len;
+                Some(PaletteResponse::Changed)
+            }
+            PaletteRequest::SelectNext => {
+                self.selected = (self.selected + 1) % len;
+                Some(PaletteResponse::Changed)
+            }
+            PaletteRequest::Accept => {
+                self.selected_command().map(PaletteResponse::Accepted)
+            }
+            PaletteRequest::Input(req) => {
+                let resp = self.input.handle(req)?;
+                if resp.value {
+                    self.filter();
+                }
+                Some(PaletteResponse::Changed)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter() {
+        let mut palette = CommandPalette::new(vec!["git commit", "cargo check", "git"]);
+        assert_eq!(palette.selected_command(), Some(0));
+
+        for c in "gc".chars() {
+            palette.handle(PaletteRequest::Input(InputRequest::InsertChar(c)));
+        }
+        let matches: Vec<_> = palette.matches().map(|(i, _, _)| i).collect();
+        assert_eq!(matches, vec![0, 1]);
+
+        palette.handle(PaletteRequest::SelectPrev);
+        assert_eq!(palette.selected(), Some(1));
+
+        palette.handle(PaletteRequest::Input(InputRequest::InsertChar('x')));
+        assert_eq!(palette.selected(), None);
+        assert_eq!(palette.handle(PaletteRequest::Accept), None);
+
+        palette.reset();
+        assert_eq!(palette.matches().count(), 3);
+    }
+}
@@ -0,0 +1,108 @@
+//! Vi-style registers holding deleted, copied and pasted text.
+
+/// The unnamed register, written by every delete and yank.
+pub const UNNAMED: char = '"';
+
+/// The unnamed register and the named registers `a` to `z`.
+///
+/// Writing to an uppercase name (`A` to `Z`) appends to the corresponding
+/// lowercase register, like in vi.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::registers::Registers;
+///
+/// let mut registers = Registers::default();
+/// registers.write(Some('a'), "foo".into());
+/// registers.write(Some('A'), "bar".into());
+///
+/// assert_eq!(registers.get('a'), Some("foobar"));
+/// assert_eq!(registers.unnamed(), "bar");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Registers {
+    unnamed: String,
+    named: [Option<String>; 26],
+}
+
+impl Registers {
+    /// Check if the given name is a valid register name.
+    pub fn is_valid_name(name: char) -> bool {
+        name == UNNAMED || name.is_ascii_alphabetic()
+    }
+
+    /// Get the content of the unnamed register.
+    pub fn unnamed(&self) -> &str {
+        &self.unnamed
+    }
+
+    /// Get the content of the given register, if set.
+    pub fn get(&self, name: char) -> Option<&str> {
+        if name == UNNAMED {
+            return Some(&self.unnamed);
+        }
+        Self::index(name).and_then(|i| self.named[i].as_deref())
+    }
+
+    /// Read the given register, or the unnamed one if no name is given.
+    pub fn read(&self, name: Option<char>) -> &str {
+        name.and_then(|n| self.get(n)).unwrap_or(match name {
+            Some(_) => "",
+            None => &self.unnamed,
+        })
+    }
+
+    /// Write the text to the unnamed register, and to the given register if a
+    /// name is given.
+    pub fn write(&mut self, name: Option<char>, text: String) {
+        if let Some(i) = name.and_then(Self::index) {
+            let register = self.named[i].get_or_insert_with(String::new);
+            if name.is_some_and(|n| n.is_ascii_uppercase()) {
+                register.push_str(&text);
+            } else {
+                register.clone_from(&text);
+            }
+        }
+        self.unnamed = text;
+    }
+
+    /// Iterate over the set registers and their content, starting with the
+    /// unnamed one.
+    pub fn iter(&self) -> impl Iterator<Item = (char, &str)> {
+        std::iter::once((UNNAMED, self.unnamed.as_str())).chain(
+            self.named
+                .iter()
+                .zip('a'..='z')
+                .filter_map(|(r, n)| r.as_deref().map(|r| (n, r))),
+        )
+    }
+
+    fn index(name: char) -> Option<usize> {
+        name.is_ascii_alphabetic()
+            .then(|| (name.to_ascii_lowercase() as u8 - b'a') as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_write() {
+        let mut registers = Registers::default();
+        assert_eq!(registers.read(None), "");
+        assert_eq!(registers.get('z'), None);
+
+        registers.write(None, "foo".into());
+        registers.write(Some('z'), "bar".into());
+        assert_eq!(registers.read(None), "bar");
+        assert_eq!(registers.read(Some('z')), "bar");
+        assert_eq!(registers.read(Some('b')), "");
+        assert_eq!(registers.read(Some(UNNAMED)), "bar");
+
+        let all: Vec<_> = registers.iter().collect();
+        assert_eq!(all, vec![('"', "bar"), ('z', "bar")]);
+    }
+}
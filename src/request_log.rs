@@ -0,0 +1,191 @@
+//! Recording of the handled requests, e.g. to attach the exact edit sequence
+//! to a bug report.
+
+use crate::{InputRequest, InputResponse};
+use std::collections::VecDeque;
+use std::fmt;
+#[cfg(feature = "json")]
+use std::io;
+use std::sync::Arc;
+
+type Callback = dyn Fn(InputRequest, InputResponse) + Send + Sync;
+
+/// A log of the requests handled by an input, along with their responses.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::request_log::RequestLog;
+/// use tui_input::{Input, InputRequest};
+///
+/// let mut input = Input::default().with_request_log(RequestLog::new(2));
+/// input.handle(InputRequest::InsertChar('a'));
+/// input.handle(InputRequest::InsertChar('b'));
+/// input.handle(InputRequest::GoToStart);
+///
+/// let log = input.request_log().unwrap();
+/// let requests: Vec<_> = log.requests().collect();
+/// assert_eq!(requests, vec![InputRequest::InsertChar('b'), InputRequest::GoToStart]);
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestLog {
+    entries: VecDeque<(InputRequest, InputResponse)>,
+    capacity: usize,
+    callback: Option<Arc<Callback>>,
+}
+
+impl RequestLog {
+    /// Initialize a log keeping the given number of latest entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    /// Call the given function with every handled request and its response.
+    pub fn with_callback<F>(mut self, f: F) -> Self
+    where
+        F: Fn(InputRequest, InputResponse) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(f));
+        self
+    }
+
+    /// Record a handled request and its response.
+    pub fn record(&mut self, req: InputRequest, resp: InputResponse) {
+        if let Some(callback) = &self.callback {
+            callback(req, resp);
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((req, resp));
+    }
+
+    /// Iterate over the kept entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &(InputRequest, InputResponse)> {
+        self.entries.iter()
+    }
+
+    /// Iterate over the kept requests, oldest first.
+    pub fn requests(&self) -> impl Iterator<Item = InputRequest> + '_ {
+        self.entries.iter().map(|(req, _)| *req)
+    }
+
+    /// Get the max number of kept entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Remove the kept entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Write the requests as JSON lines, one request per line.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::request_log::{read_jsonl, write_jsonl};
+/// use tui_input::{Input, InputRequest};
+///
+/// let mut buf = Vec::new();
+/// let requests = [InputRequest::InsertChar('a'), InputRequest::GoToStart];
+/// write_jsonl(&mut buf, requests).unwrap();
+///
+/// let mut input = Input::default();
+/// input.replay(read_jsonl(buf.as_slice()).unwrap());
+/// assert_eq!(input.value(), "a");
+/// assert_eq!(input.cursor(), 0);
+/// ```
+#[cfg(feature = "json")]
+pub fn write_jsonl<W, I>(mut writer: W, requests: I) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = InputRequest>,
+{
+    for req in requests {
+        serde_json::to_writer(&mut writer, &req)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+/// Read the requests written by [`write_jsonl`], skipping the blank lines.
+#[cfg(feature = "json")]
+pub fn read_jsonl<R: io::BufRead>(reader: R) -> io::Result<Vec<InputRequest>> {
+    let mut requests = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        requests.push(serde_json::from_str(&line)?);
+    }
+    Ok(requests)
+}
+
+impl fmt::Debug for RequestLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestLog")
+            .field("entries", &self.entries)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+    use std::sync::Mutex;
+
+    #[test]
+    fn callback() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = RequestLog::new(0).with_callback({
+            let seen = seen.clone();
+            move |req, resp| seen.lock().unwrap().push((req, resp))
+        });
+
+        let mut input = Input::from("a").with_request_log(log);
+        input.handle(InputRequest::GoToNextChar);
+        input.handle(InputRequest::RepeatLastEdit);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                (InputRequest::GoToNextChar, None),
+                (InputRequest::RepeatLastEdit, None)
+            ]
+        );
+        assert_eq!(input.request_log().unwrap().entries().count(), 0);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn jsonl() {
+        let mut input = Input::default().with_request_log(RequestLog::new(10));
+        input.insert_str_at_cursor("ab\nc");
+        input.handle(InputRequest::GoToStart);
+        input.handle(InputRequest::DeleteNextChar);
+
+        let mut buf = Vec::new();
+        let log = input.request_log().unwrap();
+        write_jsonl(&mut buf, log.requests()).unwrap();
+        assert_eq!(String::from_utf8_lossy(&buf).lines().count(), 6);
+
+        let mut replayed = Input::default();
+        replayed.replay(read_jsonl(buf.as_slice()).unwrap());
+        assert_eq!(replayed.value(), input.value());
+        assert_eq!(replayed.cursor(), input.cursor());
+
+        assert!(read_jsonl("nope\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+//! Custom splitting of the value into words.
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+type SegmentFn = dyn Fn(&str) -> Vec<Range<usize>> + Send + Sync;
+
+/// A function splitting the value into words, returning their char ranges in
+/// order. Useful for languages without spaces, e.g. with a dictionary based
+/// segmenter for Thai.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::{Input, InputRequest, Segmenter};
+///
+/// // Every char is a word.
+/// let segmenter = Segmenter::new(|value| (0..value.chars().count()).map(|i| i..i + 1).collect());
+///
+/// let mut input = Input::from("abc").with_segmenter(segmenter);
+/// input.handle(InputRequest::GoToPrevWord);
+/// assert_eq!(input.cursor(), 2);
+/// ```
+#[derive(Clone)]
+pub struct Segmenter(Arc<SegmentFn>);
+
+impl Segmenter {
+    /// Initialize a new segmenter from the given function.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Vec<Range<usize>> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Split the given value into words.
+    pub fn segment(&self, value: &str) -> Vec<Range<usize>> {
+        (self.0)(value)
+    }
+}
+
+impl fmt::Debug for Segmenter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Segmenter")
+    }
+}
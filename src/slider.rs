@@ -0,0 +1,165 @@
+//! A numeric value adjusted with keys within a range.
+
+use crate::{InputResponse, StateChanged};
+
+/// Requests used to change the slider state.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SliderRequest {
+    SetValue(i64),
+    Decrement,
+    Increment,
+    /// Decrement by the accelerated step.
+    DecrementFast,
+    /// Increment by the accelerated step.
+    IncrementFast,
+    GoToMin,
+    GoToMax,
+}
+
+/// A numeric value adjusted within a range, following the same
+/// request/response pattern as [`Input`](crate::Input).
+///
+/// Example:
+///
+/// ```
+/// use tui_input::slider::{SliderInput, SliderRequest};
+///
+/// let mut slider = SliderInput::new(0, 100).with_step(5);
+///
+/// slider.handle(SliderRequest::Increment);
+/// assert_eq!(slider.value(), 5);
+///
+/// slider.handle(SliderRequest::IncrementFast);
+/// assert_eq!(slider.value(), 55);
+/// assert_eq!(slider.ratio(), 0.55);
+/// ```
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SliderInput {
+    value: i64,
+    min: i64,
+    max: i64,
+    step: i64,
+    fast_step: i64,
+}
+
+impl Default for SliderInput {
+    fn default() -> Self {
+        Self::new(0, 100)
+    }
+}
+
+impl SliderInput {
+    /// Initialize a new slider within the given range, set to the min value.
+    /// The accelerated step defaults to 10 times the step.
+    pub fn new(min: i64, max: i64) -> Self {
+        Self {
+            value: min.min(max),
+            min: min.min(max),
+            max: max.max(min),
+            step: 1,
+            fast_step: 10,
+        }
+    }
+
+    /// Set the value manually. It'll be clamped within the range.
+    pub fn with_value(mut self, value: i64) -> Self {
+        self.value = value.clamp(self.min, self.max);
+        self
+    }
+
+    /// Set the step. The accelerated step will be set to 10 times the step.
+    pub fn with_step(mut self, step: i64) -> Self {
+        self.step = step.max(1);
+        self.fast_step = self.step.saturating_mul(10);
+        self
+    }
+
+    /// Set the accelerated step, used when Shift is held.
+    pub fn with_fast_step(mut self, fast_step: i64) -> Self {
+        self.fast_step = fast_step.max(1);
+        self
+    }
+
+    /// Get the current value.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Get the min value.
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// Get the max value.
+    pub fn max(&self) -> i64 {
+        self.max
+    }
+
+    /// Get the position of the value within the range, from 0.0 to 1.0.
+    /// Useful to render it with a gauge.
+    pub fn ratio(&self) -> f64 {
+        if self.max == self.min {
+            return 0.0;
+        }
+        let span = |from: i64, to: i64| (to as i128 - from as i128) as f64;
+        span(self.min, self.value) / span(self.min, self.max)
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: SliderRequest) -> InputResponse {
+        use SliderRequest::*;
+        let value = match req {
+            SetValue(value) => value,
+            Decrement => self.value.saturating_sub(self.step),
+            Increment => self.value.saturating_add(self.step),
+            DecrementFast => self.value.saturating_sub(self.fast_step),
+            IncrementFast => self.value.saturating_add(self.fast_step),
+            GoToMin => self.min,
+            GoToMax => self.max,
+        }
+        .clamp(self.min, self.max);
+
+        if value == self.value {
+            None
+        } else {
+            self.value = value;
+            Some(StateChanged {
+                value: true,
+                cursor: false,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let mut slider = SliderInput::new(-10, 10);
+        assert_eq!(slider.value(), -10);
+        assert_eq!(slider.handle(SliderRequest::Decrement), None);
+
+        slider.handle(SliderRequest::IncrementFast);
+        slider.handle(SliderRequest::IncrementFast);
+        slider.handle(SliderRequest::IncrementFast);
+        assert_eq!(slider.value(), 10);
+        assert_eq!(slider.ratio(), 1.0);
+
+        slider.handle(SliderRequest::SetValue(3));
+        assert_eq!(slider.value(), 3);
+        slider.handle(SliderRequest::GoToMin);
+        assert_eq!(slider.value(), -10);
+    }
+
+    #[test]
+    fn full_range_ratio() {
+        let slider = SliderInput::new(i64::MIN, i64::MAX);
+        assert_eq!(slider.ratio(), 0.0);
+        assert_eq!(slider.with_value(0).ratio(), 0.5);
+        assert_eq!(slider.with_value(i64::MAX).ratio(), 1.0);
+    }
+}
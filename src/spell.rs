@@ -0,0 +1,51 @@
+//! Spell checking hook, flagging the misspelled words of the value.
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Finds the misspelled words of a value. The checking itself is left to the
+/// app, e.g. using a dictionary crate.
+///
+/// It's implemented for the functions taking the value and returning the char
+/// ranges of the misspelled words.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::{Input, InputRequest};
+///
+/// let checker = |value: &str| -> Vec<std::ops::Range<usize>> {
+///     let start = value.find("teh").map(|i| value[..i].chars().count());
+///     start.map(|i| i..i + 3).into_iter().collect()
+/// };
+///
+/// let mut input = Input::from("fïx teh bug").with_spell_checker(checker);
+/// assert_eq!(input.misspellings(), vec![4..7]);
+///
+/// input.handle(InputRequest::GoToPrevMisspelling);
+/// assert_eq!(input.cursor(), 4);
+/// ```
+pub trait SpellChecker {
+    /// Get the char ranges of the misspelled words of the value, in order.
+    fn misspellings(&self, value: &str) -> Vec<Range<usize>>;
+}
+
+impl<F> SpellChecker for F
+where
+    F: Fn(&str) -> Vec<Range<usize>>,
+{
+    fn misspellings(&self, value: &str) -> Vec<Range<usize>> {
+        self(value)
+    }
+}
+
+/// A shared spell checker, cloned along with the input.
+#[derive(Clone)]
+pub(crate) struct SharedSpellChecker(pub(crate) Arc<dyn SpellChecker + Send + Sync>);
+
+impl fmt::Debug for SharedSpellChecker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SpellChecker")
+    }
+}
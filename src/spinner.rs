@@ -0,0 +1,179 @@
+//! A numeric input with stepping.
+
+use crate::{Input, InputRequest, InputResponse, StateChanged};
+
+/// Requests used to change the spinner state.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpinnerRequest {
+    Increment,
+    Decrement,
+    /// Edit the value directly. Only digits and a leading `-` can be inserted.
+    Input(InputRequest),
+}
+
+/// A numeric input stepping between a min and a max value.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::spinner::{Spinner, SpinnerRequest};
+///
+/// let mut spinner = Spinner::new(9).with_range(0, 10).with_step(2);
+///
+/// spinner.handle(SpinnerRequest::Increment);
+/// assert_eq!(spinner.value(), Some(10));
+///
+/// let mut spinner = spinner.with_wrap(true);
+/// spinner.handle(SpinnerRequest::Increment);
+/// assert_eq!(spinner.value(), Some(0));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spinner {
+    input: Input,
+    min: i64,
+    max: i64,
+    step: i64,
+    wrap: bool,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Spinner {
+    /// Initialize a new spinner with the given value, without bounds.
+    pub fn new(value: i64) -> Self {
+        Self {
+            input: value.to_string().into(),
+            min: i64::MIN,
+            max: i64::MAX,
+            step: 1,
+            wrap: false,
+        }
+    }
+
+    /// Set the min and max values. The current value will be clamped.
+    pub fn with_range(mut self, min: i64, max: i64) -> Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        if let Some(value) = self.value() {
+            self.set_value(value.clamp(self.min, self.max));
+        }
+        self
+    }
+
+    /// Set the amount added or subtracted on each step.
+    pub fn with_step(mut self, step: i64) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Wrap around to the other bound when stepping past one.
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Get the parsed value, if valid.
+    pub fn value(&self) -> Option<i64> {
+        self.input.value().parse().ok()
+    }
+
+    /// Get a reference to the underlying input.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Check if the value can be incremented.
+    pub fn can_increment(&self) -> bool {
+        self.wrap || self.value().is_none_or(|v| v < self.max)
+    }
+
+    /// Check if the value can be decremented.
+    pub fn can_decrement(&self) -> bool {
+        self.wrap || self.value().is_none_or(|v| v > self.min)
+    }
+
+    fn set_value(&mut self, value: i64) {
+        self.input = std::mem::take(&mut self.input).with_value(value.to_string());
+    }
+
+    fn step_by(&mut self, delta: i64) -> InputResponse {
+        let current = self.value().unwrap_or(self.min.max(0).min(self.max));
+        let next = match current.checked_add(delta) {
+            Some(v) if (self.min..=self.max).contains(&v) => v,
+            _ if self.wrap && delta > 0 => self.min,
+            _ if self.wrap => self.max,
+            _ if delta > 0 => self.max,
+            _ => self.min,
+        };
+        let old = self.input.clone();
+        self.input.replace_value(next.to_string(), usize::MAX);
+        if old.value() == self.input.value() && old.cursor() == self.input.cursor() {
+            None
+        } else {
+            Some(StateChanged {
+                value: old.value() != self.input.value(),
+                cursor: old.cursor() != self.input.cursor(),
+            })
+        }
+    }
+
+    /// Handle request and emit response.
+    pub fn handle(&mut self, req: SpinnerRequest) -> InputResponse {
+        match req {
+            SpinnerRequest::Increment => self.step_by(self.step),
+            SpinnerRequest::Decrement => self.step_by(-self.step),
+            SpinnerRequest::Input(InputRequest::InsertChar(c))
+                if !(c.is_ascii_digit() || (c == '-' && self.input.cursor() == 0)) =>
+            {
+                None
+            }
+            SpinnerRequest::Input(req) => self.input.handle(req),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp() {
+        let mut spinner = Spinner::new(20).with_range(-5, 5);
+        assert_eq!(spinner.value(), Some(5));
+        assert!(!spinner.can_increment());
+        assert_eq!(spinner.handle(SpinnerRequest::Increment), None);
+
+        spinner.handle(SpinnerRequest::Decrement);
+        assert_eq!(spinner.value(), Some(4));
+    }
+
+    #[test]
+    fn wrap() {
+        let mut spinner = Spinner::new(0).with_range(0, 2).with_wrap(true);
+        assert!(spinner.can_decrement());
+        spinner.handle(SpinnerRequest::Decrement);
+        assert_eq!(spinner.value(), Some(2));
+        spinner.handle(SpinnerRequest::Increment);
+        assert_eq!(spinner.value(), Some(0));
+    }
+
+    #[test]
+    fn edit() {
+        let mut spinner = Spinner::new(1);
+        let resp = spinner.handle(SpinnerRequest::Input(InputRequest::InsertChar('x')));
+        assert_eq!(resp, None);
+
+        spinner.handle(SpinnerRequest::Input(InputRequest::InsertChar('2')));
+        assert_eq!(spinner.value(), Some(12));
+
+        spinner.handle(SpinnerRequest::Input(InputRequest::GoToStart));
+        spinner.handle(SpinnerRequest::Input(InputRequest::InsertChar('-')));
+        assert_eq!(spinner.value(), Some(-12));
+    }
+}
@@ -0,0 +1,93 @@
+//! Helpers for testing the rendering of an input without a terminal.
+
+use crate::Input;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The marker inserted at the cursor position by [`render_to_string`].
+pub const CURSOR: char = '|';
+
+/// Render the input in the given width, as the widgets would, into a plain
+/// string with the cursor marked by [`CURSOR`]. Useful for snapshot tests.
+///
+/// The value is masked and scrolled to keep the cursor in view, with one
+/// column kept for the cursor at the end. When the value is empty, the default
+/// value is shown as `[default]`, or else the placeholder as `(placeholder)`.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::testing::render_to_string;
+/// use tui_input::Input;
+///
+/// let input = Input::from("Hello World").with_cursor(3);
+/// assert_eq!(render_to_string(&input, 20), "Hel|lo World");
+/// assert_eq!(render_to_string(&input.with_cursor(11), 6), "World|");
+///
+/// let input = Input::default().with_placeholder("Name".into());
+/// assert_eq!(render_to_string(&input, 10), "|(Name)");
+/// ```
+pub fn render_to_string(input: &Input, width: u16) -> String {
+    let width = width.max(1) as usize;
+    if input.value().is_empty() {
+        let hint = match (input.default_value(), input.placeholder()) {
+            (Some(default), _) => format!("[{default}]"),
+            (None, Some(placeholder)) => format!("({placeholder})"),
+            (None, None) => String::new(),
+        };
+        return truncate(&format!("{CURSOR}{hint}"), width + 1);
+    }
+
+    let value = input.display_value();
+    let scroll = input.visual_scroll(width - 1);
+    let cursor = input.visual_cursor();
+    let mut rendered = String::new();
+    let mut column = 0;
+    for g in value.graphemes(true) {
+        let g_width = g.width();
+        if column >= scroll {
+            if column == cursor {
+                rendered.push(CURSOR);
+            }
+            if column + g_width > scroll + width {
+                return rendered;
+            }
+            rendered.push_str(g);
+        }
+        column += g_width;
+    }
+    if column == cursor {
+        rendered.push(CURSOR);
+    }
+    rendered
+}
+
+/// Keep the graphemes fitting in the given width.
+fn truncate(text: &str, width: usize) -> String {
+    let mut column = 0;
+    text.graphemes(true)
+        .take_while(|g| {
+            column += g.width();
+            column <= width
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let input = Input::from("ａｂｃ").with_cursor(2);
+        assert_eq!(render_to_string(&input, 7), "ａｂ|ｃ");
+        assert_eq!(render_to_string(&input, 4), "ｂ|ｃ");
+        assert_eq!(render_to_string(&input, 3), "ｂ|");
+
+        let input = Input::from("secret").with_mask('*');
+        assert_eq!(render_to_string(&input, 4), "***|");
+
+        let input = Input::default().with_default_value("World".into());
+        assert_eq!(render_to_string(&input, 3), "|[Wo");
+    }
+}
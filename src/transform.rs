@@ -0,0 +1,74 @@
+//! Canonicalization of the input value as it's edited.
+
+use std::fmt;
+use std::sync::Arc;
+
+type TransformFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// A function canonicalizing the value, applied by [`Input::handle`] whenever a
+/// request changes the value. It's also applied to the text before the cursor,
+/// to find where the cursor goes in the transformed value.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::{Input, InputRequest, Transform};
+///
+/// let mut input = Input::default().with_transform(Transform::uppercase());
+/// input.handle(InputRequest::InsertChar('a'));
+/// assert_eq!(input.value(), "A");
+/// ```
+///
+/// [`Input::handle`]: crate::Input::handle
+#[derive(Clone)]
+pub struct Transform(Arc<TransformFn>);
+
+impl Transform {
+    /// Initialize a new transform from the given function.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Transform the given value.
+    pub fn apply(&self, value: &str) -> String {
+        (self.0)(value)
+    }
+
+    /// Uppercase everything, e.g. for license keys.
+    pub fn uppercase() -> Self {
+        Self::new(str::to_uppercase)
+    }
+
+    /// Lowercase everything, e.g. for emails or usernames.
+    pub fn lowercase() -> Self {
+        Self::new(str::to_lowercase)
+    }
+
+    /// Uppercase the first char, e.g. for names.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Transform;
+    ///
+    /// assert_eq!(Transform::capitalize().apply("élan vital"), "Élan vital");
+    /// ```
+    pub fn capitalize() -> Self {
+        Self::new(|value| {
+            let mut chars = value.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+    }
+}
+
+impl fmt::Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transform")
+    }
+}
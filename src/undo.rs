@@ -0,0 +1,295 @@
+//! Undo history of the input value.
+
+use std::time::{Duration, Instant};
+
+/// The value and the cursor saved by an undo step.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub value: String,
+    pub cursor: usize,
+}
+
+/// The kind of an edit, used to group consecutive edits of the same kind into
+/// a single undo step.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EditKind {
+    /// Typing the given char.
+    Insert(char),
+    /// Deleting a single char.
+    Delete,
+    /// Any other edit, never grouped.
+    Other,
+}
+
+/// Rules to group consecutive typing (or deleting) into a single undo step.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coalescing {
+    /// Group the edits at all, otherwise every edit is its own step.
+    pub enabled: bool,
+    /// Start a new step when typing a word after whitespace, so that each
+    /// word is undone separately.
+    pub break_on_whitespace: bool,
+    /// Start a new step when the time since the last edit exceeds it.
+    pub max_gap: Option<Duration>,
+}
+
+impl Default for Coalescing {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            break_on_whitespace: true,
+            max_gap: Some(Duration::from_secs(1)),
+        }
+    }
+}
+
+impl Coalescing {
+    /// Never group edits, every edit is undone separately.
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+}
+
+/// A state of the undo tree.
+#[derive(Debug, Default, Clone)]
+pub struct UndoNode {
+    snapshot: Snapshot,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    redo_child: Option<usize>,
+}
+
+impl UndoNode {
+    /// Get the saved state. It's outdated for the current node, which is the
+    /// live value of the input.
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Get the index of the state this one was edited from.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Get the indices of the states edited from this one, oldest first.
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
+}
+
+/// Undo history of an input, kept as a tree so that editing after undoing
+/// starts a new branch instead of dropping the undone steps.
+///
+/// Redo follows the branch that was last undone.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::{Input, InputRequest};
+///
+/// let mut input: Input = "a".into();
+/// input.handle(InputRequest::InsertChar('b'));
+/// input.handle(InputRequest::Undo);
+/// input.handle(InputRequest::InsertChar('c'));
+///
+/// let history = input.undo_history();
+/// assert_eq!(history.branches().count(), 2);
+///
+/// let first = history.branches().next().unwrap();
+/// input.undo_to(first);
+/// assert_eq!(input.value(), "ab");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct UndoHistory {
+    nodes: Vec<UndoNode>,
+    current: usize,
+    coalescing: Coalescing,
+    group: Option<(EditKind, Instant)>,
+}
+
+impl UndoHistory {
+    /// Initialize an empty history with the given grouping rules.
+    pub fn new(coalescing: Coalescing) -> Self {
+        Self {
+            coalescing,
+            ..Default::default()
+        }
+    }
+
+    /// Get the grouping rules.
+    pub fn coalescing(&self) -> Coalescing {
+        self.coalescing
+    }
+
+    /// Record an edit of the given kind, made from the given state.
+    pub fn record(&mut self, before: Snapshot, kind: EditKind) {
+        let now = Instant::now();
+        if self.nodes.is_empty() {
+            self.nodes.push(UndoNode::default());
+        } else if self.continues_group(kind, now) {
+            self.group = Some((kind, now));
+            return;
+        }
+        self.nodes[self.current].snapshot = before;
+        let child = self.nodes.len();
+        self.nodes.push(UndoNode {
+            parent: Some(self.current),
+            ..Default::default()
+        });
+        self.nodes[self.current].children.push(child);
+        self.nodes[self.current].redo_child = Some(child);
+        self.current = child;
+        self.group = (kind != EditKind::Other).then_some((kind, now));
+    }
+
+    /// Check if the edit of the given kind belongs to the current group.
+    fn continues_group(&self, kind: EditKind, now: Instant) -> bool {
+        let Some((last, at)) = self.group.filter(|_| self.coalescing.enabled) else {
+            return false;
+        };
+        if self
+            .coalescing
+            .max_gap
+            .is_some_and(|gap| now.duration_since(at) > gap)
+        {
+            return false;
+        }
+        match (last, kind) {
+            (EditKind::Insert(prev), EditKind::Insert(c)) => {
+                !(self.coalescing.break_on_whitespace
+                    && prev.is_whitespace()
+                    && !c.is_whitespace())
+            }
+            (EditKind::Delete, EditKind::Delete) => true,
+            _ => false,
+        }
+    }
+
+    /// End the current group, e.g. when the cursor moves.
+    pub fn break_group(&mut self) {
+        self.group = None;
+    }
+
+    /// Get the state to restore to undo the last step, saving the current one
+    /// for redo.
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let parent = self.nodes.get(self.current)?.parent?;
+        self.nodes[parent].redo_child = Some(self.current);
+        self.go_to(parent, current)
+    }
+
+    /// Get the state to restore to redo the last undone step, saving the
+    /// current one for undo.
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let child = self.nodes.get(self.current)?.redo_child?;
+        self.go_to(child, current)
+    }
+
+    /// Get the state of the given node to restore, saving the current one.
+    pub fn go_to(&mut self, node: usize, current: Snapshot) -> Option<Snapshot> {
+        if node >= self.nodes.len() {
+            return None;
+        }
+        self.nodes[self.current].snapshot = current;
+        self.current = node;
+        self.group = None;
+        Some(self.nodes[node].snapshot.clone())
+    }
+
+    /// Check if there's a step to undo.
+    pub fn can_undo(&self) -> bool {
+        self.nodes
+            .get(self.current)
+            .is_some_and(|n| n.parent.is_some())
+    }
+
+    /// Check if there's a step to redo.
+    pub fn can_redo(&self) -> bool {
+        self.nodes
+            .get(self.current)
+            .is_some_and(|n| n.redo_child.is_some())
+    }
+
+    /// Get all the states, in the order they were created. The first one is
+    /// the root.
+    pub fn nodes(&self) -> &[UndoNode] {
+        &self.nodes
+    }
+
+    /// Get the index of the current state, if anything was recorded.
+    pub fn current(&self) -> Option<usize> {
+        (!self.nodes.is_empty()).then_some(self.current)
+    }
+
+    /// Iterate over the tips of the branches, oldest first.
+    pub fn branches(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.children.is_empty() && n.parent.is_some())
+            .map(|(i, _)| i)
+    }
+
+    /// Remove all the steps.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.current = 0;
+        self.group = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(value: &str) -> Snapshot {
+        Snapshot {
+            value: value.into(),
+            cursor: value.chars().count(),
+        }
+    }
+
+    #[test]
+    fn coalesce_words() {
+        let mut history = UndoHistory::default();
+        for (i, c) in "ab c".char_indices() {
+            history.record(snapshot(&"ab c"[..i]), EditKind::Insert(c));
+        }
+
+        assert_eq!(history.undo(snapshot("ab c")), Some(snapshot("ab ")));
+        assert_eq!(history.undo(snapshot("ab ")), Some(snapshot("")));
+        assert_eq!(history.undo(snapshot("")), None);
+        assert_eq!(history.redo(snapshot("")), Some(snapshot("ab ")));
+    }
+
+    #[test]
+    fn no_coalescing() {
+        let mut history = UndoHistory::new(Coalescing::disabled());
+        history.record(snapshot(""), EditKind::Insert('a'));
+        history.record(snapshot("a"), EditKind::Insert('b'));
+        assert_eq!(history.undo(snapshot("ab")), Some(snapshot("a")));
+    }
+
+    #[test]
+    fn branches() {
+        let mut history = UndoHistory::new(Coalescing::disabled());
+        history.record(snapshot(""), EditKind::Other);
+        history.record(snapshot("a"), EditKind::Other);
+        assert_eq!(history.undo(snapshot("ab")), Some(snapshot("a")));
+
+        history.record(snapshot("a"), EditKind::Other);
+        assert_eq!(history.branches().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(history.nodes()[1].children(), &[2, 3]);
+
+        assert_eq!(history.undo(snapshot("ac")), Some(snapshot("a")));
+        assert_eq!(history.redo(snapshot("a")), Some(snapshot("ac")));
+        assert_eq!(history.go_to(2, snapshot("ac")), Some(snapshot("ab")));
+        assert!(!history.can_redo());
+    }
+}
@@ -0,0 +1,200 @@
+//! Validation of the input value.
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+type ValidateFn = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+/// A function validating the value, returning an error message when it's
+/// invalid.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::{Input, Validator};
+///
+/// let validator = Validator::new(|value| {
+///     if value.is_empty() {
+///         Err("required".into())
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// let input = Input::default().with_validator(validator);
+/// assert_eq!(input.validate(), Err("required".into()));
+/// ```
+#[derive(Clone)]
+pub struct Validator(Arc<ValidateFn>);
+
+impl Validator {
+    /// Initialize a new validator from the given function.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Validate the given value.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+/// Ready-made validators for common formats.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::Validator;
+///
+/// let port = Validator::integer(1..=65535);
+/// assert_eq!(port.validate("8080"), Ok(()));
+/// assert!(port.validate("0").is_err());
+///
+/// assert!(Validator::email().validate("hi@arijitbasu.in").is_ok());
+/// assert!(Validator::ipv4().validate("256.0.0.1").is_err());
+/// ```
+impl Validator {
+    /// Reject empty or whitespace-only values.
+    pub fn non_empty() -> Self {
+        Self::new(|value| match value.trim() {
+            "" => Err("required".into()),
+            _ => Ok(()),
+        })
+    }
+
+    /// Accept integers within the given range.
+    pub fn integer<R>(range: R) -> Self
+    where
+        R: RangeBounds<i64> + Send + Sync + 'static,
+    {
+        Self::new(move |value| match value.parse::<i64>() {
+            Ok(n) if range.contains(&n) => Ok(()),
+            Ok(_) => Err(format!("must be {}", describe_range(&range))),
+            Err(_) => Err("must be an integer".into()),
+        })
+    }
+
+    /// Accept finite decimal numbers.
+    pub fn float() -> Self {
+        Self::new(|value| match value.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(()),
+            _ => Err("must be a number".into()),
+        })
+    }
+
+    /// Accept email addresses like `name@example.com`.
+    pub fn email() -> Self {
+        Self::new(|value| {
+            let valid = value.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && domain
+                        .split('.')
+                        .all(|part| !part.is_empty() && is_host_part(part))
+                    && domain.contains('.')
+                    && !value.chars().any(char::is_whitespace)
+            });
+            valid.then_some(()).ok_or_else(|| "invalid email".into())
+        })
+    }
+
+    /// Accept URLs like `https://example.com/path`.
+    pub fn url() -> Self {
+        Self::new(|value| {
+            let valid = value.split_once("://").is_some_and(|(scheme, rest)| {
+                let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+                scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && scheme
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+                    && !host.is_empty()
+                    && !value.chars().any(char::is_whitespace)
+            });
+            valid.then_some(()).ok_or_else(|| "invalid URL".into())
+        })
+    }
+
+    /// Accept IPv4 addresses.
+    pub fn ipv4() -> Self {
+        Self::new(|value| {
+            value
+                .parse::<Ipv4Addr>()
+                .map(|_| ())
+                .map_err(|_| "invalid IPv4 address".into())
+        })
+    }
+
+    /// Accept IPv6 addresses.
+    pub fn ipv6() -> Self {
+        Self::new(|value| {
+            value
+                .parse::<Ipv6Addr>()
+                .map(|_| ())
+                .map_err(|_| "invalid IPv6 address".into())
+        })
+    }
+}
+
+fn is_host_part(part: &str) -> bool {
+    part.chars().all(|c| c.is_alphanumeric() || c == '-')
+        && !part.starts_with('-')
+        && !part.ends_with('-')
+}
+
+fn describe_range<R: RangeBounds<i64>>(range: &R) -> String {
+    let min = match range.start_bound() {
+        Bound::Included(n) => Some(*n),
+        Bound::Excluded(n) => Some(n.saturating_add(1)),
+        Bound::Unbounded => None,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(n) => Some(*n),
+        Bound::Excluded(n) => Some(n.saturating_sub(1)),
+        Bound::Unbounded => None,
+    };
+    match (min, max) {
+        (Some(min), Some(max)) => format!("between {min} and {max}"),
+        (Some(min), None) => format!("at least {min}"),
+        (None, Some(max)) => format!("at most {max}"),
+        (None, None) => "an integer".into(),
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_validators() {
+        assert!(Validator::non_empty().validate(" ").is_err());
+        assert_eq!(
+            Validator::integer(..10).validate("10"),
+            Err("must be at most 9".into())
+        );
+        assert!(Validator::float().validate("1e3").is_ok());
+        assert!(Validator::float().validate("inf").is_err());
+
+        let email = Validator::email();
+        assert!(email.validate("a@b.co").is_ok());
+        assert!(email.validate("a@b").is_err());
+        assert!(email.validate("a b@c.d").is_err());
+
+        let url = Validator::url();
+        assert!(url.validate("https://example.com?q=1").is_ok());
+        assert!(url.validate("example.com").is_err());
+        assert!(url.validate("https:///path").is_err());
+
+        assert!(Validator::ipv6().validate("::1").is_ok());
+    }
+}
@@ -0,0 +1,1550 @@
+//! Helpers for rendering the input with [ratatui](https://github.com/ratatui/ratatui).
+
+use crate::color::parse_hex_color;
+use crate::completion::CompletionState;
+use crate::palette::CommandPalette;
+use crate::spinner::Spinner;
+use crate::Input;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Position, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Styles used to render the different parts of the input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct StyleOptions {
+    /// Style of the value.
+    pub value: Style,
+    /// Style of the character under the cursor.
+    pub cursor: Style,
+    /// Style patched over the highlighted ranges.
+    pub highlight: Style,
+    /// Style patched over the selection.
+    pub selection: Style,
+    /// Style patched over the misspelled words, see
+    /// [`Input::with_spell_checker`].
+    pub misspelled: Style,
+    /// Style patched over the bracket at the cursor and its matching bracket,
+    /// see [`Input::matching_bracket`].
+    pub matching_bracket: Style,
+}
+
+impl Default for StyleOptions {
+    fn default() -> Self {
+        Self {
+            value: Style::default(),
+            cursor: Style::default().add_modifier(Modifier::REVERSED),
+            highlight: Style::default().bg(Color::Yellow).fg(Color::Black),
+            selection: Style::default().bg(Color::Blue).fg(Color::White),
+            misspelled: Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::UNDERLINED),
+            matching_bracket: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+impl StyleOptions {
+    /// Set the style of the value.
+    pub fn value(mut self, style: Style) -> Self {
+        self.value = style;
+        self
+    }
+
+    /// Set the style of the character under the cursor.
+    pub fn cursor(mut self, style: Style) -> Self {
+        self.cursor = style;
+        self
+    }
+
+    /// Set the style patched over the highlighted ranges.
+    pub fn highlight(mut self, style: Style) -> Self {
+        self.highlight = style;
+        self
+    }
+
+    /// Set the style patched over the selection.
+    pub fn selection(mut self, style: Style) -> Self {
+        self.selection = style;
+        self
+    }
+
+    /// Set the style patched over the misspelled words.
+    pub fn misspelled(mut self, style: Style) -> Self {
+        self.misspelled = style;
+        self
+    }
+
+    /// Set the style patched over the matching brackets.
+    pub fn matching_bracket(mut self, style: Style) -> Self {
+        self.matching_bracket = style;
+        self
+    }
+}
+
+impl Input {
+    /// Split the value into styled spans around the cursor, the selection and
+    /// the highlighted ranges.
+    ///
+    /// When the cursor is at the end of the value, a trailing space is added to
+    /// render it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use tui_input::Input;
+    /// use tui_input::widget::StyleOptions;
+    ///
+    /// let input = Input::from("Hello").with_cursor(1);
+    /// let line = input.to_line(&StyleOptions::default());
+    ///
+    /// assert_eq!(line.spans.len(), 3);
+    /// assert_eq!(line.spans[1].content, "e");
+    /// ```
+    pub fn to_line(&self, opts: &StyleOptions) -> Line<'_> {
+        let value = self.display_value();
+        let len = value.chars().count();
+        let cursor = self.cursor();
+
+        let selected = self.selected_ranges();
+        let misspellings = self.misspellings();
+        let brackets = match self.is_masked() {
+            true => None,
+            false => self.matching_bracket(),
+        };
+        let brackets = brackets.map_or([len, len], |(a, b)| [a, b]);
+        let mut bounds = vec![0, cursor, cursor + 1, len];
+        for i in brackets {
+            bounds.push(i);
+            bounds.push(i + 1);
+        }
+        for range in self
+            .highlights()
+            .iter()
+            .chain(&selected)
+            .chain(&misspellings)
+        {
+            bounds.push(range.start);
+            bounds.push(range.end);
+        }
+        bounds.retain(|b| *b <= len);
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let mut spans = Vec::with_capacity(bounds.len());
+        for window in bounds.windows(2) {
+            let (from, to) = (window[0], window[1]);
+            let mut style = opts.value;
+            if misspellings.iter().any(|r| r.contains(&from)) {
+                style = style.patch(opts.misspelled);
+            }
+            if self.highlights().iter().any(|r| r.contains(&from)) {
+                style = style.patch(opts.highlight);
+            }
+            if brackets.contains(&from) && from < len {
+                style = style.patch(opts.matching_bracket);
+            }
+            if selected.iter().any(|r| r.contains(&from)) {
+                style = style.patch(opts.selection);
+            }
+            if from == cursor {
+                style = style.patch(opts.cursor);
+            }
+            let range = byte_index(&value, from)..byte_index(&value, to);
+            let content = match &value {
+                Cow::Borrowed(value) => Cow::Borrowed(&value[range]),
+                Cow::Owned(value) => Cow::Owned(value[range].to_string()),
+            };
+            spans.push(Span::styled(content, style));
+        }
+        if cursor == len {
+            spans.push(Span::styled(" ", opts.value.patch(opts.cursor)));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Input {
+    /// Split the value into styled lines, like [`Input::to_line`] but breaking
+    /// at the newlines of a multi-line value.
+    ///
+    /// A cursor on a newline is rendered as a space at the end of its line.
+    pub fn to_lines(&self, opts: &StyleOptions) -> Vec<Line<'_>> {
+        let cursor = self.cursor();
+        let mut lines = vec![Line::default()];
+        let mut index = 0;
+        for span in self.to_line(opts).spans {
+            let mut parts = span.content.split('\n').peekable();
+            while let Some(part) = parts.next() {
+                let line = lines.last_mut().expect("there's always a line");
+                if !part.is_empty() {
+                    line.spans.push(Span::styled(part.to_string(), span.style));
+                }
+                index += part.chars().count();
+                if parts.peek().is_some() {
+                    if index == cursor {
+                        line.spans.push(Span::styled(" ", span.style));
+                    }
+                    index += 1;
+                    lines.push(Line::default());
+                }
+            }
+        }
+        lines
+    }
+}
+
+fn byte_index(value: &str, char_index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(char_index)
+        .map_or_else(|| value.len(), |(index, _)| index)
+}
+
+/// A ratatui widget rendering an [`Input`] on a single line.
+///
+/// Example:
+///
+/// ```
+/// use ratatui::widgets::{Block, Borders};
+/// use tui_input::widget::InputWidget;
+/// use tui_input::Input;
+///
+/// let input = Input::default().with_placeholder("Search...".into());
+/// let widget = InputWidget::new(&input).block(Block::default().borders(Borders::ALL));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InputWidget<'a> {
+    input: &'a Input,
+    block: Option<Block<'a>>,
+    styles: StyleOptions,
+    placeholder_style: Style,
+    default_style: Style,
+    suggestion_style: Style,
+    alignment: Alignment,
+    prefix: Line<'a>,
+    suffix: Line<'a>,
+    counter: bool,
+    error_style: Style,
+    show_error: bool,
+    cursor_visible: bool,
+    color_swatch: bool,
+}
+
+impl<'a> InputWidget<'a> {
+    /// Initialize a new widget for the given input.
+    pub fn new(input: &'a Input) -> Self {
+        Self {
+            input,
+            block: None,
+            styles: StyleOptions::default(),
+            placeholder_style: Style::default()
+                .add_modifier(Modifier::DIM | Modifier::ITALIC),
+            default_style: Style::default().add_modifier(Modifier::DIM),
+            suggestion_style: Style::default().add_modifier(Modifier::DIM),
+            alignment: Alignment::Left,
+            prefix: Line::default(),
+            suffix: Line::default(),
+            counter: false,
+            error_style: Style::default().fg(Color::Red),
+            show_error: false,
+            cursor_visible: true,
+            color_swatch: false,
+        }
+    }
+
+    /// Wrap the input in the given block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the styles used to render the value and the cursor.
+    pub fn styles(mut self, styles: StyleOptions) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Set the style used to render the placeholder.
+    pub fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// Set the style used to render the default value, see
+    /// [`Input::with_default_value`].
+    pub fn default_style(mut self, style: Style) -> Self {
+        self.default_style = style;
+        self
+    }
+
+    /// Set the style used to render the suggestion after the value, see
+    /// [`Input::set_suggestion`].
+    pub fn suggestion_style(mut self, style: Style) -> Self {
+        self.suggestion_style = style;
+        self
+    }
+
+    /// Set the alignment of the value when it fits in the available width.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set a static prefix rendered before the editable region, e.g. `"> "`.
+    pub fn prefix<T: Into<Line<'a>>>(mut self, prefix: T) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set a static suffix rendered after the editable region, e.g. `" MB"`.
+    pub fn suffix<T: Into<Line<'a>>>(mut self, suffix: T) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Show the length counter (e.g. `"12/140"`) in the bottom right corner of
+    /// the block, when the input has a max length.
+    pub fn counter(mut self, counter: bool) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Set the style of the block border (and the error message) when the
+    /// value is invalid.
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Show the validation error message in the bottom left corner of the
+    /// block, when the value is invalid.
+    pub fn show_error(mut self, show_error: bool) -> Self {
+        self.show_error = show_error;
+        self
+    }
+
+    /// Render a swatch of the color after the value, when it's a hex color like
+    /// `#ff8800`. See [`ColorInput`](crate::color::ColorInput).
+    pub fn color_swatch(mut self, color_swatch: bool) -> Self {
+        self.color_swatch = color_swatch;
+        self
+    }
+
+    /// Set whether the cursor is rendered with the cursor style, see
+    /// [`CursorBlink`](crate::blink::CursorBlink).
+    pub fn cursor_visible(mut self, visible: bool) -> Self {
+        self.cursor_visible = visible;
+        self
+    }
+
+    /// Get the area inside the block, excluding the prefix and the suffix.
+    fn editable(&self, area: Rect) -> Rect {
+        let mut inner = self.block.as_ref().map_or(area, |b| b.inner(area));
+        let prefix = (self.prefix.width() as u16).min(inner.width);
+        inner.x += prefix;
+        inner.width -= prefix;
+        inner.width -= (self.suffix.width() as u16).min(inner.width);
+        if self.color_swatch {
+            inner.width -= SWATCH_WIDTH.min(inner.width);
+        }
+        inner
+    }
+
+    /// Get the area the value will be rendered in, after alignment.
+    fn inner(&self, area: Rect) -> Rect {
+        let mut inner = self.editable(area);
+        let len = if self.input.value().is_empty() {
+            self.hint().map_or(0, |(hint, _)| hint.width())
+        } else {
+            UnicodeWidthStr::width(self.input.display_value().as_ref())
+        };
+        let free = inner.width.max(1) as usize - 1;
+        let free = free.max(len) - len;
+        let padding = match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => free / 2,
+            Alignment::Right => free,
+        } as u16;
+        inner.x += padding;
+        inner.width -= padding;
+        inner
+    }
+
+    /// Get the horizontal scroll for the given inner width, keeping 1 column
+    /// for the cursor.
+    fn scroll(&self, width: u16) -> usize {
+        self.input.visual_scroll(width.max(1) as usize - 1)
+    }
+
+    /// Get the terminal cursor position when rendered in the given area.
+    pub fn cursor_position(&self, area: Rect) -> Position {
+        let inner = self.inner(area);
+        let scroll = self.scroll(inner.width);
+        let x = self.input.visual_cursor().max(scroll) - scroll;
+        Position::new(inner.x + x as u16, inner.y)
+    }
+
+    /// Get the char index of the value rendered at the given terminal position,
+    /// e.g. to move the cursor on mouse click.
+    pub fn char_index_at(&self, area: Rect, position: Position) -> Option<usize> {
+        let inner = self.inner(area);
+        if position.y != inner.y || position.x < inner.x || position.x >= inner.right() {
+            return None;
+        }
+        let scroll = self.scroll(inner.width);
+        let x = (position.x - inner.x) as usize;
+        Some(self.input.char_index_at_visual_x(x, scroll))
+    }
+
+    fn line(&self) -> Line<'a> {
+        match self.hint() {
+            Some((hint, style)) => {
+                let split = hint.chars().next().map_or(0, char::len_utf8);
+                let (head, tail) = hint.split_at(split);
+                let head = if head.is_empty() { " " } else { head };
+                Line::from(vec![
+                    Span::styled(
+                        head.to_string(),
+                        style.patch(self.render_styles().cursor),
+                    ),
+                    Span::styled(tail.to_string(), style),
+                ])
+            }
+            None => {
+                let styles = self.render_styles();
+                let mut line = self.input.to_line(&styles);
+                let at_end = self.input.cursor() == self.input.char_len();
+                if let Some(suggestion) = self.input.suggestion().filter(|_| at_end) {
+                    // Render the cursor over the first char of the suggestion.
+                    line.spans.pop();
+                    let split = suggestion.chars().next().map_or(0, char::len_utf8);
+                    let (head, tail) = suggestion.split_at(split);
+                    let style = self.suggestion_style;
+                    line.spans.push(Span::styled(
+                        head.to_string(),
+                        style.patch(styles.cursor),
+                    ));
+                    line.spans.push(Span::styled(tail.to_string(), style));
+                }
+                line
+            }
+        }
+    }
+
+    fn render_styles(&self) -> StyleOptions {
+        cursor_styles(self.styles, self.cursor_visible)
+    }
+
+    /// Get the text shown instead of an empty value: the default value, or the
+    /// placeholder.
+    fn hint(&self) -> Option<(Cow<'a, str>, Style)> {
+        if !self.input.value().is_empty() {
+            return None;
+        }
+        match (self.input.default_value(), self.input.placeholder()) {
+            (Some(default), _) => {
+                Some((format!("[{default}]").into(), self.default_style))
+            }
+            (None, Some(placeholder)) => {
+                Some((placeholder.into(), self.placeholder_style))
+            }
+            (None, None) => None,
+        }
+    }
+}
+
+impl Widget for InputWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area);
+        let line = self.line();
+        let scroll = self.scroll(inner.width);
+        let editable = self.editable(area);
+        if let Some(mut block) = self.block {
+            if let Some(counter) =
+                self.input.len_info().counter().filter(|_| self.counter)
+            {
+                block = block.title_bottom(Line::from(counter).right_aligned());
+            }
+            if let Err(err) = self.input.validate() {
+                block = block.border_style(self.error_style);
+                if self.show_error {
+                    block = block.title_bottom(Line::styled(err, self.error_style));
+                }
+            }
+            block.render(area, buf);
+        }
+        let width = self.prefix.width() as u16;
+        buf.set_line(editable.x - width, editable.y, &self.prefix, width);
+        let mut suffix_x = editable.right();
+        if self.color_swatch {
+            if let Ok((r, g, b)) = parse_hex_color(self.input.value()) {
+                let swatch = Rect::new(suffix_x + 1, editable.y, SWATCH_WIDTH - 1, 1);
+                let style = Style::default().bg(Color::Rgb(r, g, b));
+                buf.set_style(swatch.intersection(area), style);
+            }
+            suffix_x += SWATCH_WIDTH;
+        }
+        let width = self.suffix.width() as u16;
+        buf.set_line(suffix_x, editable.y, &self.suffix, width);
+        Paragraph::new(line)
+            .scroll((0, scroll as u16))
+            .render(inner, buf);
+    }
+}
+
+/// Width of the color swatch, including the space before it.
+const SWATCH_WIDTH: u16 = 3;
+
+/// A widget rendering the validation error of an input, e.g. on the line
+/// under it. Nothing is rendered while the value is valid.
+///
+/// Messages wider than the area are truncated with an ellipsis.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::widget::ValidationMessage;
+/// use tui_input::{Input, Validator};
+///
+/// let input = Input::default().with_validator(Validator::non_empty());
+/// let message = ValidationMessage::new(&input).prefix("✗ ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidationMessage<'a> {
+    input: &'a Input,
+    style: Style,
+    prefix: Cow<'a, str>,
+    ellipsis: Cow<'a, str>,
+}
+
+impl<'a> ValidationMessage<'a> {
+    /// Initialize a new widget for the given input.
+    pub fn new(input: &'a Input) -> Self {
+        Self {
+            input,
+            style: Style::default().fg(Color::Red),
+            prefix: Cow::Borrowed(""),
+            ellipsis: Cow::Borrowed("…"),
+        }
+    }
+
+    /// Set the style of the message.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the text rendered before the message, e.g. `"✗ "`.
+    pub fn prefix<T: Into<Cow<'a, str>>>(mut self, prefix: T) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set the text ending a truncated message.
+    pub fn ellipsis<T: Into<Cow<'a, str>>>(mut self, ellipsis: T) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Get the message as rendered in the given width, if the value is
+    /// invalid.
+    pub fn message(&self, width: u16) -> Option<String> {
+        let err = self.input.validate().err()?;
+        let message = format!("{}{}", self.prefix, err);
+        let width = width as usize;
+        if message.width() <= width {
+            return Some(message);
+        }
+        let room = width.saturating_sub(self.ellipsis.width());
+        let mut truncated = String::new();
+        for g in message.graphemes(true) {
+            if truncated.width() + g.width() > room {
+                break;
+            }
+            truncated.push_str(g);
+        }
+        truncated.push_str(&self.ellipsis);
+        Some(truncated)
+    }
+}
+
+impl Widget for ValidationMessage<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(message) = self.message(area.width) {
+            buf.set_stringn(area.x, area.y, message, area.width as usize, self.style);
+        }
+    }
+}
+
+/// Get the styles rendering the cursor like the other chars when it's hidden.
+fn cursor_styles(styles: StyleOptions, cursor_visible: bool) -> StyleOptions {
+    if cursor_visible {
+        styles
+    } else {
+        styles.cursor(Style::default())
+    }
+}
+
+/// How the line numbers of a [`TextAreaWidget`] are displayed.
+#[derive(Debug, Default, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LineNumbers {
+    /// No line numbers.
+    #[default]
+    Hidden,
+    /// The number of each line, starting from 1.
+    Absolute,
+    /// The distance of each line from the cursor line, which shows its number.
+    Relative,
+}
+
+/// A ratatui widget rendering a multi-line [`Input`], with an optional gutter
+/// of line numbers.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::widget::{LineNumbers, TextAreaWidget};
+/// use tui_input::Input;
+///
+/// let input = Input::from("first\nsecond").with_multiline(true);
+/// let widget = TextAreaWidget::new(&input).line_numbers(LineNumbers::Relative);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextAreaWidget<'a> {
+    input: &'a Input,
+    block: Option<Block<'a>>,
+    styles: StyleOptions,
+    line_numbers: LineNumbers,
+    line_number_style: Style,
+    line_number_width: Option<u16>,
+    cursor_visible: bool,
+}
+
+impl<'a> TextAreaWidget<'a> {
+    /// Initialize a new widget for the given input.
+    pub fn new(input: &'a Input) -> Self {
+        Self {
+            input,
+            block: None,
+            styles: StyleOptions::default(),
+            line_numbers: LineNumbers::Hidden,
+            line_number_style: Style::default().add_modifier(Modifier::DIM),
+            line_number_width: None,
+            cursor_visible: true,
+        }
+    }
+
+    /// Wrap the input in the given block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the styles used to render the value and the cursor.
+    pub fn styles(mut self, styles: StyleOptions) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Set how the line numbers are displayed.
+    pub fn line_numbers(mut self, line_numbers: LineNumbers) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set the style of the line numbers.
+    pub fn line_number_style(mut self, style: Style) -> Self {
+        self.line_number_style = style;
+        self
+    }
+
+    /// Set the minimum width of the line numbers. By default, it fits the
+    /// number of lines.
+    pub fn line_number_width(mut self, width: u16) -> Self {
+        self.line_number_width = Some(width);
+        self
+    }
+
+    /// Set whether the cursor is rendered with the cursor style, see
+    /// [`CursorBlink`](crate::blink::CursorBlink).
+    pub fn cursor_visible(mut self, visible: bool) -> Self {
+        self.cursor_visible = visible;
+        self
+    }
+
+    /// Get the width of the gutter, including the separating space.
+    fn gutter_width(&self) -> u16 {
+        if self.line_numbers == LineNumbers::Hidden {
+            return 0;
+        }
+        let digits = self.input.lines().count().to_string().len() as u16;
+        digits.max(self.line_number_width.unwrap_or(0)) + 1
+    }
+
+    /// Get the area the text will be rendered in.
+    fn inner(&self, area: Rect) -> Rect {
+        let mut inner = self.block.as_ref().map_or(area, |b| b.inner(area));
+        let gutter = self.gutter_width().min(inner.width);
+        inner.x += gutter;
+        inner.width -= gutter;
+        inner
+    }
+
+    /// Get the vertical and horizontal scroll keeping the cursor in the given
+    /// inner area, starting from the viewport of the input.
+    fn scroll(&self, inner: Rect) -> (usize, usize) {
+        let (line, col) = self.input.cursor_line_col();
+        let text = self.input.lines().nth(line).unwrap_or_default();
+        let x = UnicodeWidthStr::width(&text[..byte_index(text, col)]);
+        let height = inner.height.max(1) as usize;
+        let top = self.input.viewport().top.min(line);
+        (
+            top.max(line + 1 - height.min(line + 1)),
+            x.max(inner.width.max(1) as usize - 1) + 1 - inner.width.max(1) as usize,
+        )
+    }
+
+    /// Get the terminal cursor position when rendered in the given area.
+    pub fn cursor_position(&self, area: Rect) -> Position {
+        let inner = self.inner(area);
+        let (top, left) = self.scroll(inner);
+        let (line, col) = self.input.cursor_line_col();
+        let text = self.input.lines().nth(line).unwrap_or_default();
+        let x = UnicodeWidthStr::width(&text[..byte_index(text, col)]);
+        Position::new(inner.x + (x - left) as u16, inner.y + (line - top) as u16)
+    }
+}
+
+impl Widget for TextAreaWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area);
+        let (top, left) = self.scroll(inner);
+        let gutter = self.gutter_width();
+        if let Some(block) = &self.block {
+            block.render(area, buf);
+        }
+
+        let cursor_line = self.input.cursor_line_col().0;
+        let lines = self.input.lines().count();
+        for (row, line) in (top..lines).take(inner.height as usize).enumerate() {
+            let number = match self.line_numbers {
+                LineNumbers::Hidden => break,
+                LineNumbers::Absolute => line + 1,
+                LineNumbers::Relative if line == cursor_line => line + 1,
+                LineNumbers::Relative => line.abs_diff(cursor_line),
+            };
+            let width = gutter as usize - 1;
+            buf.set_stringn(
+                inner.x - gutter,
+                inner.y + row as u16,
+                format!("{number:>width$}"),
+                width,
+                self.line_number_style,
+            );
+        }
+
+        Paragraph::new(
+            self.input
+                .to_lines(&cursor_styles(self.styles, self.cursor_visible)),
+        )
+        .scroll((top as u16, left as u16))
+        .render(inner, buf);
+    }
+}
+
+/// A popup listing the completion candidates, anchored under the cursor.
+///
+/// Render it over the whole frame area after the input, anchored at
+/// [`InputWidget::cursor_position`]. It's placed above the cursor when there's
+/// not enough room below.
+#[derive(Debug, Clone)]
+pub struct CompletionMenu<'a> {
+    state: &'a CompletionState,
+    anchor: Position,
+    max_height: u16,
+    block: Block<'a>,
+    style: Style,
+    selected_style: Style,
+}
+
+impl<'a> CompletionMenu<'a> {
+    /// Initialize a new menu for the given state, anchored at the given
+    /// cursor position.
+    pub fn new(state: &'a CompletionState, anchor: Position) -> Self {
+        Self {
+            state,
+            anchor,
+            max_height: 8,
+            block: Block::bordered(),
+            style: Style::default(),
+            selected_style: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Set the max number of candidates visible at once.
+    pub fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = max_height.max(1);
+        self
+    }
+
+    /// Set the block surrounding the candidates.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Set the style of the candidates.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the selected candidate.
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Get the area of the popup within the given bounds.
+    pub fn popup_area(&self, bounds: Rect) -> Rect {
+        let frame = Rect::new(0, 0, 100, 100);
+        let inner = self.block.inner(frame);
+        let (border_x, border_y) =
+            (frame.width - inner.width, frame.height - inner.height);
+
+        let items = self.state.completions();
+        let text_width = items
+            .iter()
+            .map(|c| UnicodeWidthStr::width(c.text.as_str()))
+            .max()
+            .unwrap_or(0) as u16;
+        let height = (items.len() as u16).min(self.max_height) + border_y;
+        let width = (text_width + border_x).min(bounds.width);
+
+        let below = self.anchor.y + 1;
+        let y = if below + height <= bounds.bottom() || self.anchor.y < bounds.y + height
+        {
+            below
+        } else {
+            self.anchor.y - height
+        };
+        let x = self.anchor.x.min(bounds.right() - width).max(bounds.x);
+        Rect::new(x, y, width, height).intersection(bounds)
+    }
+}
+
+impl Widget for CompletionMenu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(selected) = self.state.selected() else {
+            return;
+        };
+
+        let popup = self.popup_area(area);
+        let inner = self.block.inner(popup);
+        Clear.render(popup, buf);
+        self.block.render(popup, buf);
+
+        let visible = inner.height as usize;
+        let offset = (selected + 1).max(visible) - visible;
+        for (i, completion) in self
+            .state
+            .completions()
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+        {
+            let style = if i == selected {
+                self.selected_style
+            } else {
+                self.style
+            };
+            let row = Rect::new(inner.x, inner.y + (i - offset) as u16, inner.width, 1);
+            buf.set_style(row, style);
+            buf.set_stringn(row.x, row.y, &completion.text, row.width as usize, style);
+        }
+    }
+}
+
+/// A ratatui widget rendering a [`Spinner`], with stepping arrows on the right.
+///
+/// The arrows are dimmed when the value can't be stepped further.
+#[derive(Debug, Clone)]
+pub struct SpinnerWidget<'a> {
+    spinner: &'a Spinner,
+    input: InputWidget<'a>,
+    arrow_style: Style,
+}
+
+impl<'a> SpinnerWidget<'a> {
+    /// Initialize a new widget for the given spinner.
+    pub fn new(spinner: &'a Spinner) -> Self {
+        Self {
+            spinner,
+            input: InputWidget::new(spinner.input()).alignment(Alignment::Right),
+            arrow_style: Style::default(),
+        }
+    }
+
+    /// Customize the widget used to render the value.
+    pub fn input(mut self, f: impl FnOnce(InputWidget<'a>) -> InputWidget<'a>) -> Self {
+        self.input = f(self.input);
+        self
+    }
+
+    /// Set the style of the arrows.
+    pub fn arrow_style(mut self, style: Style) -> Self {
+        self.arrow_style = style;
+        self
+    }
+
+    /// Get the terminal cursor position when rendered in the given area.
+    pub fn cursor_position(&self, area: Rect) -> Position {
+        self.input.cursor_position(area)
+    }
+}
+
+impl Widget for SpinnerWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let arrow = |enabled: bool| {
+            if enabled {
+                self.arrow_style
+            } else {
+                self.arrow_style.add_modifier(Modifier::DIM)
+            }
+        };
+        let mut input = self.input;
+        input.suffix.spans.extend([
+            Span::styled("▲", arrow(self.spinner.can_increment())),
+            Span::styled("▼", arrow(self.spinner.can_decrement())),
+        ]);
+        input.render(area, buf);
+    }
+}
+
+/// A ratatui widget rendering a [`CommandPalette`]: the query on the first
+/// line, and the matching commands under it with the matched chars
+/// highlighted.
+///
+/// Example:
+///
+/// ```
+/// use tui_input::palette::CommandPalette;
+/// use tui_input::widget::CommandPaletteWidget;
+///
+/// let palette = CommandPalette::new(vec!["Open file", "Quit"]);
+/// let widget = CommandPaletteWidget::new(&palette).prefix("> ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandPaletteWidget<'a, T> {
+    palette: &'a CommandPalette<T>,
+    input: InputWidget<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    selected_style: Style,
+    match_style: Style,
+}
+
+impl<'a, T: AsRef<str>> CommandPaletteWidget<'a, T> {
+    /// Initialize a new widget for the given palette.
+    pub fn new(palette: &'a CommandPalette<T>) -> Self {
+        Self {
+            palette,
+            input: InputWidget::new(palette.input()),
+            block: Some(Block::bordered()),
+            style: Style::default(),
+            selected_style: Style::default().add_modifier(Modifier::REVERSED),
+            match_style: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Set a static prefix rendered before the query, e.g. `"> "`.
+    pub fn prefix<P: Into<Line<'a>>>(mut self, prefix: P) -> Self {
+        self.input = self.input.prefix(prefix);
+        self
+    }
+
+    /// Set the block surrounding the palette, or none.
+    pub fn block(mut self, block: Option<Block<'a>>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Set the style of the commands.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the selected command.
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Set the style patched over the matched chars.
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    fn inner(&self, area: Rect) -> Rect {
+        self.block.as_ref().map_or(area, |b| b.inner(area))
+    }
+
+    /// Get the terminal cursor position when rendered in the given area.
+    pub fn cursor_position(&self, area: Rect) -> Position {
+        let inner = self.inner(area);
+        self.input
+            .cursor_position(Rect::new(inner.x, inner.y, inner.width, 1))
+    }
+}
+
+impl<T: AsRef<str>> Widget for CommandPaletteWidget<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area);
+        if let Some(block) = self.block {
+            block.render(area, buf);
+        }
+        if inner.is_empty() {
+            return;
+        }
+        self.input
+            .render(Rect::new(inner.x, inner.y, inner.width, 1), buf);
+
+        let visible = inner.height as usize - 1;
+        let selected = self.palette.selected().unwrap_or(0);
+        let offset = (selected + 1).max(visible) - visible;
+        for (row, (i, (_, command, m))) in self
+            .palette
+            .matches()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .enumerate()
+        {
+            let style = if i == selected {
+                self.selected_style
+            } else {
+                self.style
+            };
+            let spans: Vec<_> = command
+                .as_ref()
+                .chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    if m.indices.contains(&j) {
+                        Span::styled(c.to_string(), style.patch(self.match_style))
+                    } else {
+                        Span::styled(c.to_string(), style)
+                    }
+                })
+                .collect();
+            let area = Rect::new(inner.x, inner.y + 1 + row as u16, inner.width, 1);
+            buf.set_style(area, style);
+            buf.set_line(area.x, area.y, &Line::from(spans), area.width);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette::PaletteRequest;
+    use crate::{InputRequest, Validator};
+    use ratatui::widgets::Borders;
+
+    #[test]
+    fn to_line_cursor_in_middle() {
+        let input = Input::from("¡test¡").with_cursor(1);
+        let opts = StyleOptions::default();
+        let line = input.to_line(&opts);
+
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("¡", opts.value),
+                Span::styled("t", opts.cursor),
+                Span::styled("est¡", opts.value),
+            ]
+        );
+    }
+
+    #[test]
+    fn validation_message() {
+        let mut input = Input::from("1").with_validator(Validator::integer(0..=99));
+        let message = ValidationMessage::new(&input).prefix("✗ ");
+        assert_eq!(message.message(10), None);
+
+        input.insert_char('x');
+        let message = ValidationMessage::new(&input).prefix("✗ ");
+        assert_eq!(message.message(30).as_deref(), Some("✗ must be an integer"));
+        assert_eq!(message.message(10).as_deref(), Some("✗ must be…"));
+        assert_eq!(message.message(0).as_deref(), Some("…"));
+    }
+
+    #[test]
+    fn to_line_highlights() {
+        let mut input = Input::from("one two three").with_cursor(5);
+        input.set_highlights(vec![4..7, 8..13]);
+        let opts = StyleOptions::default();
+        let line = input.to_line(&opts);
+
+        let highlight = opts.value.patch(opts.highlight);
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("one ", opts.value),
+                Span::styled("t", highlight),
+                Span::styled("w", highlight.patch(opts.cursor)),
+                Span::styled("o", highlight),
+                Span::styled(" ", opts.value),
+                Span::styled("three", highlight),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_lines_block_selection() {
+        let opts = StyleOptions::default();
+        let mut input = Input::from("abc\ndef").with_multiline(true).with_cursor(1);
+        input.handle(InputRequest::ToggleBlockSelection);
+        input.handle(InputRequest::GoToNextLine);
+        input.handle(InputRequest::GoToNextChar);
+
+        let lines = input.to_lines(&opts);
+        let selected = |line: &Line| {
+            line.spans
+                .iter()
+                .filter(|s| s.style.bg == opts.selection.bg)
+                .map(|s| s.content.to_string())
+                .collect::<String>()
+        };
+        assert_eq!(selected(&lines[0]), "b");
+        assert_eq!(selected(&lines[1]), "e");
+    }
+
+    #[test]
+    fn to_line_misspellings() {
+        let opts = StyleOptions::default();
+        let checker = |value: &str| -> Vec<std::ops::Range<usize>> {
+            let start = value.find("teh").map(|i| value[..i].chars().count());
+            start.map(|i| i..i + 3).into_iter().collect()
+        };
+        let underlined = |input: &Input| -> String {
+            let line = input.to_line(&opts);
+            line.spans
+                .iter()
+                .filter(|s| s.style.add_modifier.contains(Modifier::UNDERLINED))
+                .map(|s| s.content.to_string())
+                .collect()
+        };
+        let input = Input::from("ä teh b")
+            .with_cursor(0)
+            .with_spell_checker(checker);
+        assert_eq!(underlined(&input), "teh");
+
+        let mut input = input.with_mask('*');
+        assert_eq!(underlined(&input), "");
+        input.handle(InputRequest::ToggleMask);
+        assert_eq!(underlined(&input), "teh");
+    }
+
+    #[test]
+    fn to_line_matching_bracket() {
+        let opts = StyleOptions::default();
+        let input = Input::from("(a)b");
+        assert_eq!(input.to_line(&opts).spans[0].style, opts.value);
+
+        let input = input.with_cursor(3);
+        let bold: String = input
+            .to_line(&opts)
+            .spans
+            .iter()
+            .filter(|s| s.style.add_modifier.contains(Modifier::BOLD))
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(bold, "()");
+    }
+
+    #[test]
+    fn to_line_selection() {
+        let mut input = Input::from("one two");
+        input.set_selection(1, 5);
+        let opts = StyleOptions::default();
+        let line = input.to_line(&opts);
+
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("o", opts.value),
+                Span::styled("ne t", opts.value.patch(opts.selection)),
+                Span::styled("w", opts.cursor),
+                Span::styled("o", opts.value),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_line_masked() {
+        let input = Input::from("pass").with_mask('*').with_cursor(2);
+        let opts = StyleOptions::default();
+        let line = input.to_line(&opts);
+
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("**", opts.value),
+                Span::styled("*", opts.cursor),
+                Span::styled("*", opts.value),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_line_cursor_at_edges() {
+        let opts = StyleOptions::default();
+
+        let input = Input::from("test");
+        let line = input.to_line(&opts);
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("test", opts.value),
+                Span::styled(" ", opts.cursor)
+            ]
+        );
+
+        let input = input.with_cursor(0);
+        let line = input.to_line(&opts);
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("t", opts.cursor),
+                Span::styled("est", opts.value)
+            ]
+        );
+
+        let input = Input::default();
+        let line = input.to_line(&opts);
+        assert_eq!(line.spans, vec![Span::styled(" ", opts.cursor)]);
+    }
+
+    #[test]
+    fn render_default_value() {
+        let input = Input::default()
+            .with_placeholder("Name".into())
+            .with_default_value("World".into());
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        InputWidget::new(&input).render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["[World]   "]);
+        let style = Style::default().add_modifier(Modifier::DIM);
+        expected.set_style(Rect::new(0, 0, 7, 1), style);
+        expected.set_style(
+            Rect::new(0, 0, 1, 1),
+            style.add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_suggestion() {
+        let mut input = Input::from("gi");
+        input.set_suggestion(Some("t log".into()));
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        InputWidget::new(&input).render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["git log "]);
+        let style = Style::default().add_modifier(Modifier::DIM);
+        expected.set_style(Rect::new(2, 0, 5, 1), style);
+        expected.set_style(
+            Rect::new(2, 0, 1, 1),
+            style.add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_color_swatch() {
+        let input = Input::from("#f80");
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        InputWidget::new(&input)
+            .color_swatch(true)
+            .suffix("!")
+            .render(area, &mut buf);
+
+        let swatch = Color::Rgb(255, 136, 0);
+        assert_eq!(buf[(6, 0)].bg, Color::Reset);
+        assert_eq!(buf[(7, 0)].bg, swatch);
+        assert_eq!(buf[(8, 0)].bg, swatch);
+        assert_eq!(buf[(9, 0)].symbol(), "!");
+    }
+
+    #[test]
+    fn render_palette() {
+        let mut palette = CommandPalette::new(vec!["open", "quit", "copy"]);
+        palette.handle(PaletteRequest::Input(InputRequest::InsertChar('o')));
+        palette.handle(PaletteRequest::SelectNext);
+        let widget = CommandPaletteWidget::new(&palette).block(None).prefix(">");
+        let area = Rect::new(0, 0, 6, 3);
+        assert_eq!(widget.cursor_position(area), Position::new(2, 0));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        assert_eq!(
+            buf.content.iter().map(|c| c.symbol()).collect::<String>(),
+            ">o    open  copy  "
+        );
+        assert_eq!(buf[(0, 1)].modifier, Modifier::BOLD);
+        assert_eq!(buf[(1, 1)].modifier, Modifier::empty());
+        assert_eq!(buf[(1, 2)].modifier, Modifier::BOLD | Modifier::REVERSED);
+    }
+
+    #[test]
+    fn render_hidden_cursor() {
+        let input = Input::from("ab").with_cursor(0);
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        InputWidget::new(&input)
+            .cursor_visible(false)
+            .render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ab "]));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let input = Input::default().with_placeholder("Search".into());
+        let widget =
+            InputWidget::new(&input).block(Block::default().borders(Borders::ALL));
+        let area = Rect::new(0, 0, 10, 3);
+
+        assert_eq!(widget.cursor_position(area), Position::new(1, 1));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected =
+            Buffer::with_lines(["┌────────┐", "│Search  │", "└────────┘"]);
+        let style = Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC);
+        expected.set_style(Rect::new(1, 1, 6, 1), style);
+        expected.set_style(
+            Rect::new(1, 1, 1, 1),
+            style.add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_scrolled() {
+        let input = Input::from("Hello World");
+        let widget = InputWidget::new(&input);
+        let area = Rect::new(0, 0, 5, 1);
+
+        assert_eq!(widget.cursor_position(area), Position::new(4, 0));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["orld "]);
+        expected.set_style(
+            Rect::new(4, 0, 1, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn char_index_at() {
+        let input = Input::from("Hello World");
+        let widget = InputWidget::new(&input).prefix("> ");
+        let area = Rect::new(0, 0, 8, 1);
+
+        assert_eq!(widget.char_index_at(area, Position::new(1, 0)), None);
+        assert_eq!(widget.char_index_at(area, Position::new(2, 0)), Some(6));
+        assert_eq!(widget.char_index_at(area, Position::new(7, 0)), Some(11));
+    }
+
+    #[test]
+    fn render_right_aligned() {
+        let input = Input::from("42");
+        let widget = InputWidget::new(&input).alignment(Alignment::Right);
+        let area = Rect::new(0, 0, 6, 1);
+
+        assert_eq!(widget.cursor_position(area), Position::new(5, 0));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["   42 "]);
+        expected.set_style(
+            Rect::new(5, 0, 1, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_affixes() {
+        let input = Input::from("123");
+        let widget = InputWidget::new(&input).prefix("$ ").suffix(" MB");
+        let area = Rect::new(0, 0, 10, 1);
+
+        assert_eq!(widget.cursor_position(area), Position::new(5, 0));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["$ 123   MB"]);
+        expected.set_style(
+            Rect::new(5, 0, 1, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_counter() {
+        let input = Input::from("abc").with_max_len(10);
+        let widget = InputWidget::new(&input)
+            .styles(StyleOptions::default().cursor(Style::default()))
+            .block(Block::default().borders(Borders::ALL))
+            .counter(true);
+        let area = Rect::new(0, 0, 10, 3);
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let expected = Buffer::with_lines(["┌────────┐", "│abc     │", "└────3/10┘"]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_error() {
+        let validator = Validator::new(|v| match v {
+            "" => Err("empty".into()),
+            _ => Ok(()),
+        });
+        let input = Input::default().with_validator(validator);
+        let widget = InputWidget::new(&input)
+            .styles(StyleOptions::default().cursor(Style::default()))
+            .block(Block::default().borders(Borders::ALL))
+            .show_error(true);
+        let area = Rect::new(0, 0, 10, 3);
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected =
+            Buffer::with_lines(["┌────────┐", "│        │", "└empty───┘"]);
+        let error = Style::default().fg(Color::Red);
+        expected.set_style(Rect::new(0, 0, 10, 1), error);
+        expected.set_style(Rect::new(0, 1, 1, 1), error);
+        expected.set_style(Rect::new(9, 1, 1, 1), error);
+        expected.set_style(Rect::new(0, 2, 10, 1), error);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_completion_menu() {
+        let mut state = CompletionState::default();
+        let completion = |text: &str| crate::completion::Completion {
+            range: 0..0,
+            text: text.into(),
+        };
+        state.set_completions(vec![completion("a"), completion("bb"), completion("c")]);
+        state.handle(
+            crate::completion::CompletionRequest::SelectNext,
+            &mut Input::default(),
+        );
+
+        let area = Rect::new(0, 0, 8, 5);
+        let menu = CompletionMenu::new(&state, Position::new(6, 0))
+            .max_height(2)
+            .block(Block::default());
+
+        assert_eq!(menu.popup_area(area), Rect::new(6, 1, 2, 2));
+
+        let mut buf = Buffer::empty(area);
+        menu.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines([
+            "        ", "      a ", "      bb", "        ", "        ",
+        ]);
+        expected.set_style(
+            Rect::new(6, 2, 2, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+
+        let menu = CompletionMenu::new(&state, Position::new(0, 4)).max_height(2);
+        assert_eq!(menu.popup_area(area), Rect::new(0, 0, 4, 4));
+    }
+
+    #[test]
+    fn render_spinner() {
+        let spinner = Spinner::new(5).with_range(0, 5);
+        let widget = SpinnerWidget::new(&spinner)
+            .input(|w| w.styles(StyleOptions::default().cursor(Style::default())));
+        let area = Rect::new(0, 0, 6, 1);
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["  5 ▲▼"]);
+        expected.set_style(
+            Rect::new(4, 0, 1, 1),
+            Style::default().add_modifier(Modifier::DIM),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn to_lines() {
+        let input = Input::from("ab\ncd").with_cursor(2);
+        let opts = StyleOptions::default();
+        let lines = input.to_lines(&opts);
+
+        assert_eq!(
+            lines,
+            vec![
+                Line::from(vec![
+                    Span::styled("ab", opts.value),
+                    Span::styled(" ", opts.cursor),
+                ]),
+                Line::from(vec![Span::styled("cd", opts.value)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_text_area_line_numbers() {
+        let input = Input::from("a\nb\nc").with_multiline(true).with_cursor(2);
+        let widget = TextAreaWidget::new(&input).line_numbers(LineNumbers::Relative);
+        let area = Rect::new(0, 0, 5, 2);
+
+        assert_eq!(widget.cursor_position(area), Position::new(2, 1));
+
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["1 a  ", "2 b  "]);
+        let dim = Style::default().add_modifier(Modifier::DIM);
+        expected.set_style(Rect::new(0, 0, 1, 2), dim);
+        expected.set_style(
+            Rect::new(2, 1, 1, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_eq!(buf, expected);
+    }
+}